@@ -0,0 +1,150 @@
+use fefix_dictionary::{self as dict, TagU32};
+use fnv::FnvHashSet;
+use serde_json::{json, Value};
+
+/// Generates a [Cytoscape.js](https://js.cytoscape.org/) graph of all
+/// messages, components, and fields in `fix_dictionary`, as a JSON document.
+///
+/// Every message, component, and field is a node, with an ID of the form
+/// `message:<MsgType>`, `component:<Name>`, and `field:<Tag>` respectively, and
+/// a `kind` attribute. Edges go from each message or component to the layout
+/// items it contains. Repeating groups are represented by their `NumInGroup`
+/// field, which has an edge to every item of the group. Nodes and edges are
+/// sorted, so the output is stable across runs.
+///
+/// ```
+/// use fefix_codegen::dictionary_to_cytoscape_json;
+/// use fefix_dictionary::Dictionary;
+///
+/// let json = dictionary_to_cytoscape_json(&Dictionary::fix44());
+/// assert!(json.contains("\"id\": \"message:D\""));
+/// ```
+pub fn dictionary_to_cytoscape_json(fix_dictionary: &dict::Dictionary) -> String {
+    let mut messages = fix_dictionary.messages();
+    messages.sort_by(|a, b| a.msg_type().cmp(b.msg_type()));
+    let mut components = fix_dictionary.components();
+    components.sort_by(|a, b| a.name().cmp(b.name()));
+    let mut fields = fix_dictionary.fields();
+    fields.sort_by_key(|field| field.tag());
+
+    let mut nodes = Vec::new();
+    let mut edges = Edges::default();
+    for msg in messages.iter() {
+        let id = format!("message:{}", msg.msg_type());
+        nodes.push(node(&id, msg.name(), "message"));
+        edges.add_layout(&id, msg.layout());
+    }
+    for component in components.iter() {
+        let id = component_id(component.name());
+        nodes.push(node(&id, component.name(), "component"));
+        edges.add_layout(&id, component.items());
+    }
+    for field in fields.iter() {
+        nodes.push(node(&field_id(field.tag()), field.name(), "field"));
+    }
+    let graph = json!({
+        "elements": {
+            "nodes": nodes,
+            "edges": edges.list,
+        }
+    });
+    serde_json::to_string_pretty(&graph).unwrap()
+}
+
+fn node(id: &str, label: &str, kind: &str) -> Value {
+    json!({ "data": { "id": id, "label": label, "kind": kind } })
+}
+
+fn component_id(name: &str) -> String {
+    format!("component:{}", name)
+}
+
+fn field_id(tag: TagU32) -> String {
+    format!("field:{}", tag)
+}
+
+/// Containment edges, without duplicates.
+#[derive(Default)]
+struct Edges {
+    list: Vec<Value>,
+    seen: FnvHashSet<(String, String)>,
+}
+
+impl Edges {
+    fn add(&mut self, source: &str, target: String) {
+        if self.seen.insert((source.to_string(), target.clone())) {
+            let id = format!("{}->{}", source, target);
+            self.list.push(json!({
+                "data": { "id": id, "source": source, "target": target }
+            }));
+        }
+    }
+
+    fn add_layout<'a>(&mut self, source: &str, items: impl Iterator<Item = dict::LayoutItem<'a>>) {
+        for item in items {
+            match item.kind() {
+                dict::LayoutItemKind::Field(field) => self.add(source, field_id(field.tag())),
+                dict::LayoutItemKind::Group(len_field, items) => {
+                    let id = field_id(len_field.tag());
+                    self.add(source, id.clone());
+                    self.add_layout(&id, items.into_iter());
+                }
+                dict::LayoutItemKind::Component(component) => {
+                    self.add(source, component_id(component.name()))
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn fix44_graph() -> Value {
+        let json = dictionary_to_cytoscape_json(&dict::Dictionary::fix44());
+        serde_json::from_str(&json).unwrap()
+    }
+
+    fn has_edge(graph: &Value, source: &str, target: &str) -> bool {
+        graph["elements"]["edges"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|edge| edge["data"]["source"] == source && edge["data"]["target"] == target)
+    }
+
+    #[test]
+    fn fix44_new_order_single() {
+        let graph = fix44_graph();
+        assert!(has_edge(&graph, "message:D", "field:11"));
+        assert!(has_edge(&graph, "message:D", "component:Instrument"));
+        assert!(has_edge(&graph, "component:Instrument", "field:55"));
+        // `NoPartyIDs` announces a group inside `Parties`.
+        assert!(has_edge(&graph, "component:Parties", "field:453"));
+        assert!(has_edge(&graph, "field:453", "field:448"));
+        assert!(!has_edge(&graph, "message:D", "field:55"));
+    }
+
+    #[test]
+    fn every_edge_connects_nodes() {
+        let fix_dictionary = dict::Dictionary::fix44();
+        let graph = fix44_graph();
+        let nodes = graph["elements"]["nodes"].as_array().unwrap();
+        assert_eq!(
+            nodes.len(),
+            fix_dictionary.messages().len()
+                + fix_dictionary.components().len()
+                + fix_dictionary.fields().len()
+        );
+        let ids = nodes
+            .iter()
+            .map(|node| node["data"]["id"].as_str().unwrap())
+            .collect::<FnvHashSet<_>>();
+        assert_eq!(ids.len(), nodes.len());
+        for edge in graph["elements"]["edges"].as_array().unwrap() {
+            assert!(ids.contains(edge["data"]["source"].as_str().unwrap()));
+            assert!(ids.contains(edge["data"]["target"].as_str().unwrap()));
+        }
+    }
+}
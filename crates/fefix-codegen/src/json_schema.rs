@@ -0,0 +1,145 @@
+use fefix_dictionary::{self as dict, FixDatatype};
+use serde_json::{json, Map, Value};
+
+/// Generates a [JSON Schema](https://json-schema.org/) document for all
+/// messages in `fix_dictionary`.
+///
+/// Every message is a definition under `$defs`, keyed by message name. Each
+/// definition is an object with one property per field, named after the field.
+/// Components are flattened into their parent, and repeating groups become
+/// arrays of objects named after their `NumInGroup` field. Fields with a code
+/// set list their allowed values with `enum`. The standard header and trailer
+/// are not part of any message definition.
+pub fn dictionary_to_json_schema(fix_dictionary: &dict::Dictionary) -> Value {
+    let mut messages = fix_dictionary.messages();
+    messages.sort_by(|a, b| a.name().cmp(b.name()));
+    let defs = messages
+        .iter()
+        .map(|msg| {
+            let schema = object_schema(msg.layout());
+            (msg.name().to_string(), schema)
+        })
+        .collect::<Map<String, Value>>();
+    json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "title": fix_dictionary.version(),
+        "$defs": defs,
+    })
+}
+
+fn object_schema<'a>(items: impl Iterator<Item = dict::LayoutItem<'a>>) -> Value {
+    let mut properties = Map::new();
+    let mut required = Vec::new();
+    add_properties(items, true, &mut properties, &mut required);
+    json!({
+        "type": "object",
+        "properties": properties,
+        "required": required,
+    })
+}
+
+fn add_properties<'a>(
+    items: impl Iterator<Item = dict::LayoutItem<'a>>,
+    parent_is_required: bool,
+    properties: &mut Map<String, Value>,
+    required: &mut Vec<Value>,
+) {
+    for item in items {
+        let is_required = parent_is_required && item.required();
+        let (name, schema) = match item.kind() {
+            dict::LayoutItemKind::Field(field) => (field.name().to_string(), field_schema(field)),
+            dict::LayoutItemKind::Group(len_field, items) => {
+                let schema = json!({
+                    "type": "array",
+                    "items": object_schema(items.into_iter()),
+                });
+                (len_field.name().to_string(), schema)
+            }
+            dict::LayoutItemKind::Component(component) => {
+                add_properties(component.items(), is_required, properties, required);
+                continue;
+            }
+        };
+        if is_required {
+            required.push(Value::String(name.clone()));
+        }
+        properties.insert(name, schema);
+    }
+}
+
+fn field_schema(field: dict::Field) -> Value {
+    let datatype = field.fix_datatype();
+    let mut schema = Map::new();
+    schema.insert("type".to_string(), json_type(datatype).into());
+    if let Some(enums) = field.enums() {
+        let values = enums
+            .map(|e| json_value(datatype, e.value()))
+            .collect::<Vec<Value>>();
+        schema.insert("enum".to_string(), Value::Array(values));
+    }
+    Value::Object(schema)
+}
+
+fn json_type(datatype: FixDatatype) -> &'static str {
+    match datatype.base_type() {
+        FixDatatype::Int => "integer",
+        FixDatatype::Float => "number",
+        FixDatatype::Boolean => "boolean",
+        _ => "string",
+    }
+}
+
+/// Converts the wire representation `value` into its JSON counterpart, falling
+/// back to a JSON string whenever `value` doesn't match `datatype`.
+fn json_value(datatype: FixDatatype, value: &str) -> Value {
+    let parsed = match json_type(datatype) {
+        "integer" => value.parse::<i64>().ok().map(Value::from),
+        "number" => value.parse::<f64>().ok().map(Value::from),
+        "boolean" => match value {
+            "Y" => Some(Value::Bool(true)),
+            "N" => Some(Value::Bool(false)),
+            _ => None,
+        },
+        _ => None,
+    };
+    parsed.unwrap_or_else(|| Value::String(value.to_string()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn fix44_new_order_single() {
+        let schema = dictionary_to_json_schema(&dict::Dictionary::fix44());
+        assert_eq!(schema["title"], "FIX.4.4");
+        let msg = &schema["$defs"]["NewOrderSingle"];
+        assert_eq!(msg["type"], "object");
+        let required = msg["required"].as_array().unwrap();
+        assert!(required.contains(&json!("ClOrdID")));
+        assert!(!required.contains(&json!("Account")));
+        let properties = &msg["properties"];
+        assert_eq!(properties["OrderQty"]["type"], "number");
+        assert_eq!(properties["Side"]["type"], "string");
+        assert!(properties["Side"]["enum"]
+            .as_array()
+            .unwrap()
+            .contains(&json!("1")));
+        // `Symbol` comes from the `Instrument` component.
+        assert_eq!(properties["Symbol"]["type"], "string");
+        assert!(!required.contains(&json!("Symbol")));
+        assert_eq!(properties["NoPartyIDs"]["type"], "array");
+        assert_eq!(
+            properties["NoPartyIDs"]["items"]["properties"]["PartyRole"]["enum"][0],
+            1
+        );
+    }
+
+    #[test]
+    fn every_message_is_defined() {
+        let fix_dictionary = dict::Dictionary::fix44();
+        let schema = dictionary_to_json_schema(&fix_dictionary);
+        let defs = schema["$defs"].as_object().unwrap();
+        assert_eq!(defs.len(), fix_dictionary.messages().len());
+    }
+}
@@ -4,6 +4,20 @@ use heck::{ToPascalCase, ToShoutySnakeCase};
 use indoc::indoc;
 use std::marker::PhantomData;
 
+#[cfg(feature = "graph-export")]
+mod graph;
+#[cfg(feature = "json-schema-codegen")]
+mod json_schema;
+#[cfg(feature = "quickfix-compat")]
+mod quickfix_config;
+
+#[cfg(feature = "graph-export")]
+pub use graph::dictionary_to_cytoscape_json;
+#[cfg(feature = "json-schema-codegen")]
+pub use json_schema::dictionary_to_json_schema;
+#[cfg(feature = "quickfix-compat")]
+pub use quickfix_config::{dictionary_to_quickfix_config, ConnectionType, SessionConfig};
+
 const FEFIX_VERSION: &str = env!("CARGO_PKG_VERSION");
 
 /// Creates a [`String`] that contains a multiline Rust "Doc" comment explaining
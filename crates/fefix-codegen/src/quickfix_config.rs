@@ -0,0 +1,214 @@
+use fefix_dictionary::{self as dict, FixVersion};
+use std::fmt::Write;
+
+/// Whether a QuickFIX session initiates connections or accepts them.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ConnectionType {
+    Initiator,
+    Acceptor,
+}
+
+/// The settings of a single QuickFIX session. See
+/// [`dictionary_to_quickfix_config`].
+#[derive(Debug, Clone)]
+pub struct SessionConfig {
+    /// [`ConnectionType::Initiator`] by default.
+    pub connection_type: ConnectionType,
+    /// The `SenderCompID <49>` of outgoing messages.
+    pub sender_comp_id: String,
+    /// The `TargetCompID <56>` of outgoing messages.
+    pub target_comp_id: String,
+    /// The host to connect to (initiators) or to listen on (acceptors).
+    /// `127.0.0.1` by default.
+    pub host: String,
+    /// The port to connect to (initiators) or to listen on (acceptors).
+    pub port: u16,
+    /// The heartbeat interval of initiators, in seconds. 30 by default.
+    pub heartbeat_interval: u32,
+    /// The daily start time of the session, in UTC. `00:00:00` by default.
+    pub start_time: String,
+    /// The daily end time of the session, in UTC. `00:00:00` by default.
+    pub end_time: String,
+    /// The directory where QuickFIX stores messages and sequence numbers.
+    /// `store` by default.
+    pub file_store_path: String,
+    /// The path of the application data dictionary. If `None`, it's the name
+    /// of the specification file shipped with QuickFIX for the version of the
+    /// dictionary, e.g. `FIX44.xml`.
+    pub data_dictionary_path: Option<String>,
+    /// The path of the transport data dictionary, only used by FIX 5.0 and
+    /// later. `FIXT11.xml` if `None`.
+    pub transport_data_dictionary_path: Option<String>,
+}
+
+impl Default for SessionConfig {
+    fn default() -> Self {
+        Self {
+            connection_type: ConnectionType::Initiator,
+            sender_comp_id: String::new(),
+            target_comp_id: String::new(),
+            host: "127.0.0.1".to_string(),
+            port: 0,
+            heartbeat_interval: 30,
+            start_time: "00:00:00".to_string(),
+            end_time: "00:00:00".to_string(),
+            file_store_path: "store".to_string(),
+            data_dictionary_path: None,
+            transport_data_dictionary_path: None,
+        }
+    }
+}
+
+/// Generates a QuickFIX (and QuickFIX/n, QuickFIX/J) `quickfix.cfg` file with
+/// a single `[SESSION]` for `fix_dictionary`, as described by
+/// `session_config`.
+///
+/// FIX 5.0 and later sessions use FIXT.1.1 as `BeginString`, with the
+/// version of `fix_dictionary` as `DefaultApplVerID` and separate transport
+/// and application data dictionaries.
+///
+/// ```
+/// use fefix_codegen::{dictionary_to_quickfix_config, SessionConfig};
+/// use fefix_dictionary::Dictionary;
+///
+/// let session_config = SessionConfig {
+///     sender_comp_id: "CLIENT".to_string(),
+///     target_comp_id: "BROKER".to_string(),
+///     port: 5001,
+///     ..SessionConfig::default()
+/// };
+/// let cfg = dictionary_to_quickfix_config(&Dictionary::fix44(), &session_config);
+/// assert!(cfg.contains("BeginString=FIX.4.4\n"));
+/// assert!(cfg.contains("DataDictionary=FIX44.xml\n"));
+/// assert!(cfg.contains("SocketConnectPort=5001\n"));
+/// ```
+pub fn dictionary_to_quickfix_config(
+    fix_dictionary: &dict::Dictionary,
+    session_config: &SessionConfig,
+) -> String {
+    let mut cfg = String::new();
+    let data_dictionary_path = session_config
+        .data_dictionary_path
+        .clone()
+        .unwrap_or_else(|| quickfix_spec_file_name(fix_dictionary.version()));
+    let connection_type = match session_config.connection_type {
+        ConnectionType::Initiator => "initiator",
+        ConnectionType::Acceptor => "acceptor",
+    };
+    writeln!(cfg, "[DEFAULT]").unwrap();
+    writeln!(cfg, "ConnectionType={}", connection_type).unwrap();
+    writeln!(cfg, "StartTime={}", session_config.start_time).unwrap();
+    writeln!(cfg, "EndTime={}", session_config.end_time).unwrap();
+    writeln!(cfg, "FileStorePath={}", session_config.file_store_path).unwrap();
+    writeln!(cfg, "UseDataDictionary=Y").unwrap();
+    writeln!(cfg).unwrap();
+    writeln!(cfg, "[SESSION]").unwrap();
+    match default_appl_ver_id(fix_dictionary) {
+        Some(appl_ver_id) => {
+            let transport_data_dictionary_path = session_config
+                .transport_data_dictionary_path
+                .clone()
+                .unwrap_or_else(|| quickfix_spec_file_name(FixVersion::Fixt11.as_str()));
+            writeln!(cfg, "BeginString={}", FixVersion::Fixt11.as_str()).unwrap();
+            writeln!(cfg, "DefaultApplVerID={}", appl_ver_id).unwrap();
+            writeln!(
+                cfg,
+                "TransportDataDictionary={}",
+                transport_data_dictionary_path
+            )
+            .unwrap();
+            writeln!(cfg, "AppDataDictionary={}", data_dictionary_path).unwrap();
+        }
+        None => {
+            writeln!(cfg, "BeginString={}", fix_dictionary.version()).unwrap();
+            writeln!(cfg, "DataDictionary={}", data_dictionary_path).unwrap();
+        }
+    }
+    writeln!(cfg, "SenderCompID={}", session_config.sender_comp_id).unwrap();
+    writeln!(cfg, "TargetCompID={}", session_config.target_comp_id).unwrap();
+    match session_config.connection_type {
+        ConnectionType::Initiator => {
+            writeln!(cfg, "HeartBtInt={}", session_config.heartbeat_interval).unwrap();
+            writeln!(cfg, "SocketConnectHost={}", session_config.host).unwrap();
+            writeln!(cfg, "SocketConnectPort={}", session_config.port).unwrap();
+        }
+        ConnectionType::Acceptor => {
+            writeln!(cfg, "SocketAcceptHost={}", session_config.host).unwrap();
+            writeln!(cfg, "SocketAcceptPort={}", session_config.port).unwrap();
+        }
+    }
+    cfg
+}
+
+/// Returns the `DefaultApplVerID` of FIX 5.0 and later dictionaries, which
+/// are used over FIXT.1.1 sessions.
+fn default_appl_ver_id(fix_dictionary: &dict::Dictionary) -> Option<&'static str> {
+    match fix_dictionary.fix_version()? {
+        FixVersion::Fix50 => Some("FIX.5.0"),
+        FixVersion::Fix50SP1 => Some("FIX.5.0SP1"),
+        FixVersion::Fix50SP2 => Some("FIX.5.0SP2"),
+        _ => None,
+    }
+}
+
+/// QuickFIX names its specification files after their version, without
+/// punctuation, e.g. `FIX50SP2.xml` for `FIX.5.0-SP2`.
+fn quickfix_spec_file_name(version: &str) -> String {
+    let name = version.replace(['.', '-'], "");
+    format!("{}.xml", name)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn lines(cfg: &str) -> Vec<&str> {
+        cfg.lines().collect()
+    }
+
+    #[test]
+    fn fix50_uses_fixt() {
+        let dict = dict::Dictionary::fix50();
+        let session_config = SessionConfig {
+            connection_type: ConnectionType::Acceptor,
+            sender_comp_id: "BROKER".to_string(),
+            target_comp_id: "CLIENT".to_string(),
+            host: "0.0.0.0".to_string(),
+            port: 9878,
+            ..SessionConfig::default()
+        };
+        let cfg = dictionary_to_quickfix_config(&dict, &session_config);
+        let lines = lines(&cfg);
+        assert!(lines.contains(&"ConnectionType=acceptor"));
+        assert!(lines.contains(&"BeginString=FIXT.1.1"));
+        assert!(lines.contains(&"DefaultApplVerID=FIX.5.0"));
+        assert!(lines.contains(&"TransportDataDictionary=FIXT11.xml"));
+        assert!(lines.contains(&"AppDataDictionary=FIX50.xml"));
+        assert!(lines.contains(&"SocketAcceptPort=9878"));
+        assert!(!lines.iter().any(|line| line.starts_with("HeartBtInt=")));
+        assert!(!lines.iter().any(|line| line.starts_with("DataDictionary=")));
+    }
+
+    #[test]
+    fn custom_data_dictionary_path() {
+        let dict = dict::Dictionary::fix44();
+        let session_config = SessionConfig {
+            data_dictionary_path: Some("spec/VENUE44.xml".to_string()),
+            ..SessionConfig::default()
+        };
+        let cfg = dictionary_to_quickfix_config(&dict, &session_config);
+        let lines = lines(&cfg);
+        assert_eq!(lines[0], "[DEFAULT]");
+        assert!(lines.contains(&"[SESSION]"));
+        assert!(lines.contains(&"DataDictionary=spec/VENUE44.xml"));
+        assert!(lines.contains(&"HeartBtInt=30"));
+        assert!(lines.contains(&"SocketConnectHost=127.0.0.1"));
+    }
+
+    #[test]
+    fn quickfix_spec_file_names() {
+        assert_eq!(quickfix_spec_file_name("FIX.4.2"), "FIX42.xml");
+        assert_eq!(quickfix_spec_file_name("FIX.5.0-SP1"), "FIX50SP1.xml");
+        assert_eq!(quickfix_spec_file_name("FIXT.1.1"), "FIXT11.xml");
+    }
+}
@@ -0,0 +1,120 @@
+use super::*;
+use std::{borrow::Cow, fmt::Write};
+
+/// Reports
+impl Dictionary {
+    /// Returns a CSV document with one row per field of each message body,
+    /// i.e. with components and repeating groups expanded. Columns are tag,
+    /// field name, datatype, message name, and whether the field is required
+    /// (`Y` or `N`). A field is required if it's marked as such and so are all
+    /// of its enclosing components and groups.
+    ///
+    /// Messages are sorted by message type and fields appear in layout order,
+    /// so the output is stable across runs. Fields that appear more than once
+    /// in a message are only listed once.
+    ///
+    /// ```
+    /// use fefix_dictionary::Dictionary;
+    ///
+    /// let dict = Dictionary::fix44();
+    /// let csv = dict.to_cross_reference_csv();
+    /// let mut lines = csv.lines();
+    /// assert_eq!(lines.next(), Some("tag,field,datatype,message,required"));
+    /// assert_eq!(lines.next(), Some("112,TestReqID,String,Heartbeat,N"));
+    /// ```
+    pub fn to_cross_reference_csv(&self) -> String {
+        let mut csv = String::from("tag,field,datatype,message,required\n");
+        let mut messages = self.messages();
+        messages.sort_by(|a, b| a.msg_type().cmp(b.msg_type()));
+        for message in messages {
+            let mut fields = Vec::new();
+            collect_fields(self, &message.1.layout_items, true, &mut fields);
+            for (field, required) in fields {
+                writeln!(
+                    csv,
+                    "{},{},{},{},{}",
+                    field.tag(),
+                    csv_escape(field.name()),
+                    csv_escape(field.data_type().name()),
+                    csv_escape(message.name()),
+                    if required { "Y" } else { "N" },
+                )
+                .unwrap();
+            }
+        }
+        csv
+    }
+}
+
+fn collect_fields<'a>(
+    dict: &'a Dictionary,
+    items: &'a [LayoutItemData],
+    parent_is_required: bool,
+    fields: &mut Vec<(Field<'a>, bool)>,
+) {
+    let push = |fields: &mut Vec<(Field<'a>, bool)>, tag: &u32, required: bool| {
+        if let Some(field) = dict.field_by_tag(*tag) {
+            match fields.iter_mut().find(|(f, _)| f.tag() == field.tag()) {
+                Some((_, r)) => *r |= required,
+                None => fields.push((field, required)),
+            }
+        }
+    };
+    for item in items {
+        let required = parent_is_required && item.required;
+        match &item.kind {
+            LayoutItemKindData::Field { tag } => push(fields, tag, required),
+            LayoutItemKindData::Group {
+                len_field_tag,
+                items,
+            } => {
+                push(fields, len_field_tag, required);
+                collect_fields(dict, items, required, fields);
+            }
+            LayoutItemKindData::Component { name } => {
+                if let Some(component) = dict.components_by_name.get(name) {
+                    collect_fields(dict, &component.layout_items, required, fields);
+                }
+            }
+        }
+    }
+}
+
+fn csv_escape(value: &str) -> Cow<'_, str> {
+    if value.contains([',', '"', '\n', '\r']) {
+        Cow::Owned(format!("\"{}\"", value.replace('"', "\"\"")))
+    } else {
+        Cow::Borrowed(value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn fix44_cross_reference_csv() {
+        let dict = Dictionary::fix44();
+        let csv = dict.to_cross_reference_csv();
+        assert_eq!(csv, dict.to_cross_reference_csv());
+        let rows = csv.lines().skip(1).collect::<Vec<_>>();
+        assert!(rows.contains(&"11,ClOrdID,String,NewOrderSingle,Y"));
+        assert!(rows.contains(&"1,Account,String,NewOrderSingle,N"));
+        assert!(rows.contains(&"54,Side,char,NewOrderSingle,Y"));
+        // `Instrument` is required in `NewOrderSingle`, but `Symbol` isn't
+        // required in `Instrument`.
+        assert!(rows.contains(&"55,Symbol,String,NewOrderSingle,N"));
+        // `NoPartyIDs` is optional, so are its members.
+        assert!(rows.contains(&"448,PartyID,String,NewOrderSingle,N"));
+        assert!(rows.iter().all(|row| row.split(',').count() == 5));
+        let heartbeat_rows = rows.iter().filter(|row| row.contains(",Heartbeat,"));
+        assert_eq!(heartbeat_rows.count(), 1);
+    }
+
+    #[test]
+    fn csv_values_are_escaped() {
+        assert_eq!(csv_escape("ClOrdID"), "ClOrdID");
+        assert_eq!(csv_escape("a,b"), "\"a,b\"");
+        assert_eq!(csv_escape("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+}
@@ -0,0 +1,310 @@
+use super::*;
+
+/// The changes to the code set of a single [`Field`] between two
+/// [`Dictionary`]'s. See [`Dictionary::enum_diff`].
+#[derive(Debug)]
+pub struct EnumDiff<'a> {
+    /// Values which are only allowed by the newer [`Dictionary`].
+    pub added: Vec<FieldEnum<'a>>,
+    /// Values which are only allowed by the older [`Dictionary`].
+    pub removed: Vec<FieldEnum<'a>>,
+}
+
+impl<'a> EnumDiff<'a> {
+    /// Returns `true` if the code set didn't change at all, `false`
+    /// otherwise.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
+/// The definitions that differ between a [`Dictionary`] and a reference one.
+/// See [`Dictionary::diff`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DictionaryDiff {
+    /// Fields whose tags only exist in the [`Dictionary`], sorted by tag.
+    pub added_fields: Vec<FieldDef>,
+    /// Fields whose tags only exist in the reference, sorted by tag.
+    pub removed_fields: Vec<FieldDef>,
+    /// Fields with different definitions under the same tag, as
+    /// `(reference, dictionary)` pairs sorted by tag.
+    pub changed_fields: Vec<(FieldDef, FieldDef)>,
+    /// Components whose names only exist in the [`Dictionary`], sorted by
+    /// name.
+    pub added_components: Vec<ComponentDef>,
+    /// Components whose names only exist in the reference, sorted by name.
+    pub removed_components: Vec<ComponentDef>,
+    /// Components with different layouts under the same name, as
+    /// `(reference, dictionary)` pairs sorted by name.
+    pub changed_components: Vec<(ComponentDef, ComponentDef)>,
+    /// Messages whose message types only exist in the [`Dictionary`], sorted
+    /// by message type.
+    pub added_messages: Vec<MessageDef>,
+    /// Messages whose message types only exist in the reference, sorted by
+    /// message type.
+    pub removed_messages: Vec<MessageDef>,
+    /// Messages with different definitions under the same message type, as
+    /// `(reference, dictionary)` pairs sorted by message type.
+    pub changed_messages: Vec<(MessageDef, MessageDef)>,
+}
+
+impl DictionaryDiff {
+    /// Returns `true` if there are no differences at all, `false` otherwise.
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+}
+
+/// Comparison between dictionaries
+impl Dictionary {
+    /// Compares the definitions of `self` to those of `reference`, as returned
+    /// by [`Dictionary::to_parts`]. Fields are matched by tag, components by
+    /// name, and messages by message type.
+    ///
+    /// ```
+    /// use fefix_dictionary::Dictionary;
+    ///
+    /// let dict = Dictionary::fix44();
+    /// let mut custom = Dictionary::fix44();
+    /// custom.remove_message("D");
+    /// let diff = custom.diff(&dict);
+    /// assert_eq!(diff.removed_messages[0].name, "NewOrderSingle");
+    /// assert!(diff.added_messages.is_empty());
+    /// ```
+    pub fn diff(&self, reference: &Dictionary) -> DictionaryDiff {
+        let (fields, _, components, messages) = self.to_parts();
+        let (ref_fields, _, ref_components, ref_messages) = reference.to_parts();
+        let (added_fields, removed_fields, changed_fields) =
+            diff_defs(fields, ref_fields, |f| f.tag);
+        let (added_components, removed_components, changed_components) =
+            diff_defs(components, ref_components, |c| c.name.clone());
+        let (added_messages, removed_messages, changed_messages) =
+            diff_defs(messages, ref_messages, |m| m.msg_type.clone());
+        DictionaryDiff {
+            added_fields,
+            removed_fields,
+            changed_fields,
+            added_components,
+            removed_components,
+            changed_components,
+            added_messages,
+            removed_messages,
+            changed_messages,
+        }
+    }
+
+    /// Compares `self` to the bundled standard [`Dictionary`] of the same
+    /// version (see [`Dictionary::standard`]), to list customizations such as
+    /// user-defined fields. Returns `None` if `self` doesn't have a standard
+    /// version or if it was left out by feature flags.
+    ///
+    /// ```
+    /// use fefix_dictionary::Dictionary;
+    ///
+    /// let diff = Dictionary::fix44().deviations_from_standard().unwrap();
+    /// assert!(diff.is_empty());
+    /// ```
+    pub fn deviations_from_standard(&self) -> Option<DictionaryDiff> {
+        let standard = Dictionary::standard(self.fix_version()?)?;
+        Some(self.diff(&standard))
+    }
+
+    /// Compares the code set of the field `tag` in `self` (the older
+    /// [`Dictionary`]) to the one in `other` (the newer one). Fields without a
+    /// code set are treated as having an empty one. Returns `None` if `tag`
+    /// doesn't exist in either [`Dictionary`].
+    ///
+    /// Values are compared by their wire representation only and they're
+    /// sorted in the order in which they appear in the specification.
+    ///
+    /// ```
+    /// use fefix_dictionary::{Dictionary, TagU32};
+    ///
+    /// let dict = Dictionary::fix44();
+    /// let diff = dict.enum_diff(&dict, TagU32::new(167).unwrap()).unwrap();
+    /// assert!(diff.is_empty());
+    /// ```
+    pub fn enum_diff<'a>(&'a self, other: &'a Dictionary, tag: TagU32) -> Option<EnumDiff<'a>> {
+        let old = self.fields_by_tags.get(&tag.get())?;
+        let new = other.fields_by_tags.get(&tag.get())?;
+        Some(EnumDiff {
+            added: enums_missing_from(other, new, old),
+            removed: enums_missing_from(self, old, new),
+        })
+    }
+
+    /// Returns all fields of `self` whose tags don't exist in `other`, sorted
+    /// by tag. Only tags are compared, so renamed fields are not reported.
+    ///
+    /// ```
+    /// use fefix_dictionary::Dictionary;
+    ///
+    /// let dict = Dictionary::fix44();
+    /// assert!(dict.fields_not_in(&dict).is_empty());
+    /// ```
+    pub fn fields_not_in(&self, other: &Dictionary) -> Vec<Field<'_>> {
+        let mut fields = self
+            .fields_by_tags
+            .values()
+            .filter(|field| !other.fields_by_tags.contains_key(&field.tag))
+            .map(|data| Field(self, data))
+            .collect::<Vec<_>>();
+        fields.sort_by_key(|field| field.tag());
+        fields
+    }
+
+    /// Returns all messages of `self` whose message types don't exist in
+    /// `other`, sorted by message type. See [`Dictionary::fields_not_in`].
+    ///
+    /// ```
+    /// use fefix_dictionary::Dictionary;
+    ///
+    /// let dict = Dictionary::fix44();
+    /// assert!(dict.messages_not_in(&dict).is_empty());
+    /// ```
+    pub fn messages_not_in(&self, other: &Dictionary) -> Vec<Message<'_>> {
+        let mut messages = self
+            .messages_by_msgtype
+            .values()
+            .filter(|message| !other.messages_by_msgtype.contains_key(&message.msg_type))
+            .map(|data| Message(self, data))
+            .collect::<Vec<_>>();
+        messages.sort_by(|a, b| a.msg_type().cmp(b.msg_type()));
+        messages
+    }
+}
+
+/// Splits `defs` and `reference` into the definitions whose keys are only in
+/// `defs`, those whose keys are only in `reference`, and the pairs which share
+/// a key but differ. The relative order of definitions is preserved.
+fn diff_defs<T: PartialEq, K: Eq + std::hash::Hash>(
+    defs: Vec<T>,
+    reference: Vec<T>,
+    key: impl Fn(&T) -> K,
+) -> (Vec<T>, Vec<T>, Vec<(T, T)>) {
+    let keys = defs.iter().map(&key).collect::<FnvHashSet<K>>();
+    let (removed, common): (Vec<T>, Vec<T>) = reference
+        .into_iter()
+        .partition(|def| !keys.contains(&key(def)));
+    let mut common = common
+        .into_iter()
+        .map(|def| (key(&def), def))
+        .collect::<FnvHashMap<K, T>>();
+    let mut added = Vec::new();
+    let mut changed = Vec::new();
+    for def in defs {
+        match common.remove(&key(&def)) {
+            None => added.push(def),
+            Some(reference_def) if reference_def != def => changed.push((reference_def, def)),
+            Some(_) => {}
+        }
+    }
+    (added, removed, changed)
+}
+
+/// Returns all [`FieldEnum`]'s of `field` which are not in `reference`.
+fn enums_missing_from<'a>(
+    dict: &'a Dictionary,
+    field: &'a FieldData,
+    reference: &FieldData,
+) -> Vec<FieldEnum<'a>> {
+    let reference_values = reference
+        .value_restrictions
+        .iter()
+        .flatten()
+        .map(|e| e.value.as_str())
+        .collect::<FnvHashSet<&str>>();
+    field
+        .value_restrictions
+        .iter()
+        .flatten()
+        .filter(|e| !reference_values.contains(e.value.as_str()))
+        .map(|e| FieldEnum(dict, e))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn enum_diff_of_unknown_tag_is_none() {
+        let dict = Dictionary::fix44();
+        assert!(dict.enum_diff(&dict, TagU32::new(9999).unwrap()).is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "fix42")]
+    fn fix42_to_fix44_security_type_diff() {
+        let fix42 = Dictionary::fix42();
+        let fix44 = Dictionary::fix44();
+        let diff = fix42.enum_diff(&fix44, TagU32::new(167).unwrap()).unwrap();
+        assert!(diff.added.iter().any(|e| e.value() == "EUCORP"));
+        assert!(diff.removed.iter().any(|e| e.value() == "MUNI"));
+        assert!(!diff.added.iter().any(|e| e.value() == "CORP"));
+        assert!(!diff.removed.iter().any(|e| e.value() == "CORP"));
+    }
+
+    #[test]
+    #[cfg(feature = "fix42")]
+    fn fix42_to_fix44_missing_fields_and_messages() {
+        let fix42 = Dictionary::fix42();
+        let fix44 = Dictionary::fix44();
+        let added_fields = fix44.fields_not_in(&fix42);
+        assert!(added_fields.iter().any(|f| f.name() == "NoPartyIDs"));
+        assert!(!added_fields.iter().any(|f| f.name() == "ClOrdID"));
+        assert!(added_fields.windows(2).all(|w| w[0].tag() < w[1].tag()));
+        let added_messages = fix44.messages_not_in(&fix42);
+        assert!(added_messages.iter().any(|m| m.msg_type() == "AE"));
+        assert!(!added_messages.iter().any(|m| m.msg_type() == "D"));
+        assert!(fix42
+            .messages_not_in(&fix44)
+            .iter()
+            .all(|m| fix44.message_by_msgtype(m.msg_type()).is_none()));
+    }
+
+    #[test]
+    fn deviations_from_standard_fix44() {
+        let mut builder = DictionaryBuilder::from_dictionary(&Dictionary::fix44());
+        builder.add_field(FieldDef {
+            tag: 5001,
+            name: "VenueOrderTag".to_string(),
+            datatype: FixDatatype::String,
+            enums: vec![],
+        });
+        let mut dict = builder.build().unwrap();
+        dict.remove_message("AE");
+        dict.modify_message("D", |m| {
+            m.add_layout_item(LayoutItemDef::Field {
+                name: "VenueOrderTag".to_string(),
+                required: false,
+            })
+        })
+        .unwrap();
+        let diff = dict.deviations_from_standard().unwrap();
+        let tags = |fields: &[FieldDef]| fields.iter().map(|f| f.tag).collect::<Vec<_>>();
+        assert_eq!(tags(&diff.added_fields), vec![5001]);
+        assert!(diff.removed_fields.is_empty());
+        assert!(diff.changed_fields.is_empty());
+        assert!(diff.added_components.is_empty());
+        assert!(diff.removed_components.is_empty());
+        assert!(diff.changed_components.is_empty());
+        assert!(diff.added_messages.is_empty());
+        assert_eq!(diff.removed_messages.len(), 1);
+        assert_eq!(diff.removed_messages[0].msg_type, "AE");
+        assert_eq!(diff.changed_messages.len(), 1);
+        let (standard, custom) = &diff.changed_messages[0];
+        assert_eq!(custom.items.len(), standard.items.len() + 1);
+        assert!(!diff.is_empty());
+        let custom = Dictionary::from_parts("FIX.4.4-VENUE", &[], &[], &[], &[]).unwrap();
+        assert!(custom.deviations_from_standard().is_none());
+    }
+
+    #[test]
+    fn standard_dictionaries_have_no_deviations() {
+        for dict in Dictionary::common_dictionaries() {
+            let diff = dict.deviations_from_standard().unwrap();
+            assert!(diff.is_empty(), "{}", dict.version());
+        }
+    }
+}
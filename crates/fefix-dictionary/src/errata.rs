@@ -0,0 +1,175 @@
+use super::*;
+
+/// A single correction to a published FIX specification.
+enum Erratum {
+    /// The field with this tag was published with the wrong datatype.
+    Datatype { tag: u32, datatype: FixDatatype },
+    /// The field with this tag is missing a value from its code set.
+    MissingEnum {
+        tag: u32,
+        value: &'static str,
+        description: &'static str,
+    },
+}
+
+/// Known errata of the FIX 4.4 specification.
+///
+/// Missing enum values come from the FIX Repository 2010 Edition
+/// (`resources/repositories/fix_repository_2010_edition_20140507`), which
+/// marks values added to FIX 4.4 by the errata with `addedEP="-1"`. Wrong
+/// datatypes are those of `char` fields whose code sets contain multi-character
+/// values; FIX 5.0 corrected both of them. See also the "FIX 4.4 with Errata
+/// 20030618" specification document published by FIX Trading Community.
+const FIX44_ERRATA: &[Erratum] = &[
+    // MiscFeeType: values 10, 11, and 12 are not single characters.
+    Erratum::Datatype {
+        tag: 139,
+        datatype: FixDatatype::String,
+    },
+    // MassCancelRejectReason: value 99 is not a single character.
+    Erratum::Datatype {
+        tag: 532,
+        datatype: FixDatatype::Int,
+    },
+    Erratum::MissingEnum {
+        tag: 235,
+        value: "VALUE1_32",
+        description: "YIELD_VALUE_OF_1_32",
+    },
+    Erratum::MissingEnum {
+        tag: 452,
+        value: "39",
+        description: "CONTRA_INVESTOR_ID",
+    },
+    Erratum::MissingEnum {
+        tag: 452,
+        value: "40",
+        description: "TRANSFER_TO_FIRM",
+    },
+    Erratum::MissingEnum {
+        tag: 495,
+        value: "999",
+        description: "OTHER",
+    },
+];
+
+impl Dictionary {
+    /// Creates a new [`Dictionary`] for FIX 4.4, with corrections for the
+    /// known errata of the specification applied on top of
+    /// [`Dictionary::fix44`]. Affected fields may have a different datatype or
+    /// additional enum values.
+    ///
+    /// ```
+    /// use fefix_dictionary::{Dictionary, FixDatatype};
+    ///
+    /// let dict = Dictionary::fix44_patched();
+    /// let field = dict.field_by_name("MassCancelRejectReason").unwrap();
+    /// assert_eq!(field.fix_datatype(), FixDatatype::Int);
+    /// ```
+    pub fn fix44_patched() -> Self {
+        let mut dict = Dictionary::fix44();
+        for erratum in FIX44_ERRATA {
+            dict.apply_erratum(erratum);
+        }
+        dict.index_layouts();
+        dict
+    }
+
+    fn apply_erratum(&mut self, erratum: &Erratum) {
+        match erratum {
+            Erratum::Datatype { tag, datatype } => {
+                if self.datatype_by_name(datatype.name()).is_none() {
+                    self.add_datatype(DatatypeData {
+                        datatype: *datatype,
+                        description: String::new(),
+                        examples: Vec::new(),
+                    });
+                }
+                if let Some(field) = self.fields_by_tags.get_mut(tag) {
+                    field.data_type_name = datatype.name().into();
+                }
+            }
+            Erratum::MissingEnum {
+                tag,
+                value,
+                description,
+            } => {
+                if let Some(field) = self.fields_by_tags.get_mut(tag) {
+                    let enums = field.value_restrictions.get_or_insert_with(Vec::new);
+                    if !enums.iter().any(|e| e.value == *value) {
+                        enums.push(FieldEnumData {
+                            value: value.to_string(),
+                            description: description.to_string(),
+                        });
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn has_enum(dict: &Dictionary, tag: u32, value: &str) -> bool {
+        let field = dict.field_by_tag(tag).unwrap();
+        let enums = field.enums();
+        if let Some(mut enums) = enums {
+            enums.any(|e| e.value() == value)
+        } else {
+            false
+        }
+    }
+
+    #[test]
+    fn misc_fee_type_is_string() {
+        let datatype_of = |dict: &Dictionary| dict.field_by_tag(139).unwrap().fix_datatype();
+        assert_eq!(datatype_of(&Dictionary::fix44()), FixDatatype::Char);
+        assert_eq!(
+            datatype_of(&Dictionary::fix44_patched()),
+            FixDatatype::String
+        );
+    }
+
+    #[test]
+    fn mass_cancel_reject_reason_is_int() {
+        let dict = Dictionary::fix44_patched();
+        let field = dict.field_by_tag(532).unwrap();
+        assert_eq!(field.fix_datatype(), FixDatatype::Int);
+        assert!(has_enum(&dict, 532, "99"));
+    }
+
+    #[test]
+    fn yield_type_has_value_of_32nds() {
+        assert!(!has_enum(&Dictionary::fix44(), 235, "VALUE1_32"));
+        assert!(has_enum(&Dictionary::fix44_patched(), 235, "VALUE1_32"));
+    }
+
+    #[test]
+    fn party_role_has_contra_investor_id_and_transfer_to_firm() {
+        let dict = Dictionary::fix44_patched();
+        assert!(has_enum(&dict, 452, "39"));
+        assert!(has_enum(&dict, 452, "40"));
+        assert!(has_enum(&dict, 452, "1"));
+    }
+
+    #[test]
+    fn tax_advantage_type_has_other() {
+        assert!(!has_enum(&Dictionary::fix44(), 495, "999"));
+        assert!(has_enum(&Dictionary::fix44_patched(), 495, "999"));
+    }
+
+    #[test]
+    fn errata_are_idempotent() {
+        let mut dict = Dictionary::fix44_patched();
+        for erratum in FIX44_ERRATA {
+            dict.apply_erratum(erratum);
+        }
+        let field = dict.field_by_tag(452).unwrap();
+        assert_eq!(
+            field.enums().unwrap().filter(|e| e.value() == "39").count(),
+            1
+        );
+    }
+}
@@ -0,0 +1,492 @@
+use super::*;
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+
+/// A typed Rust value for a FIX field. See [`Dictionary::encode_field_value`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum FieldValue {
+    /// An integer value, e.g. for `int`, `Length`, or `SeqNum` fields.
+    Int(i64),
+    /// A decimal value, e.g. for `float`, `Price`, or `Qty` fields.
+    Float(f64),
+    /// A textual value. It's used verbatim for fields of any datatype.
+    String(String),
+    /// A boolean value, i.e. `Y` or `N` on the wire.
+    Bool(bool),
+    /// A point in time, e.g. for `UTCTimestamp` or `LocalMktDate` fields.
+    Timestamp(DateTime<Utc>),
+}
+
+/// The error type that can arise when encoding a [`FieldValue`]. See
+/// [`Dictionary::encode_field_value`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum EncodeError {
+    /// The [`Dictionary`] doesn't contain the field.
+    UnknownTag,
+    /// The value can't be represented by the field's datatype, e.g. a
+    /// [`FieldValue::Bool`] for a `Price` field.
+    IncompatibleType,
+    /// The value is out of range for the field's datatype, e.g. `1e20` for
+    /// an `int` field.
+    Overflow,
+}
+
+/// The error type that can arise when decoding a [`FieldValue`]. See
+/// [`Dictionary::decode_field_value`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The [`Dictionary`] doesn't contain the field.
+    UnknownTag,
+    /// The bytes are not a valid value of the field's datatype.
+    InvalidFormat {
+        /// The tag of the field.
+        tag: TagU32,
+        /// The datatype of the field.
+        expected_type: FixDatatype,
+        /// The offending bytes.
+        got_bytes: Vec<u8>,
+    },
+    /// The value is well-formed but too large to fit in its [`FieldValue`]
+    /// variant.
+    Overflow,
+}
+
+/// Typed field values
+impl Dictionary {
+    /// Converts `value` into the wire representation of the field `tag`,
+    /// according to its [`FixDatatype`]. Fails if there's no such field or
+    /// `value` can't be represented by its datatype (see [`EncodeError`]).
+    ///
+    /// Timestamps are truncated to millisecond precision.
+    ///
+    /// Unknown tags are reported as [`EncodeError::UnknownTag`] rather than
+    /// `None`, so that they can be told apart from values that don't fit the
+    /// field, just like with [`Dictionary::decode_field_value`].
+    ///
+    /// ```
+    /// use fefix_dictionary::{Dictionary, FieldValue, TagU32};
+    ///
+    /// let dict = Dictionary::fix44();
+    /// let price = TagU32::new(44).unwrap();
+    /// let bytes = dict.encode_field_value(price, &FieldValue::Float(101.25));
+    /// assert_eq!(bytes.as_deref(), Ok(&b"101.25"[..]));
+    /// ```
+    pub fn encode_field_value(
+        &self,
+        tag: TagU32,
+        value: &FieldValue,
+    ) -> Result<Vec<u8>, EncodeError> {
+        let datatype = self
+            .field_by_tag(tag.get())
+            .ok_or(EncodeError::UnknownTag)?
+            .fix_datatype();
+        let encoded = match value {
+            FieldValue::String(s) => s.clone(),
+            FieldValue::Int(i) => match datatype.base_type() {
+                FixDatatype::Int | FixDatatype::Float | FixDatatype::String => i.to_string(),
+                _ => return Err(EncodeError::IncompatibleType),
+            },
+            FieldValue::Float(f) => match datatype.base_type() {
+                FixDatatype::Float | FixDatatype::String if f.is_finite() => f.to_string(),
+                // `i64::MAX as f64` rounds up to 2^63, which is out of range.
+                FixDatatype::Int if f.fract() == 0.0 => {
+                    if !(i64::MIN as f64..i64::MAX as f64).contains(f) {
+                        return Err(EncodeError::Overflow);
+                    }
+                    (*f as i64).to_string()
+                }
+                _ => return Err(EncodeError::IncompatibleType),
+            },
+            FieldValue::Bool(b) => match datatype.base_type() {
+                FixDatatype::Char | FixDatatype::String => (if *b { "Y" } else { "N" }).to_string(),
+                _ => return Err(EncodeError::IncompatibleType),
+            },
+            FieldValue::Timestamp(t) => {
+                let format = match datatype {
+                    FixDatatype::UtcTimestamp => "%Y%m%d-%H:%M:%S%.3f",
+                    FixDatatype::UtcTimeOnly => "%H:%M:%S%.3f",
+                    FixDatatype::UtcDateOnly | FixDatatype::LocalMktDate => "%Y%m%d",
+                    FixDatatype::MonthYear => "%Y%m",
+                    _ => return Err(EncodeError::IncompatibleType),
+                };
+                t.format(format).to_string()
+            }
+        };
+        Ok(encoded.into_bytes())
+    }
+
+    /// Parses the wire representation `bytes` of the field `tag` into a
+    /// [`FieldValue`], according to its [`FixDatatype`]. This is the inverse of
+    /// [`Dictionary::encode_field_value`].
+    ///
+    /// Integer and decimal datatypes are decoded as [`FieldValue::Int`] and
+    /// [`FieldValue::Float`], `Boolean` as [`FieldValue::Bool`], and dates
+    /// and times as [`FieldValue::Timestamp`] (dates at midnight, times of day
+    /// on 1970-01-01, `MonthYear`'s on the first day of the month). Everything
+    /// else, including `MonthYear`'s with a week code, is decoded as
+    /// [`FieldValue::String`].
+    ///
+    /// ```
+    /// use fefix_dictionary::{Dictionary, FieldValue, TagU32};
+    ///
+    /// let dict = Dictionary::fix44();
+    /// let msg_seq_num = TagU32::new(34).unwrap();
+    /// let value = dict.decode_field_value(msg_seq_num, b"42");
+    /// assert_eq!(value, Ok(FieldValue::Int(42)));
+    /// ```
+    pub fn decode_field_value(&self, tag: TagU32, bytes: &[u8]) -> Result<FieldValue, DecodeError> {
+        let field = self
+            .field_by_tag(tag.get())
+            .ok_or(DecodeError::UnknownTag)?;
+        let datatype = field.fix_datatype();
+        decode(datatype, bytes).map_err(|err| match err {
+            Invalid::Format => DecodeError::InvalidFormat {
+                tag,
+                expected_type: datatype,
+                got_bytes: bytes.to_vec(),
+            },
+            Invalid::Overflow => DecodeError::Overflow,
+        })
+    }
+}
+
+impl FixDatatype {
+    /// Checks that `bytes` is a well-formed wire value of `self`, e.g. that a
+    /// `UTCTimestamp` looks like `20230101-12:30:00.000`. Values that are
+    /// well-formed but too large for [`FieldValue`] (see
+    /// [`DecodeError::Overflow`]) are accepted.
+    ///
+    /// ```
+    /// use fefix_dictionary::FixDatatype;
+    ///
+    /// assert!(FixDatatype::UtcTimestamp.is_valid_value(b"20230101-12:30:00.000"));
+    /// assert!(!FixDatatype::UtcTimestamp.is_valid_value(b"2023-01-01T12:30:00"));
+    /// assert!(FixDatatype::Price.is_valid_value(b"-0.5"));
+    /// ```
+    pub fn is_valid_value(&self, bytes: &[u8]) -> bool {
+        !matches!(decode(*self, bytes), Err(Invalid::Format))
+    }
+}
+
+/// Why [`decode`] rejected some bytes, before the tag of the field is known.
+enum Invalid {
+    Format,
+    Overflow,
+}
+
+fn decode(datatype: FixDatatype, bytes: &[u8]) -> Result<FieldValue, Invalid> {
+    let s = std::str::from_utf8(bytes).map_err(|_| Invalid::Format)?;
+    let midnight = NaiveTime::from_hms_opt(0, 0, 0).unwrap();
+    let is_temporal = matches!(
+        datatype,
+        FixDatatype::UtcTimestamp
+            | FixDatatype::UtcTimeOnly
+            | FixDatatype::UtcDateOnly
+            | FixDatatype::LocalMktDate
+            | FixDatatype::MonthYear
+    );
+    // Dates and times are ASCII-only, which makes it safe to split them at
+    // any byte offset.
+    if is_temporal && !s.is_ascii() {
+        return Err(Invalid::Format);
+    }
+    let value = match datatype {
+        FixDatatype::Boolean => match s {
+            "Y" => FieldValue::Bool(true),
+            "N" => FieldValue::Bool(false),
+            _ => return Err(Invalid::Format),
+        },
+        FixDatatype::UtcTimestamp => {
+            let (date_time, fraction) = s.split_at(s.len().min(17));
+            if !has_shape(date_time, "dddddddd-dd:dd:dd") || !is_fraction(fraction) {
+                return Err(Invalid::Format);
+            }
+            let t = NaiveDateTime::parse_from_str(s, "%Y%m%d-%H:%M:%S%.f")
+                .map_err(|_| Invalid::Format)?;
+            FieldValue::Timestamp(DateTime::from_naive_utc_and_offset(t, Utc))
+        }
+        FixDatatype::UtcTimeOnly => {
+            let (time, fraction) = s.split_at(s.len().min(8));
+            if !has_shape(time, "dd:dd:dd") || !is_fraction(fraction) {
+                return Err(Invalid::Format);
+            }
+            let t = NaiveTime::parse_from_str(s, "%H:%M:%S%.f").map_err(|_| Invalid::Format)?;
+            let epoch = NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
+            FieldValue::Timestamp(DateTime::from_naive_utc_and_offset(epoch.and_time(t), Utc))
+        }
+        FixDatatype::UtcDateOnly | FixDatatype::LocalMktDate => {
+            if !has_shape(s, "dddddddd") {
+                return Err(Invalid::Format);
+            }
+            let date = NaiveDate::parse_from_str(s, "%Y%m%d").map_err(|_| Invalid::Format)?;
+            FieldValue::Timestamp(DateTime::from_naive_utc_and_offset(
+                date.and_time(midnight),
+                Utc,
+            ))
+        }
+        FixDatatype::MonthYear => {
+            let (year_month, rest) = s.split_at(s.len().min(6));
+            if !has_shape(year_month, "dddddd") {
+                return Err(Invalid::Format);
+            }
+            let day = match rest {
+                "" => "01",
+                _ if has_shape(rest, "dd") => rest,
+                _ if has_shape(rest, "wd") => return Ok(FieldValue::String(s.to_string())),
+                _ => return Err(Invalid::Format),
+            };
+            let date = NaiveDate::parse_from_str(&format!("{}{}", year_month, day), "%Y%m%d")
+                .map_err(|_| Invalid::Format)?;
+            FieldValue::Timestamp(DateTime::from_naive_utc_and_offset(
+                date.and_time(midnight),
+                Utc,
+            ))
+        }
+        _ => match datatype.base_type() {
+            FixDatatype::Int => {
+                if !is_decimal(s, false) {
+                    return Err(Invalid::Format);
+                }
+                FieldValue::Int(s.parse().map_err(|_| Invalid::Overflow)?)
+            }
+            FixDatatype::Float => {
+                if !is_decimal(s, true) {
+                    return Err(Invalid::Format);
+                }
+                let f: f64 = s.parse().map_err(|_| Invalid::Format)?;
+                if !f.is_finite() {
+                    return Err(Invalid::Overflow);
+                }
+                FieldValue::Float(f)
+            }
+            FixDatatype::Char if s.chars().count() != 1 => return Err(Invalid::Format),
+            _ if s.is_empty() => return Err(Invalid::Format),
+            _ => FieldValue::String(s.to_string()),
+        },
+    };
+    Ok(value)
+}
+
+/// Checks that `s` has the same length as `shape` and that every character
+/// matches: `d` stands for any ASCII digit and every other character for
+/// itself.
+fn has_shape(s: &str, shape: &str) -> bool {
+    s.len() == shape.len()
+        && s.bytes()
+            .zip(shape.bytes())
+            .all(|(c, expected)| match expected {
+                b'd' => c.is_ascii_digit(),
+                _ => c == expected,
+            })
+}
+
+/// Checks that `s` is either empty or a dot followed by one or more digits.
+fn is_fraction(s: &str) -> bool {
+    s.is_empty()
+        || (s.len() > 1 && s.starts_with('.') && s[1..].bytes().all(|c| c.is_ascii_digit()))
+}
+
+/// Checks that `s` is an optionally negative decimal number, without exponent.
+fn is_decimal(s: &str, allow_dot: bool) -> bool {
+    let digits = s.strip_prefix('-').unwrap_or(s);
+    let mut parts = digits.splitn(2, '.');
+    let integer = parts.next().unwrap_or_default();
+    let fraction = parts.next();
+    let all_digits = |part: &str| part.bytes().all(|c| c.is_ascii_digit());
+    match fraction {
+        None => !integer.is_empty() && all_digits(integer),
+        Some(fraction) => {
+            allow_dot
+                && !(integer.is_empty() && fraction.is_empty())
+                && all_digits(integer)
+                && all_digits(fraction)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn encode(tag: u32, value: FieldValue) -> Result<String, EncodeError> {
+        let dict = Dictionary::fix44();
+        dict.encode_field_value(TagU32::new(tag).unwrap(), &value)
+            .map(|bytes| String::from_utf8(bytes).unwrap())
+    }
+
+    /// A valid and an invalid wire value for every datatype.
+    fn samples(datatype: FixDatatype) -> (&'static [u8], &'static [u8]) {
+        match datatype {
+            FixDatatype::Char => (b"A", b"AB"),
+            FixDatatype::Boolean => (b"Y", b"T"),
+            FixDatatype::Float
+            | FixDatatype::Amt
+            | FixDatatype::Price
+            | FixDatatype::PriceOffset
+            | FixDatatype::Qty
+            | FixDatatype::Percentage => (b"-12.50", b"1e5"),
+            FixDatatype::Int
+            | FixDatatype::DayOfMonth
+            | FixDatatype::Length
+            | FixDatatype::NumInGroup
+            | FixDatatype::SeqNum
+            | FixDatatype::TagNum => (b"-42", b"4.2"),
+            FixDatatype::UtcTimestamp => (b"20210304-05:06:07.008", b"20210304 05:06:07"),
+            FixDatatype::UtcTimeOnly => (b"05:06:07", b"5:06:07"),
+            FixDatatype::UtcDateOnly | FixDatatype::LocalMktDate => (b"20210304", b"2021034"),
+            FixDatatype::MonthYear => (b"202103", b"2021-03"),
+            FixDatatype::String
+            | FixDatatype::Data
+            | FixDatatype::MultipleCharValue
+            | FixDatatype::Currency
+            | FixDatatype::Exchange
+            | FixDatatype::Language
+            | FixDatatype::MultipleStringValue
+            | FixDatatype::XmlData
+            | FixDatatype::Country => (b"EUR", b"\xff"),
+        }
+    }
+
+    #[test]
+    fn decode_every_datatype() {
+        for datatype in FixDatatype::iter_all() {
+            let (valid, invalid) = samples(datatype);
+            assert!(decode(datatype, valid).is_ok(), "{:?}", datatype);
+            assert!(matches!(decode(datatype, invalid), Err(Invalid::Format)));
+            assert!(datatype.is_valid_value(valid), "{:?}", datatype);
+            assert!(!datatype.is_valid_value(invalid), "{:?}", datatype);
+        }
+    }
+
+    #[test]
+    fn decode_values() {
+        let dict = Dictionary::fix44();
+        let decode = |tag, bytes| dict.decode_field_value(TagU32::new(tag).unwrap(), bytes);
+        assert_eq!(decode(9999, b"1"), Err(DecodeError::UnknownTag));
+        assert_eq!(decode(34, b"42"), Ok(FieldValue::Int(42)));
+        assert_eq!(
+            decode(34, b"99999999999999999999"),
+            Err(DecodeError::Overflow)
+        );
+        assert_eq!(decode(44, b".5"), Ok(FieldValue::Float(0.5)));
+        assert!(matches!(
+            decode(44, b"-"),
+            Err(DecodeError::InvalidFormat { .. })
+        ));
+        assert!(decode(52, "2021030405:06:07é".as_bytes()).is_err());
+        assert_eq!(decode(43, b"N"), Ok(FieldValue::Bool(false)));
+        assert_eq!(
+            decode(11, b"ABC-1"),
+            Ok(FieldValue::String("ABC-1".to_string()))
+        );
+        assert_eq!(
+            decode(200, b"202103w2"),
+            Ok(FieldValue::String("202103w2".to_string()))
+        );
+        let t = Utc.with_ymd_and_hms(2021, 3, 4, 0, 0, 0).unwrap();
+        assert_eq!(decode(75, b"20210304"), Ok(FieldValue::Timestamp(t)));
+        assert_eq!(decode(200, b"20210304"), Ok(FieldValue::Timestamp(t)));
+        assert!(decode(52, b"20210230-00:00:00").is_err());
+    }
+
+    #[test]
+    fn decode_is_inverse_of_encode() {
+        let dict = Dictionary::fix44();
+        let t =
+            Utc.with_ymd_and_hms(2021, 3, 4, 5, 6, 7).unwrap() + chrono::Duration::milliseconds(8);
+        let values = [
+            (34, FieldValue::Int(7)),
+            (38, FieldValue::Float(0.25)),
+            (43, FieldValue::Bool(true)),
+            (11, FieldValue::String("X".to_string())),
+            (52, FieldValue::Timestamp(t)),
+        ];
+        for (tag, value) in values.iter() {
+            let tag = TagU32::new(*tag).unwrap();
+            let bytes = dict.encode_field_value(tag, value).unwrap();
+            assert_eq!(dict.decode_field_value(tag, &bytes).as_ref(), Ok(value));
+        }
+    }
+
+    #[test]
+    fn unknown_tag_is_an_error() {
+        assert_eq!(
+            encode(9999, FieldValue::Int(1)),
+            Err(EncodeError::UnknownTag)
+        );
+    }
+
+    #[test]
+    fn ints() {
+        // MsgSeqNum
+        assert_eq!(encode(34, FieldValue::Int(42)).as_deref(), Ok("42"));
+        // Price
+        assert_eq!(encode(44, FieldValue::Int(-3)).as_deref(), Ok("-3"));
+        // HandlInst
+        assert_eq!(
+            encode(21, FieldValue::Int(1)),
+            Err(EncodeError::IncompatibleType)
+        );
+    }
+
+    #[test]
+    fn floats() {
+        // OrderQty
+        assert_eq!(encode(38, FieldValue::Float(0.5)).as_deref(), Ok("0.5"));
+        assert_eq!(
+            encode(38, FieldValue::Float(f64::NAN)),
+            Err(EncodeError::IncompatibleType)
+        );
+        // MsgSeqNum
+        assert_eq!(encode(34, FieldValue::Float(7.0)).as_deref(), Ok("7"));
+        assert_eq!(
+            encode(34, FieldValue::Float(7.5)),
+            Err(EncodeError::IncompatibleType)
+        );
+        assert_eq!(
+            encode(34, FieldValue::Float(1e20)),
+            Err(EncodeError::Overflow)
+        );
+        assert_eq!(
+            encode(34, FieldValue::Float(i64::MAX as f64)),
+            Err(EncodeError::Overflow)
+        );
+        assert_eq!(
+            encode(34, FieldValue::Float(i64::MIN as f64)).as_deref(),
+            Ok("-9223372036854775808")
+        );
+    }
+
+    #[test]
+    fn bools() {
+        // PossDupFlag
+        assert_eq!(encode(43, FieldValue::Bool(true)).as_deref(), Ok("Y"));
+        assert_eq!(encode(43, FieldValue::Bool(false)).as_deref(), Ok("N"));
+        assert_eq!(
+            encode(34, FieldValue::Bool(true)),
+            Err(EncodeError::IncompatibleType)
+        );
+    }
+
+    #[test]
+    fn strings_are_verbatim() {
+        // ClOrdID
+        let value = FieldValue::String("ABC-1".to_string());
+        assert_eq!(encode(11, value).as_deref(), Ok("ABC-1"));
+    }
+
+    #[test]
+    fn timestamps() {
+        let t = Utc.with_ymd_and_hms(2021, 3, 4, 5, 6, 7).unwrap()
+            + chrono::Duration::microseconds(8_900);
+        let ts = |tag| encode(tag, FieldValue::Timestamp(t));
+        // SendingTime
+        assert_eq!(ts(52).as_deref(), Ok("20210304-05:06:07.008"));
+        // TradeDate
+        assert_eq!(ts(75).as_deref(), Ok("20210304"));
+        // MaturityMonthYear
+        assert_eq!(ts(200).as_deref(), Ok("202103"));
+        // ClOrdID
+        assert_eq!(ts(11), Err(EncodeError::IncompatibleType));
+    }
+}
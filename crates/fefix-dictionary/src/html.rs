@@ -0,0 +1,80 @@
+use super::*;
+use std::fmt::Write;
+
+/// HTML documentation
+impl<'a> Message<'a> {
+    /// Returns the description of `self` as an HTML paragraph, for
+    /// documentation sites. HTML special characters are escaped and field
+    /// references of the form `[FieldName]` become links to `#<tag>`
+    /// anchors; references to unknown fields are left as they are.
+    ///
+    /// ```
+    /// use fefix_dictionary::Dictionary;
+    ///
+    /// let dict = Dictionary::fix44();
+    /// let msg = dict.message_by_name("Heartbeat").unwrap();
+    /// assert!(msg.description_html().starts_with("<p>"));
+    /// ```
+    pub fn description_html(&self) -> String {
+        description_to_html(self.0, self.description())
+    }
+}
+
+/// HTML documentation
+impl<'a> Field<'a> {
+    /// Returns the description of `self` as an HTML paragraph, which is empty
+    /// if `self` has no description. See [`Message::description_html`].
+    pub fn description_html(&self) -> String {
+        description_to_html(self.0, self.description().unwrap_or_default())
+    }
+}
+
+fn description_to_html(dict: &Dictionary, description: &str) -> String {
+    let mut html = String::from("<p>");
+    let mut rest = description;
+    while let Some(start) = rest.find('[') {
+        let Some(len) = rest[start..].find(']') else {
+            break;
+        };
+        let name = &rest[start + 1..start + len];
+        html.push_str(&quickfix::escape(&rest[..start]));
+        match dict.field_by_name(name) {
+            Some(field) => {
+                let tag = field.tag();
+                let name = quickfix::escape(name);
+                write!(html, "<a href=\"#{}\">{} ({})</a>", tag, name, tag).unwrap();
+            }
+            None => html.push_str(&quickfix::escape(&rest[start..=start + len])),
+        }
+        rest = &rest[start + len + 1..];
+    }
+    html.push_str(&quickfix::escape(rest));
+    html.push_str("</p>");
+    html
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn field_references_become_links() {
+        let mut dict = Dictionary::fix44();
+        dict.messages_by_msgtype.get_mut("0").unwrap().description =
+            "Answers a [TestRequest] with the same [TestReqID] & <nothing> else [".to_string();
+        let msg = dict.message_by_msgtype("0").unwrap();
+        assert_eq!(
+            msg.description_html(),
+            "<p>Answers a [TestRequest] with the same \
+             <a href=\"#112\">TestReqID (112)</a> &amp; &lt;nothing&gt; else [</p>"
+        );
+    }
+
+    #[test]
+    fn missing_field_description_is_an_empty_paragraph() {
+        let dict = Dictionary::fix44();
+        let field = dict.field_by_tag(112).unwrap();
+        assert_eq!(field.description(), None);
+        assert_eq!(field.description_html(), "<p></p>");
+    }
+}
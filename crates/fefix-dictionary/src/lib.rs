@@ -1,12 +1,36 @@
 //! Access to FIX Dictionary reference and message specifications.
 
+mod cross_reference;
+mod diff;
+mod errata;
+mod field_value;
+mod html;
+mod merge;
+mod parts;
 mod quickfix;
+mod sections;
+mod stats;
+mod tag_order;
+#[cfg(feature = "test-utils")]
+mod test_utils;
+mod validation;
 
 pub use datatype::FixDatatype;
-use fnv::FnvHashMap;
-use quickfix::{ParseDictionaryError, QuickFixReader};
+pub use diff::{DictionaryDiff, EnumDiff};
+pub use field_value::{DecodeError, EncodeError, FieldValue};
+use fnv::{FnvHashMap, FnvHashSet};
+pub use merge::{ConflictError, DatatypeConflict};
+pub use parts::{BuildError, ComponentDef, DictionaryBuilder, FieldDef, LayoutItemDef, MessageDef};
+use quickfix::QuickFixReader;
+pub use quickfix::{ParseDictionaryError, ParsePhase, QuickFixOptions, TextPosition};
 use smartstring::alias::String as SmartString;
-use std::{fmt, sync::Arc};
+pub use stats::DictionaryStats;
+use std::sync::Arc;
+pub use tag_order::{OrderError, ValidationNote, ValidationOptions};
+pub use validation::{
+    DatatypeExampleError, DuplicateFieldError, FixmlIssue, GroupDelimiterError, GroupUsageConflict,
+    MissingLengthFieldError,
+};
 
 /// Type alias for FIX tags: 32-bit unsigned integers, strictly positive.
 pub type TagU32 = std::num::NonZeroU32;
@@ -39,6 +63,190 @@ impl NumInGroupLookup<u32> for Dictionary {
     }
 }
 
+/// A session-level ("admin") FIX message. See
+/// [`Dictionary::msg_type_for_admin`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum AdminMessageKind {
+    /// `Heartbeat <0>`.
+    Heartbeat,
+    /// `Logon <A>`.
+    Logon,
+    /// `Logout <5>`.
+    Logout,
+    /// `TestRequest <1>`.
+    TestRequest,
+    /// `ResendRequest <2>`.
+    ResendRequest,
+    /// `SequenceReset <4>`.
+    SequenceReset,
+    /// `Reject <3>`.
+    Reject,
+}
+
+impl AdminMessageKind {
+    /// Returns the name of the message as it appears in FIX specifications.
+    pub fn message_name(&self) -> &'static str {
+        match self {
+            AdminMessageKind::Heartbeat => "Heartbeat",
+            AdminMessageKind::Logon => "Logon",
+            AdminMessageKind::Logout => "Logout",
+            AdminMessageKind::TestRequest => "TestRequest",
+            AdminMessageKind::ResendRequest => "ResendRequest",
+            AdminMessageKind::SequenceReset => "SequenceReset",
+            AdminMessageKind::Reject => "Reject",
+        }
+    }
+}
+
+/// See [`Dictionary::well_known_tags`].
+const WELL_KNOWN_TAGS: &[(u32, &str, &str)] = &[
+    (7, "BeginSeqNo", "Message sequence number of first message in range to be resent."),
+    (8, "BeginString", "Identifies beginning of new message and protocol version."),
+    (9, "BodyLength", "Message length, in bytes, forward to the CheckSum field."),
+    (10, "CheckSum", "Three byte, simple checksum of the message."),
+    (16, "EndSeqNo", "Message sequence number of last message in range to be resent."),
+    (34, "MsgSeqNum", "Integer message sequence number."),
+    (35, "MsgType", "Defines message type."),
+    (36, "NewSeqNo", "New sequence number."),
+    (43, "PossDupFlag", "Indicates possible retransmission of message with this sequence number."),
+    (45, "RefSeqNum", "Reference message sequence number."),
+    (49, "SenderCompID", "Assigned value used to identify firm sending message."),
+    (50, "SenderSubID", "Assigned value used to identify specific message originator."),
+    (52, "SendingTime", "Time of message transmission, in UTC."),
+    (56, "TargetCompID", "Assigned value used to identify receiving firm."),
+    (57, "TargetSubID", "Assigned value used to identify specific individual or unit intended to receive message."),
+    (58, "Text", "Free format text string."),
+    (89, "Signature", "Electronic signature."),
+    (93, "SignatureLength", "Number of bytes in signature field."),
+    (95, "RawDataLength", "Number of bytes in raw data field."),
+    (96, "RawData", "Unformatted raw data, can include bitmaps, word processor documents, etc."),
+    (97, "PossResend", "Indicates that message may contain information that has been sent under another sequence number."),
+    (98, "EncryptMethod", "Method of encryption."),
+    (108, "HeartBtInt", "Heartbeat interval, in seconds."),
+    (112, "TestReqID", "Identifier included in Test Request message to be returned in resulting Heartbeat."),
+    (115, "OnBehalfOfCompID", "Assigned value used to identify firm originating message if the message was delivered by a third party."),
+    (122, "OrigSendingTime", "Original time of message transmission, in UTC, when transmitting orders as the result of a resend request."),
+    (123, "GapFillFlag", "Indicates that the Sequence Reset message is replacing administrative or application messages which will not be resent."),
+    (128, "DeliverToCompID", "Assigned value used to identify the firm targeted to receive the message if the message is delivered by a third party."),
+    (141, "ResetSeqNumFlag", "Indicates that both sides of the FIX session should reset sequence numbers."),
+    (371, "RefTagID", "The tag number of the FIX field being referenced."),
+    (372, "RefMsgType", "The MsgType of the FIX message being referenced."),
+    (373, "SessionRejectReason", "Code to identify reason for a session-level Reject message."),
+    (553, "Username", "Userid or username."),
+    (554, "Password", "Password or passphrase."),
+    (1128, "ApplVerID", "Specifies the service pack release being applied at message level."),
+    (1137, "DefaultApplVerID", "Specifies the service pack release being applied, by default, to message at the session level."),
+];
+
+/// A FIX protocol version with a standard [`Dictionary`].
+///
+/// Application versions are ordered chronologically, e.g. `FixVersion::Fix42 <
+/// FixVersion::Fix50SP1`. [`FixVersion::Fixt11`] is a session protocol and
+/// isn't comparable to any application version.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum FixVersion {
+    /// FIX 4.0.
+    Fix40,
+    /// FIX 4.1.
+    Fix41,
+    /// FIX 4.2.
+    Fix42,
+    /// FIX 4.3.
+    Fix43,
+    /// FIX 4.4.
+    Fix44,
+    /// FIX 5.0.
+    Fix50,
+    /// FIX 5.0 SP1.
+    Fix50SP1,
+    /// FIX 5.0 SP2.
+    Fix50SP2,
+    /// FIXT 1.1.
+    Fixt11,
+}
+
+impl FixVersion {
+    /// Returns all [`FixVersion`]'s, application versions first in
+    /// chronological order.
+    pub const ALL: &'static [FixVersion] = &[
+        FixVersion::Fix40,
+        FixVersion::Fix41,
+        FixVersion::Fix42,
+        FixVersion::Fix43,
+        FixVersion::Fix44,
+        FixVersion::Fix50,
+        FixVersion::Fix50SP1,
+        FixVersion::Fix50SP2,
+        FixVersion::Fixt11,
+    ];
+
+    /// Returns the version string of `self`, as returned by
+    /// [`Dictionary::version`], e.g. `FIX.5.0-SP2`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FixVersion::Fix40 => "FIX.4.0",
+            FixVersion::Fix41 => "FIX.4.1",
+            FixVersion::Fix42 => "FIX.4.2",
+            FixVersion::Fix43 => "FIX.4.3",
+            FixVersion::Fix44 => "FIX.4.4",
+            FixVersion::Fix50 => "FIX.5.0",
+            FixVersion::Fix50SP1 => "FIX.5.0-SP1",
+            FixVersion::Fix50SP2 => "FIX.5.0-SP2",
+            FixVersion::Fixt11 => "FIXT.1.1",
+        }
+    }
+
+    /// Parses a version string, e.g. `FIX.4.4`. Returns `None` if `version`
+    /// isn't one of the standard versions.
+    pub fn from_version_str(version: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|v| v.as_str() == version)
+    }
+
+    /// Returns the `ApplVerID(1128)` value that identifies `self` over FIXT,
+    /// e.g. `6` for FIX 4.4 and `9` for FIX 5.0 SP2. Returns `None` for
+    /// [`FixVersion::Fixt11`], which is a session protocol.
+    pub fn appl_ver_id(&self) -> Option<&'static str> {
+        match self {
+            FixVersion::Fix40 => Some("2"),
+            FixVersion::Fix41 => Some("3"),
+            FixVersion::Fix42 => Some("4"),
+            FixVersion::Fix43 => Some("5"),
+            FixVersion::Fix44 => Some("6"),
+            FixVersion::Fix50 => Some("7"),
+            FixVersion::Fix50SP1 => Some("8"),
+            FixVersion::Fix50SP2 => Some("9"),
+            FixVersion::Fixt11 => None,
+        }
+    }
+
+    /// Parses an `ApplVerID(1128)` value, e.g. `9`. Returns `None` if
+    /// `appl_ver_id` doesn't identify one of the standard versions.
+    pub fn from_appl_ver_id(appl_ver_id: &str) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|v| v.appl_ver_id() == Some(appl_ver_id))
+    }
+
+    /// The position of `self` in the chronology of application versions.
+    fn application_index(&self) -> Option<usize> {
+        match self {
+            FixVersion::Fixt11 => None,
+            v => Self::ALL.iter().position(|x| x == v),
+        }
+    }
+}
+
+impl PartialOrd for FixVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        if self == other {
+            return Some(std::cmp::Ordering::Equal);
+        }
+        self.application_index()?
+            .partial_cmp(&other.application_index()?)
+    }
+}
+
 /// The expected location of a field within a FIX message (i.e. header, body, or
 /// trailer).
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -70,6 +278,11 @@ pub type Dictionaries = FnvHashMap<String, Arc<Dictionary>>;
 /// application protocol only for FIX 5.0 and subsequent versions. All FIX
 /// Dictionaries for older versions will also contain information about the
 /// session layer.
+///
+/// Definitions are owned by flat maps keyed by tag, name, or message type,
+/// and all views ([`Field`], [`Message`], etc.) borrow from them. There are no
+/// reference-counted pointers, so there's no need for a separate index-based
+/// representation and dictionaries can be shared across threads as they are.
 #[derive(Debug, Clone)]
 pub struct Dictionary {
     version: String,
@@ -80,50 +293,25 @@ pub struct Dictionary {
 
     fields_by_tags: FnvHashMap<u32, FieldData>,
     field_tags_by_name: FnvHashMap<SmartString, u32>,
+    field_tags_by_abbr_name: FnvHashMap<SmartString, u32>,
+    field_tags_by_lowercase_name: FnvHashMap<SmartString, u32>,
 
     components_by_name: FnvHashMap<SmartString, ComponentData>,
+    component_names_by_id: FnvHashMap<usize, SmartString>,
 
     messages_by_msgtype: FnvHashMap<SmartString, MessageData>,
     message_msgtypes_by_name: FnvHashMap<SmartString, SmartString>,
+    message_msgtypes_by_lowercase_name: FnvHashMap<SmartString, SmartString>,
 
     //layout_items: Vec<LayoutItemData>,
     categories_by_name: FnvHashMap<SmartString, CategoryData>,
     header: Vec<FieldData>,
-}
 
-fn display_layout_item(indent: u32, item: LayoutItem, f: &mut fmt::Formatter) -> fmt::Result {
-    for _ in 0..indent {
-        write!(f, " ")?;
-    }
-    match item.kind() {
-        LayoutItemKind::Field(_) => {
-            writeln!(
-                f,
-                "<field name='{}' required='{}' />",
-                item.tag_text(),
-                item.required(),
-            )?;
-        }
-        LayoutItemKind::Group(_, _fields) => {
-            writeln!(
-                f,
-                "<group name='{}' required='{}' />",
-                item.tag_text(),
-                item.required(),
-            )?;
-            writeln!(f, "</group>")?;
-        }
-        LayoutItemKind::Component(_c) => {
-            writeln!(
-                f,
-                "<component name='{}' required='{}' />",
-                item.tag_text(),
-                item.required(),
-            )?;
-            writeln!(f, "</component>")?;
-        }
-    }
-    Ok(())
+    group_tags_by_count_tag: FnvHashMap<TagU32, FnvHashSet<TagU32>>,
+    field_tags_by_enum_value: FnvHashMap<SmartString, Vec<TagU32>>,
+    datatypes_by_tag: FnvHashMap<TagU32, FixDatatype>,
+    data_tags: FnvHashSet<TagU32>,
+    component_names_by_field_tag: FnvHashMap<TagU32, Vec<SmartString>>,
 }
 
 impl Dictionary {
@@ -136,19 +324,186 @@ impl Dictionary {
             data_types_by_name: FnvHashMap::default(),
             fields_by_tags: FnvHashMap::default(),
             field_tags_by_name: FnvHashMap::default(),
+            field_tags_by_abbr_name: FnvHashMap::default(),
+            field_tags_by_lowercase_name: FnvHashMap::default(),
             components_by_name: FnvHashMap::default(),
+            component_names_by_id: FnvHashMap::default(),
             messages_by_msgtype: FnvHashMap::default(),
             message_msgtypes_by_name: FnvHashMap::default(),
+            message_msgtypes_by_lowercase_name: FnvHashMap::default(),
             categories_by_name: FnvHashMap::default(),
+            group_tags_by_count_tag: FnvHashMap::default(),
+            field_tags_by_enum_value: FnvHashMap::default(),
+            datatypes_by_tag: FnvHashMap::default(),
+            data_tags: FnvHashSet::default(),
+            component_names_by_field_tag: FnvHashMap::default(),
         }
     }
 
     /// Attempts to read a QuickFIX-style specification file and convert it into
     /// a [`Dictionary`].
     pub fn from_quickfix_spec(input: &str) -> Result<Self, ParseDictionaryError> {
-        let xml_document =
-            roxmltree::Document::parse(input).map_err(|_| ParseDictionaryError::InvalidFormat)?;
-        QuickFixReader::new(&xml_document)
+        // `roxmltree` errors already carry the position of the problem.
+        let xml_document = roxmltree::Document::parse(input)
+            .map_err(|err| ParseDictionaryError::InvalidData(err.to_string()))?;
+        QuickFixReader::new(
+            &xml_document,
+            None,
+            &QuickFixOptions::default(),
+            &mut |_, _, _| {},
+        )
+        .map(|(dict, _)| dict)
+    }
+
+    /// Like [`Dictionary::from_quickfix_spec`], but the version of the
+    /// resulting [`Dictionary`] is `version` instead of the one computed from
+    /// the `type`, `major`, `minor`, and `servicepack` attributes of the
+    /// specification file, which may then be wrong or missing.
+    ///
+    /// ```
+    /// use fefix_dictionary::Dictionary;
+    ///
+    /// let spec = Dictionary::fix44().to_quickfix_xml();
+    /// let spec = std::str::from_utf8(&spec).unwrap();
+    /// let dict = Dictionary::from_quickfix_spec_with_version(spec, "FIX.4.4-VENUE").unwrap();
+    /// assert_eq!(dict.version(), "FIX.4.4-VENUE");
+    /// ```
+    pub fn from_quickfix_spec_with_version(
+        input: &str,
+        version: &str,
+    ) -> Result<Self, ParseDictionaryError> {
+        let xml_document = roxmltree::Document::parse(input)
+            .map_err(|err| ParseDictionaryError::InvalidData(err.to_string()))?;
+        QuickFixReader::new(
+            &xml_document,
+            Some(version),
+            &QuickFixOptions::default(),
+            &mut |_, _, _| {},
+        )
+        .map(|(dict, _)| dict)
+    }
+
+    /// Like [`Dictionary::from_quickfix_spec`], but some errors can be
+    /// recovered from according to `options`, which is useful for ingesting
+    /// messy vendor specifications. Recovered errors are returned alongside
+    /// the [`Dictionary`] as warnings.
+    ///
+    /// ```
+    /// use fefix_dictionary::{Dictionary, QuickFixOptions};
+    ///
+    /// let spec = r#"
+    /// <fix type='FIX' major='4' minor='4' servicepack='0'>
+    ///     <header />
+    ///     <messages />
+    ///     <trailer />
+    ///     <components />
+    ///     <fields>
+    ///         <field number='112' name='TestReqID' type='STRING' />
+    ///         <component name='VendorBlock' />
+    ///     </fields>
+    /// </fix>
+    /// "#;
+    /// assert!(Dictionary::from_quickfix_spec(spec).is_err());
+    /// let options = QuickFixOptions {
+    ///     skip_unparseable_fields: true,
+    /// };
+    /// let (dict, warnings) = Dictionary::from_quickfix_spec_with_options(spec, &options).unwrap();
+    /// assert!(dict.field_by_tag(112).is_some());
+    /// assert_eq!(warnings.len(), 1);
+    /// ```
+    pub fn from_quickfix_spec_with_options(
+        input: &str,
+        options: &QuickFixOptions,
+    ) -> Result<(Self, Vec<ParseDictionaryError>), ParseDictionaryError> {
+        let xml_document = roxmltree::Document::parse(input)
+            .map_err(|err| ParseDictionaryError::InvalidData(err.to_string()))?;
+        QuickFixReader::new(&xml_document, None, options, &mut |_, _, _| {})
+    }
+
+    /// Like [`Dictionary::from_quickfix_spec`], but reports progress to
+    /// `on_progress`, e.g. to show a progress bar while reading large
+    /// specification files. `on_progress` is called with the current
+    /// [`ParsePhase`] and the number of its steps that are done and in total,
+    /// once at the start of each phase and then after every step. Phases come
+    /// in the order in which they're declared.
+    ///
+    /// ```
+    /// use fefix_dictionary::{Dictionary, ParsePhase};
+    ///
+    /// let spec = Dictionary::fix44().to_quickfix_string();
+    /// let mut fields_done = 0;
+    /// let dict = Dictionary::from_quickfix_spec_with_progress(&spec, |phase, done, total| {
+    ///     if phase == ParsePhase::Fields {
+    ///         assert!(done <= total);
+    ///         fields_done = done;
+    ///     }
+    /// })
+    /// .unwrap();
+    /// assert_eq!(fields_done, dict.fields().len());
+    /// ```
+    pub fn from_quickfix_spec_with_progress(
+        input: &str,
+        mut on_progress: impl FnMut(ParsePhase, usize, usize),
+    ) -> Result<Self, ParseDictionaryError> {
+        let xml_document = roxmltree::Document::parse(input)
+            .map_err(|err| ParseDictionaryError::InvalidData(err.to_string()))?;
+        QuickFixReader::new(
+            &xml_document,
+            None,
+            &QuickFixOptions::default(),
+            &mut on_progress,
+        )
+        .map(|(dict, _)| dict)
+    }
+
+    /// Serializes `self` into a QuickFIX-style specification file, which can
+    /// then be read back with [`Dictionary::from_quickfix_spec`].
+    pub fn to_quickfix_xml(&self) -> Vec<u8> {
+        let mut xml = Vec::new();
+        // Writing to a `Vec` can't fail.
+        quickfix::write_quickfix_xml(self, &mut xml).unwrap();
+        xml
+    }
+
+    /// Like [`Dictionary::to_quickfix_xml`], but returns a [`String`]. The
+    /// output is always valid UTF-8.
+    ///
+    /// ```
+    /// use fefix_dictionary::Dictionary;
+    ///
+    /// let dict = Dictionary::fix44();
+    /// let xml = dict.to_quickfix_string();
+    /// assert!(xml.starts_with("<fix type='FIX' major='4' minor='4'"));
+    /// let dict2 = Dictionary::from_quickfix_spec(&xml).unwrap();
+    /// assert_eq!(dict2.version(), "FIX.4.4");
+    /// ```
+    pub fn to_quickfix_string(&self) -> String {
+        // All contents come from `str`'s.
+        String::from_utf8(self.to_quickfix_xml()).expect("QuickFIX XML is UTF-8")
+    }
+
+    /// Returns a copy of `self` without any message structure: all fields,
+    /// datatypes, components, and messages are kept, but messages and
+    /// components have no layout items. This is enough for services that only
+    /// need field metadata, at a fraction of the memory.
+    ///
+    /// ```
+    /// use fefix_dictionary::Dictionary;
+    ///
+    /// let dict = Dictionary::fix44().clone_without_layout();
+    /// assert_eq!(dict.field_by_tag(35).unwrap().name(), "MsgType");
+    /// assert_eq!(dict.message_by_name("NewOrderSingle").unwrap().layout().count(), 0);
+    /// ```
+    pub fn clone_without_layout(&self) -> Dictionary {
+        let mut dict = self.clone();
+        for message in dict.messages_by_msgtype.values_mut() {
+            message.layout_items = LayoutItems::new();
+        }
+        for component in dict.components_by_name.values_mut() {
+            component.layout_items = LayoutItems::new();
+        }
+        dict.index_layouts();
+        dict
     }
 
     /// Returns the version string associated with this [`Dictionary`] (e.g.
@@ -164,6 +519,202 @@ impl Dictionary {
         self.version.as_str()
     }
 
+    /// Returns a static table of well-known FIX tags, mostly of the standard
+    /// header and trailer and of session-level messages, as `(tag, name,
+    /// description)` triples sorted by tag. Unlike the rest of the
+    /// [`Dictionary`] API, this doesn't need any dictionary to be loaded.
+    ///
+    /// ```
+    /// use fefix_dictionary::Dictionary;
+    ///
+    /// let (tag, name, _) = Dictionary::well_known_tags()[0];
+    /// assert_eq!((tag, name), (7, "BeginSeqNo"));
+    /// ```
+    pub fn well_known_tags() -> &'static [(u32, &'static str, &'static str)] {
+        WELL_KNOWN_TAGS
+    }
+
+    /// Returns `true` if `tag` is listed by [`Dictionary::well_known_tags`].
+    ///
+    /// ```
+    /// use fefix_dictionary::Dictionary;
+    ///
+    /// assert!(Dictionary::is_well_known_tag(35));
+    /// assert!(!Dictionary::is_well_known_tag(5001));
+    /// ```
+    pub fn is_well_known_tag(tag: u32) -> bool {
+        WELL_KNOWN_TAGS
+            .binary_search_by_key(&tag, |(tag, _, _)| *tag)
+            .is_ok()
+    }
+
+    /// Returns the message types of all session-level messages (e.g. `Logon`,
+    /// `Heartbeat`, `ResendRequest`) for the FIX version of `self`. FIX 5.0 and
+    /// later versions delegate the session layer to FIXT.1.1, so they share its
+    /// session messages. The returned slice is empty for unknown versions.
+    ///
+    /// ```
+    /// use fefix_dictionary::Dictionary;
+    ///
+    /// let dict = Dictionary::fix44();
+    /// let msg_types = dict.known_session_message_types();
+    /// assert!(msg_types.contains(&"A"));
+    /// assert!(!msg_types.contains(&"D"));
+    /// ```
+    pub fn known_session_message_types(&self) -> &'static [&'static str] {
+        const FIX40_SESSION_MESSAGE_TYPES: &[&str] = &["0", "1", "2", "3", "4", "5", "A"];
+        // FIX 4.3 introduced `XMLnonFIX`.
+        const FIX43_SESSION_MESSAGE_TYPES: &[&str] = &["0", "1", "2", "3", "4", "5", "A", "n"];
+        const FIXT11_SESSION_MESSAGE_TYPES: &[&str] = &["0", "1", "2", "3", "4", "5", "A"];
+        match self.version() {
+            "FIX.4.0" | "FIX.4.1" | "FIX.4.2" => FIX40_SESSION_MESSAGE_TYPES,
+            "FIX.4.3" | "FIX.4.4" => FIX43_SESSION_MESSAGE_TYPES,
+            "FIXT.1.1" | "FIX.5.0" | "FIX.5.0-SP1" | "FIX.5.0-SP2" => FIXT11_SESSION_MESSAGE_TYPES,
+            _ => &[],
+        }
+    }
+
+    /// Returns `true` if `self` is a FIXT session dictionary, i.e. its version
+    /// (as read from the `type='FIXT'` root element of QuickFIX specification
+    /// files) starts with `FIXT.`; `false` otherwise. Session dictionaries
+    /// only define the header, the trailer, and session messages: application
+    /// messages are defined by a separate FIX 5.0 or later dictionary.
+    ///
+    /// ```
+    /// use fefix_dictionary::Dictionary;
+    ///
+    /// assert!(!Dictionary::fix44().is_session_only());
+    /// ```
+    pub fn is_session_only(&self) -> bool {
+        self.version().starts_with("FIXT.")
+    }
+
+    /// Returns the tags of the session layer fields, i.e. those of the
+    /// standard header and trailer (e.g. `BeginString`, `MsgSeqNum`,
+    /// `CheckSum`), including the fields of their repeating groups.
+    ///
+    /// FIX 4.x merges session and application protocol, so a standard set of
+    /// tags is used, restricted to the fields defined by `self`. FIX 5.0 and
+    /// later have no session layer of their own: the fields are taken from
+    /// FIXT 1.1 instead, using [`Dictionary::fixt11`] if the `fixt11` feature is
+    /// enabled. For any other dictionary, they're taken from the
+    /// `StandardHeader` and `StandardTrailer` components of `self`.
+    ///
+    /// ```
+    /// use fefix_dictionary::{Dictionary, TagU32};
+    ///
+    /// let dict = Dictionary::fix44();
+    /// let tags = dict.session_layer_fields();
+    /// assert!(tags.contains(&TagU32::new(34).unwrap()));
+    /// assert!(!tags.contains(&TagU32::new(11).unwrap()));
+    /// ```
+    pub fn session_layer_fields(&self) -> FnvHashSet<TagU32> {
+        // The standard header and trailer fields of FIX 4.0 through 4.4.
+        const FIX4_SESSION_LAYER_TAGS: &[u32] = &[
+            8, 9, 35, 49, 56, 115, 128, 90, 91, 34, 50, 142, 57, 143, 116, 144, 129, 145, 43, 97,
+            52, 122, 212, 213, 347, 369, 370, 627, 628, 629, 630, 93, 89, 10,
+        ];
+        let tags = |tags: &[u32]| -> FnvHashSet<TagU32> {
+            tags.iter().filter_map(|tag| TagU32::new(*tag)).collect()
+        };
+        match self.version() {
+            "FIX.4.0" | "FIX.4.1" | "FIX.4.2" | "FIX.4.3" | "FIX.4.4" => {
+                let mut session_tags = tags(FIX4_SESSION_LAYER_TAGS);
+                session_tags.retain(|tag| self.field_by_tag(tag.get()).is_some());
+                session_tags
+            }
+            #[cfg(feature = "fixt11")]
+            "FIX.5.0" | "FIX.5.0-SP1" | "FIX.5.0-SP2" => Self::fixt11().session_layer_fields(),
+            #[cfg(not(feature = "fixt11"))]
+            "FIX.5.0" | "FIX.5.0-SP1" | "FIX.5.0-SP2" => {
+                // FIXT 1.1 drops `OnBehalfOfSendingTime` and adds `ApplVerID`
+                // and `CstmApplVerID`.
+                let mut session_tags = tags(FIX4_SESSION_LAYER_TAGS);
+                session_tags.remove(&TagU32::new(370).unwrap());
+                session_tags.extend(tags(&[1128, 1129]));
+                session_tags
+            }
+            _ => {
+                let mut session_tags = Vec::new();
+                for name in ["StandardHeader", "StandardTrailer"] {
+                    if let Some(component) = self.component_by_name(name) {
+                        expand_layout_tags(component.items(), &mut session_tags);
+                    }
+                }
+                session_tags.into_iter().collect()
+            }
+        }
+    }
+
+    /// Returns the [`FixVersion`] of `self`, or `None` if its version string
+    /// isn't a standard one (e.g. `FIX.4.4-VENUE`).
+    pub fn fix_version(&self) -> Option<FixVersion> {
+        FixVersion::from_version_str(self.version())
+    }
+
+    /// Returns the `ApplVerID(1128)` value of the application version of
+    /// `self`, as used by FIXT sessions to select the application
+    /// [`Dictionary`]. Returns `None` for FIXT dictionaries and nonstandard
+    /// versions. See [`FixVersion::appl_ver_id`].
+    ///
+    /// ```
+    /// use fefix_dictionary::Dictionary;
+    ///
+    /// assert_eq!(Dictionary::fix44().appl_ver_id(), Some("6"));
+    /// ```
+    pub fn appl_ver_id(&self) -> Option<&str> {
+        self.fix_version()?.appl_ver_id()
+    }
+
+    /// Returns `true` if `self` is for `min` or a later version. Always `false`
+    /// if either `self` or `min` is FIXT.1.1 and the other isn't, or if `self`
+    /// doesn't have a standard version; see [`Dictionary::fix_version`].
+    ///
+    /// ```
+    /// use fefix_dictionary::{Dictionary, FixVersion};
+    ///
+    /// let dict = Dictionary::fix44();
+    /// assert!(dict.version_is_at_least(FixVersion::Fix42));
+    /// assert!(dict.version_is_at_least(FixVersion::Fix44));
+    /// assert!(!dict.version_is_at_least(FixVersion::Fix50));
+    /// ```
+    pub fn version_is_at_least(&self, min: FixVersion) -> bool {
+        self.fix_version().is_some_and(|version| version >= min)
+    }
+
+    /// Returns `true` if `self` is for `max` or an earlier version. Always
+    /// `false` if either `self` or `max` is FIXT.1.1 and the other isn't, or if
+    /// `self` doesn't have a standard version; see [`Dictionary::fix_version`].
+    ///
+    /// ```
+    /// use fefix_dictionary::{Dictionary, FixVersion};
+    ///
+    /// let dict = Dictionary::fix44();
+    /// assert!(dict.version_is_at_most(FixVersion::Fix50SP2));
+    /// assert!(!dict.version_is_at_most(FixVersion::Fix43));
+    /// assert!(!dict.version_is_at_most(FixVersion::Fixt11));
+    /// ```
+    pub fn version_is_at_most(&self, max: FixVersion) -> bool {
+        self.fix_version().is_some_and(|version| version <= max)
+    }
+
+    /// Returns the message type of the session-level message `kind` in `self`,
+    /// if it's defined. This is `None` for FIX 5.0 and later application
+    /// dictionaries, which don't contain any session-level messages.
+    ///
+    /// ```
+    /// use fefix_dictionary::{AdminMessageKind, Dictionary};
+    ///
+    /// let dict = Dictionary::fix44();
+    /// assert_eq!(dict.msg_type_for_admin(AdminMessageKind::Logon), Some("A"));
+    /// assert_eq!(dict.msg_type_for_admin(AdminMessageKind::Heartbeat), Some("0"));
+    /// ```
+    pub fn msg_type_for_admin(&self, kind: AdminMessageKind) -> Option<&str> {
+        self.message_msgtypes_by_name
+            .get(kind.message_name())
+            .map(|msg_type| msg_type.as_str())
+    }
+
     /// Creates a new [`Dictionary`] for FIX 4.0.
     #[cfg(feature = "fix40")]
     #[cfg_attr(doc_cfg, doc(cfg(feature = "fix40")))]
@@ -234,6 +785,40 @@ impl Dictionary {
         Dictionary::from_quickfix_spec(spec).unwrap()
     }
 
+    /// Creates a new [`Dictionary`] for `version` out of the bundled
+    /// specification files. Returns `None` if `version` was left out by
+    /// feature flags.
+    ///
+    /// ```
+    /// use fefix_dictionary::{Dictionary, FixVersion};
+    ///
+    /// let dict = Dictionary::standard(FixVersion::Fix44).unwrap();
+    /// assert_eq!(dict.version(), "FIX.4.4");
+    /// ```
+    pub fn standard(version: FixVersion) -> Option<Self> {
+        match version {
+            #[cfg(feature = "fix40")]
+            FixVersion::Fix40 => Some(Self::fix40()),
+            #[cfg(feature = "fix41")]
+            FixVersion::Fix41 => Some(Self::fix41()),
+            #[cfg(feature = "fix42")]
+            FixVersion::Fix42 => Some(Self::fix42()),
+            #[cfg(feature = "fix43")]
+            FixVersion::Fix43 => Some(Self::fix43()),
+            FixVersion::Fix44 => Some(Self::fix44()),
+            #[cfg(feature = "fix50")]
+            FixVersion::Fix50 => Some(Self::fix50()),
+            #[cfg(feature = "fix50sp1")]
+            FixVersion::Fix50SP1 => Some(Self::fix50sp1()),
+            #[cfg(feature = "fix50sp2")]
+            FixVersion::Fix50SP2 => Some(Self::fix50sp2()),
+            #[cfg(feature = "fixt11")]
+            FixVersion::Fixt11 => Some(Self::fixt11()),
+            #[allow(unreachable_patterns)]
+            _ => None,
+        }
+    }
+
     /// Returns a [`Vec`] of FIX [`Dictionary`]'s for the most common FIX
     /// versions (that have been enabled via feature flags). This is only
     /// intended for testing purposes.
@@ -284,6 +869,26 @@ impl Dictionary {
         self.message_by_msgtype(msg_type)
     }
 
+    /// Like [`Dictionary::message_by_name`], but `name` is compared
+    /// case-insensitively (ASCII only).
+    ///
+    /// Wrong-case matches aren't logged, as this crate has no logging
+    /// dependency. Compare [`Message::name`] with `name` to detect them.
+    ///
+    /// ```
+    /// use fefix_dictionary::Dictionary;
+    ///
+    /// let dict = Dictionary::fix44();
+    /// let msg = dict.message_by_name_case_insensitive("heartbeat").unwrap();
+    /// assert_eq!(msg.name(), "Heartbeat");
+    /// ```
+    pub fn message_by_name_case_insensitive(&self, name: &str) -> Option<Message<'_>> {
+        let msg_type = self
+            .message_msgtypes_by_lowercase_name
+            .get(name.to_ascii_lowercase().as_str())?;
+        self.message_by_msgtype(msg_type)
+    }
+
     /// Returns the [`Message`](Message) that has the given `msgtype`, if any.
     ///
     /// ```
@@ -301,6 +906,41 @@ impl Dictionary {
             .map(|data| Message(self, data))
     }
 
+    /// Returns the [`Message`]'s that are expected in response to the message
+    /// with type `msg_type`, e.g. `MarketDataSnapshotFullRefresh <W>` for
+    /// `MarketDataRequest <V>`. Responses are only known if they were given to
+    /// [`Dictionary::from_parts`]; the result is empty otherwise.
+    pub fn responses_for(&self, msg_type: &str) -> Vec<Message<'_>> {
+        self.messages_by_msgtype
+            .get(msg_type)
+            .map(|message| {
+                message
+                    .responses
+                    .iter()
+                    .filter_map(|msg_type| self.message_by_msgtype(msg_type))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Returns the name of the [`Component`] with ID `id`, if any. See
+    /// [`Component::id`].
+    ///
+    /// ```
+    /// use fefix_dictionary::Dictionary;
+    ///
+    /// let dict = Dictionary::fix44();
+    /// let component = dict.component_by_name("Instrument").unwrap();
+    /// let id = component.id() as usize;
+    /// assert_eq!(dict.component_name_for_id(id), Some("Instrument"));
+    /// assert_eq!(dict.component_name_for_id(0), None);
+    /// ```
+    pub fn component_name_for_id(&self, id: usize) -> Option<&str> {
+        self.component_names_by_id
+            .get(&id)
+            .map(|name| name.as_str())
+    }
+
     /// Returns the [`Component`] named `name`, if any.
     pub fn component_by_name(&self, name: &str) -> Option<Component> {
         self.components_by_name
@@ -325,6 +965,10 @@ impl Dictionary {
 
     /// Returns the [`Field`] associated with `tag`, if any.
     ///
+    /// `tag` isn't validated: this is a plain lookup, and tag 0 simply isn't
+    /// associated with any field. Callers with a [`TagU32`] can pass
+    /// `tag.get()` at no extra cost.
+    ///
     /// ```
     /// use fefix_dictionary::Dictionary;
     ///
@@ -332,6 +976,7 @@ impl Dictionary {
     /// let field1 = dict.field_by_tag(112).unwrap();
     /// let field2 = dict.field_by_name("TestReqID").unwrap();
     /// assert_eq!(field1.name(), field2.name());
+    /// assert!(dict.field_by_tag(0).is_none());
     /// ```
     pub fn field_by_tag(&self, tag: u32) -> Option<Field> {
         self.fields_by_tags.get(&tag).map(|data| Field(self, data))
@@ -343,329 +988,2090 @@ impl Dictionary {
         self.field_by_tag(*tag)
     }
 
-    /// Returns the [`Category`] named `name`, if any.
-    fn category_by_name(&self, name: &str) -> Option<Category> {
-        self.categories_by_name
-            .get(name)
-            .map(|data| Category(self, data))
-    }
-
-    /// Returns a [`Vec`] of all [`Datatype`]'s in this [`Dictionary`]. The ordering
-    /// of items is not specified.
+    /// Returns an owned map from the name of every field to its tag, e.g. to
+    /// serialize it and hand it over to other processes. See
+    /// [`Dictionary::field_by_name`].
     ///
     /// ```
-    /// use fefix_dictionary::Dictionary;
+    /// use fefix_dictionary::{Dictionary, TagU32};
     ///
     /// let dict = Dictionary::fix44();
-    /// // FIX 4.4 defines 23 (FIXME) datatypes.
-    /// assert_eq!(dict.datatypes().len(), 23);
+    /// let map = dict.name_to_tag_map();
+    /// assert_eq!(map.len(), dict.fields().len());
+    /// assert_eq!(map["MsgType"], TagU32::new(35).unwrap());
     /// ```
-    pub fn datatypes(&self) -> Vec<Datatype> {
-        self.data_types_by_name
-            .values()
-            .map(|data| Datatype(self, data))
+    pub fn name_to_tag_map(&self) -> FnvHashMap<String, TagU32> {
+        self.field_tags_by_name
+            .iter()
+            .filter_map(|(name, tag)| Some((name.to_string(), TagU32::new(*tag)?)))
             .collect()
     }
 
-    /// Returns a [`Vec`] of all [`Message`]'s in this [`Dictionary`]. The ordering
-    /// of items is not specified.
+    /// Like [`Dictionary::field_by_name`], but `name` is compared
+    /// case-insensitively (ASCII only). Wrong-case matches aren't logged; see
+    /// [`Dictionary::message_by_name_case_insensitive`].
+    pub fn field_by_name_case_insensitive(&self, name: &str) -> Option<Field<'_>> {
+        let tag = self
+            .field_tags_by_lowercase_name
+            .get(name.to_ascii_lowercase().as_str())?;
+        self.field_by_tag(*tag)
+    }
+
+    /// Returns the [`Field`] with the abbreviated name `abbr_name`, if any.
+    /// Abbreviated names are used as element names by FIXML.
+    pub fn field_by_abbr_name(&self, abbr_name: &str) -> Option<Field<'_>> {
+        let tag = self.field_tags_by_abbr_name.get(abbr_name)?;
+        self.field_by_tag(*tag)
+    }
+
+    /// Returns the [`Field`] identified by `key`, which is either a numeric tag
+    /// or a field name. Keys made only of ASCII digits are looked up as tags,
+    /// anything else as a name. Like on the wire, tags can't have leading
+    /// zeros.
     ///
     /// ```
     /// use fefix_dictionary::Dictionary;
     ///
     /// let dict = Dictionary::fix44();
-    /// let msgs = dict.messages();
-    /// let msg = msgs.iter().find(|m| m.name() == "MarketDataRequest");
-    /// assert_eq!(msg.unwrap().msg_type(), "V");
+    /// assert_eq!(dict.field_lookup("35").unwrap().name(), "MsgType");
+    /// assert_eq!(dict.field_lookup("MsgType").unwrap().tag().get(), 35);
+    /// assert!(dict.field_lookup("99999").is_none());
+    /// assert!(dict.field_lookup("035").is_none());
+    /// assert!(dict.field_lookup("+35").is_none());
     /// ```
-    pub fn messages(&self) -> Vec<Message> {
-        self.messages_by_msgtype
-            .values()
-            .map(|data| Message(self, data))
-            .collect()
+    pub fn field_lookup(&self, key: &str) -> Option<Field<'_>> {
+        if key.is_empty() || !key.bytes().all(|b| b.is_ascii_digit()) {
+            return self.field_by_name(key);
+        }
+        if key.starts_with('0') {
+            return None;
+        }
+        self.field_by_tag(key.parse().ok()?)
     }
 
-    /// Returns a [`Vec`] of all [`Category`]'s in this [`Dictionary`]. The ordering
-    /// of items is not specified.
-    pub fn categories(&self) -> Vec<Category> {
-        self.categories_by_name
-            .values()
-            .map(|data| Category(self, data))
-            .collect()
+    /// Returns the [`Field`] documented at `url`, an OnixS FIX Dictionary URL
+    /// as generated by [`Field::doc_url_onixs`]. Only the tag number is taken
+    /// from `url`, so URLs for other FIX versions resolve to the field with
+    /// the same tag in `self`. Returns `None` if `url` isn't a valid OnixS
+    /// field URL or if there's no such field.
+    ///
+    /// ```
+    /// use fefix_dictionary::Dictionary;
+    ///
+    /// let dict = Dictionary::fix44();
+    /// let url = "https://www.onixs.biz/fix-dictionary/4.4/tagNum_35.html";
+    /// assert_eq!(dict.field_by_onixs_url(url).unwrap().name(), "MsgType");
+    /// assert!(dict.field_by_onixs_url("https://example.com/tagNum_35.html").is_none());
+    /// ```
+    pub fn field_by_onixs_url(&self, url: &str) -> Option<Field<'_>> {
+        let (_version, page) = url.strip_prefix(ONIXS_URL_PREFIX)?.split_once('/')?;
+        let tag = page.strip_prefix("tagNum_")?.strip_suffix(".html")?;
+        self.field_by_tag(tag.parse().ok()?)
     }
 
-    /// Returns a [`Vec`] of all [`Field`]'s in this [`Dictionary`]. The ordering
-    /// of items is not specified.
-    pub fn fields(&self) -> Vec<Field> {
-        self.fields_by_tags
-            .values()
-            .map(|data| Field(self, data))
-            .collect()
+    /// Returns the tags of all fields that have `value` in their code set,
+    /// sorted. This helps diagnosing which field an unexpected value belongs
+    /// to.
+    ///
+    /// ```
+    /// use fefix_dictionary::{Dictionary, TagU32};
+    ///
+    /// let dict = Dictionary::fix44();
+    /// let tags = dict.field_tags_for_enum_value("D");
+    /// // `MsgType <35>`, for `NewOrderSingle`.
+    /// assert!(tags.contains(&TagU32::new(35).unwrap()));
+    /// assert!(dict.field_tags_for_enum_value("NOT_AN_ENUM_VALUE").is_empty());
+    /// ```
+    pub fn field_tags_for_enum_value(&self, value: &str) -> Vec<TagU32> {
+        self.field_tags_by_enum_value
+            .get(value)
+            .cloned()
+            .unwrap_or_default()
     }
 
-    /// Returns a [`Vec`] of all [`Component`]'s in this [`Dictionary`]. The ordering
-    /// of items is not specified.
-    pub fn components(&self) -> Vec<Component> {
-        self.components_by_name
-            .values()
-            .map(|data| Component(self, data))
-            .collect()
+    /// Returns the FIXML element name of the field `tag`, i.e. its
+    /// abbreviated name if it has one and its name otherwise. Returns `None` if
+    /// there's no such field.
+    ///
+    /// ```
+    /// use fefix_dictionary::{Dictionary, TagU32};
+    ///
+    /// let dict = Dictionary::fix44();
+    /// let tag = TagU32::new(35).unwrap();
+    /// assert_eq!(dict.fixml_name(tag), Some("MsgType"));
+    /// ```
+    pub fn fixml_name(&self, tag: TagU32) -> Option<&str> {
+        let field = self.fields_by_tags.get(&tag.get())?;
+        Some(field.abbr_name.as_deref().unwrap_or(field.name.as_str()))
     }
-}
 
-/// Builder utilities
-impl Dictionary {
-    fn add_field(&mut self, field: FieldData) {
-        self.field_tags_by_name
-            .insert(field.name.clone(), field.tag);
-        self.fields_by_tags.insert(field.tag, field);
+    /// Returns the name of the field `tag`, if any. Unlike
+    /// [`Field::name`] through [`Dictionary::field_by_tag`], the result
+    /// borrows from `self` rather than from a temporary [`Field`].
+    ///
+    /// ```
+    /// use fefix_dictionary::{Dictionary, TagU32};
+    ///
+    /// let dict = Dictionary::fix44();
+    /// assert_eq!(dict.field_name(TagU32::new(11).unwrap()), Some("ClOrdID"));
+    /// ```
+    pub fn field_name(&self, tag: TagU32) -> Option<&str> {
+        Some(self.fields_by_tags.get(&tag.get())?.name.as_str())
     }
 
-    fn add_message(&mut self, message: MessageData) {
-        self.message_msgtypes_by_name
-            .insert(message.name.clone(), message.msg_type.clone());
-        self.messages_by_msgtype
-            .insert(message.msg_type.clone(), message);
+    /// Returns the description of the field `tag`, if it has one. See
+    /// [`Dictionary::field_name`].
+    pub fn field_description(&self, tag: TagU32) -> Option<&str> {
+        self.fields_by_tags.get(&tag.get())?.description.as_deref()
     }
 
-    fn add_component(&mut self, component: ComponentData) {
-        self.components_by_name
-            .insert(component.name.clone(), component);
+    /// Returns the name of the [`Datatype`] of the field `tag`, if any. See
+    /// [`Dictionary::field_name`].
+    ///
+    /// ```
+    /// use fefix_dictionary::{Dictionary, TagU32};
+    ///
+    /// let dict = Dictionary::fix44();
+    /// let tag = TagU32::new(44).unwrap();
+    /// assert_eq!(dict.field_datatype_name(tag), Some("Price"));
+    /// ```
+    pub fn field_datatype_name(&self, tag: TagU32) -> Option<&str> {
+        Some(self.fields_by_tags.get(&tag.get())?.data_type_name.as_str())
     }
 
-    fn add_datatype(&mut self, datatype: DatatypeData) {
-        self.data_types_by_name
-            .insert(datatype.datatype.name().into(), datatype);
+    /// Returns the [`FixDatatype`] of the field `tag`, if any. This is the
+    /// same as [`Field::fix_datatype`], but it's precomputed for all fields
+    /// and thus cheaper, e.g. for picking a value parser for every field of
+    /// every decoded message.
+    ///
+    /// ```
+    /// use fefix_dictionary::{Dictionary, FixDatatype, TagU32};
+    ///
+    /// let dict = Dictionary::fix44();
+    /// let tag = TagU32::new(44).unwrap();
+    /// assert_eq!(dict.datatype_of_tag(tag), Some(FixDatatype::Price));
+    /// ```
+    pub fn datatype_of_tag(&self, tag: TagU32) -> Option<FixDatatype> {
+        self.datatypes_by_tag.get(&tag).copied()
     }
 
-    fn add_category(&mut self, category: CategoryData) {
+    /// Returns the precomputed set of tags of the fields whose values can
+    /// contain arbitrary bytes, i.e. fields of a length-prefixed datatype
+    /// such as `RawData` and `XmlData`. See
+    /// [`FixDatatype::is_length_prefixed`].
+    ///
+    /// ```
+    /// use fefix_dictionary::{Dictionary, TagU32};
+    ///
+    /// let dict = Dictionary::fix44();
+    /// assert!(dict.data_tags().contains(&TagU32::new(96).unwrap()));
+    /// assert!(!dict.data_tags().contains(&TagU32::new(95).unwrap()));
+    /// ```
+    pub fn data_tags(&self) -> &FnvHashSet<TagU32> {
+        &self.data_tags
+    }
+
+    /// Like [`Dictionary::fixml_name`], but for the [`Component`] named
+    /// `name`.
+    pub fn fixml_name_for_component(&self, name: &str) -> Option<&str> {
+        let component = self.components_by_name.get(name)?;
+        Some(
+            component
+                .abbr_name
+                .as_deref()
+                .unwrap_or(component.name.as_str()),
+        )
+    }
+
+    /// Returns all components that belong to the [`Category`] named `name`
+    /// (see [`Component::category`]), sorted by name.
+    pub fn components_in_category(&self, name: &str) -> Vec<Component<'_>> {
+        let mut components = self
+            .components_by_name
+            .values()
+            .filter(|component| !name.is_empty() && component.category_name == name)
+            .map(|data| Component(self, data))
+            .collect::<Vec<_>>();
+        components.sort_by(|a, b| a.name().cmp(b.name()));
+        components
+    }
+
+    /// Returns the [`Category`] named `name`, if any.
+    fn category_by_name(&self, name: &str) -> Option<Category> {
         self.categories_by_name
-            .insert(category.name.clone().into(), category);
+            .get(name)
+            .map(|data| Category(self, data))
     }
-}
 
-#[derive(Clone, Debug)]
-struct AbbreviationData {
-    abbreviation: SmartString,
-    is_last: bool,
-}
+    /// Returns a [`Vec`] of all [`Datatype`]'s in this [`Dictionary`]. The ordering
+    /// of items is not specified.
+    ///
+    /// ```
+    /// use fefix_dictionary::Dictionary;
+    ///
+    /// let dict = Dictionary::fix44();
+    /// // FIX 4.4 defines 23 (FIXME) datatypes.
+    /// assert_eq!(dict.datatypes().len(), 23);
+    /// ```
+    pub fn datatypes(&self) -> Vec<Datatype> {
+        self.data_types_by_name
+            .values()
+            .map(|data| Datatype(self, data))
+            .collect()
+    }
 
-/// An [`Abbreviation`] is a standardized abbreviated form for a specific word,
-/// pattern, or name. Abbreviation data is mostly meant for documentation
-/// purposes, but in general it can have other uses as well, e.g. FIXML field
-/// naming.
-#[derive(Debug)]
-pub struct Abbreviation<'a>(&'a Dictionary, &'a AbbreviationData);
+    /// Returns a [`Vec`] of all [`Message`]'s in this [`Dictionary`]. The ordering
+    /// of items is not specified.
+    ///
+    /// ```
+    /// use fefix_dictionary::Dictionary;
+    ///
+    /// let dict = Dictionary::fix44();
+    /// let msgs = dict.messages();
+    /// let msg = msgs.iter().find(|m| m.name() == "MarketDataRequest");
+    /// assert_eq!(msg.unwrap().msg_type(), "V");
+    /// ```
+    pub fn messages(&self) -> Vec<Message> {
+        self.messages_by_msgtype
+            .values()
+            .map(|data| Message(self, data))
+            .collect()
+    }
 
-impl<'a> Abbreviation<'a> {
-    /// Returns the full term (non-abbreviated) associated with `self`.
-    pub fn term(&self) -> &str {
-        self.1.abbreviation.as_str()
+    /// Returns all [`Message`]'s whose message type starts with `prefix`,
+    /// sorted by message type.
+    ///
+    /// ```
+    /// use fefix_dictionary::Dictionary;
+    ///
+    /// let dict = Dictionary::fix44();
+    /// let msgs = dict.messages_by_msgtype_prefix("A");
+    /// assert_eq!(msgs[0].msg_type(), "A");
+    /// assert_eq!(msgs[1].msg_type(), "AA");
+    /// ```
+    pub fn messages_by_msgtype_prefix(&self, prefix: &str) -> Vec<Message<'_>> {
+        self.sorted_messages_where(|msg_type| msg_type.starts_with(prefix))
     }
-}
 
-#[derive(Clone, Debug)]
-struct CategoryData {
-    /// **Primary key**. A string uniquely identifying this category.
-    name: String,
-    /// The FIXML file name for a Category.
-    fixml_filename: String,
-}
+    /// Returns all [`Message`]'s whose message type is `len` characters long,
+    /// sorted by message type. Session-level messages, for example, all have
+    /// single-character message types.
+    ///
+    /// ```
+    /// use fefix_dictionary::Dictionary;
+    ///
+    /// let dict = Dictionary::fix44();
+    /// let msgs = dict.messages_with_msgtype_len(2);
+    /// assert!(msgs.iter().all(|msg| msg.msg_type().len() == 2));
+    /// assert_eq!(msgs[0].msg_type(), "AA");
+    /// ```
+    pub fn messages_with_msgtype_len(&self, len: usize) -> Vec<Message<'_>> {
+        self.sorted_messages_where(|msg_type| msg_type.len() == len)
+    }
 
-/// A [`Category`] is a collection of loosely related FIX messages or components
-/// all belonging to the same [`Section`].
-#[derive(Clone, Debug)]
-pub struct Category<'a>(&'a Dictionary, &'a CategoryData);
+    /// Returns all [`Message`]'s whose name contains `query`, ignoring ASCII
+    /// case, sorted by name.
+    ///
+    /// ```
+    /// use fefix_dictionary::Dictionary;
+    ///
+    /// let dict = Dictionary::fix44();
+    /// let msgs = dict.find_messages("order");
+    /// assert!(msgs.iter().any(|msg| msg.name() == "NewOrderSingle"));
+    /// assert!(msgs.iter().any(|msg| msg.name() == "OrderCancelRequest"));
+    /// assert!(msgs.iter().all(|msg| msg.name().to_lowercase().contains("order")));
+    /// ```
+    pub fn find_messages(&self, query: &str) -> Vec<Message<'_>> {
+        let query = query.to_ascii_lowercase();
+        let mut messages = self
+            .messages_by_msgtype
+            .values()
+            .filter(|data| data.name.to_ascii_lowercase().contains(&query))
+            .map(|data| Message(self, data))
+            .collect::<Vec<_>>();
+        messages.sort_by(|a, b| a.name().cmp(b.name()));
+        messages
+    }
 
-#[derive(Clone, Debug)]
-struct ComponentData {
-    /// **Primary key.** The unique integer identifier of this component
-    /// type.
-    id: usize,
-    component_type: FixmlComponentAttributes,
-    layout_items: Vec<LayoutItemData>,
-    category_name: SmartString,
-    /// The human readable name of the component.
-    name: SmartString,
-    /// The name for this component when used in an XML context.
-    abbr_name: Option<SmartString>,
-}
+    fn sorted_messages_where(&self, predicate: impl Fn(&str) -> bool) -> Vec<Message<'_>> {
+        let mut messages = self
+            .messages_by_msgtype
+            .values()
+            .filter(|data| predicate(&data.msg_type))
+            .map(|data| Message(self, data))
+            .collect::<Vec<_>>();
+        messages.sort_by(|a, b| a.msg_type().cmp(b.msg_type()));
+        messages
+    }
 
-/// A [`Component`] is an ordered collection of fields and/or other components.
-/// There are two kinds of components: (1) common blocks and (2) repeating
-/// groups. Common blocks are merely commonly reused sequences of the same
-/// fields/components
-/// which are given names for simplicity, i.e. they serve as "macros". Repeating
-/// groups, on the other hand, are components which can appear zero or more times
-/// inside FIX messages (or other components, for that matter).
-#[derive(Clone, Debug)]
-pub struct Component<'a>(&'a Dictionary, &'a ComponentData);
+    /// Returns a [`Vec`] of all [`Category`]'s in this [`Dictionary`]. The ordering
+    /// of items is not specified.
+    pub fn categories(&self) -> Vec<Category> {
+        self.categories_by_name
+            .values()
+            .map(|data| Category(self, data))
+            .collect()
+    }
 
-impl<'a> Component<'a> {
-    /// Returns the unique numberic ID of `self`.
-    pub fn id(&self) -> u32 {
-        self.1.id as u32
+    /// Returns a [`Vec`] of all [`Field`]'s in this [`Dictionary`]. The ordering
+    /// of items is not specified.
+    pub fn fields(&self) -> Vec<Field> {
+        self.fields_by_tags
+            .values()
+            .map(|data| Field(self, data))
+            .collect()
     }
 
-    /// Returns the name of `self`. The name of every [`Component`] is unique
-    /// across a [`Dictionary`].
-    pub fn name(&self) -> &str {
-        self.1.name.as_str()
+    /// Returns all [`Field`]'s with a non-empty description, sorted by tag.
+    /// QuickFIX specs can give fields a description with the `description`
+    /// attribute.
+    ///
+    /// ```
+    /// use fefix_dictionary::Dictionary;
+    ///
+    /// let spec = r#"
+    /// <fix type='FIX' major='4' minor='4' servicepack='0'>
+    ///     <header />
+    ///     <messages />
+    ///     <trailer />
+    ///     <components />
+    ///     <fields>
+    ///         <field number='55' name='Symbol' type='STRING' description='Ticker symbol.' />
+    ///         <field number='58' name='Text' type='STRING' description='' />
+    ///         <field number='112' name='TestReqID' type='STRING' />
+    ///     </fields>
+    /// </fix>
+    /// "#;
+    /// let dict = Dictionary::from_quickfix_spec(spec).unwrap();
+    /// let fields = dict.fields_with_description();
+    /// assert_eq!(fields.len(), 1);
+    /// assert_eq!(fields[0].name(), "Symbol");
+    /// ```
+    pub fn fields_with_description(&self) -> Vec<Field<'_>> {
+        let mut fields = self
+            .fields()
+            .into_iter()
+            .filter(|field| field.description().is_some_and(|d| !d.is_empty()))
+            .collect::<Vec<_>>();
+        fields.sort_by_key(|field| field.tag());
+        fields
     }
 
-    /// Returns `true` if and only if `self` is a "group" component; `false`
-    /// otherwise.
-    pub fn is_group(&self) -> bool {
-        match self.1.component_type {
-            FixmlComponentAttributes::Block { is_repeating, .. } => is_repeating,
-            _ => false,
+    /// Returns the fraction of fields with a non-empty description, between
+    /// 0 and 1, or 0 if `self` has no fields. See
+    /// [`Dictionary::fields_with_description`].
+    ///
+    /// ```
+    /// use fefix_dictionary::Dictionary;
+    ///
+    /// assert_eq!(Dictionary::fix44().description_coverage(), 0.0);
+    /// ```
+    pub fn description_coverage(&self) -> f64 {
+        if self.fields_by_tags.is_empty() {
+            return 0.0;
         }
+        self.fields_with_description().len() as f64 / self.fields_by_tags.len() as f64
     }
 
-    /// Returns the [`Category`] to which `self` belongs.
-    pub fn category(&self) -> Category {
-        self.0
-            .category_by_name(self.1.category_name.as_str())
-            .unwrap()
+    /// Returns a [`Vec`] of all [`Component`]'s in this [`Dictionary`]. The ordering
+    /// of items is not specified.
+    pub fn components(&self) -> Vec<Component> {
+        self.components_by_name
+            .values()
+            .map(|data| Component(self, data))
+            .collect()
     }
 
-    /// Returns an [`Iterator`] over all items that are part of `self`.
-    pub fn items(&self) -> impl Iterator<Item = LayoutItem> {
-        self.1
-            .layout_items
-            .iter()
-            .map(move |data| LayoutItem(self.0, data))
+    /// Returns the names of all components and groups that lead to the field
+    /// `tag` inside the message with type `msg_type`, ending with the name of
+    /// the field itself. Returns `None` if either the message or the field
+    /// can't be found.
+    ///
+    /// ```
+    /// use fefix_dictionary::{Dictionary, TagU32};
+    ///
+    /// let dict = Dictionary::fix44();
+    /// let path = dict
+    ///     .layout_item_path_to_field("8", TagU32::new(600).unwrap())
+    ///     .unwrap();
+    /// assert_eq!(path, &["InstrmtLegExecGrp", "NoLegs", "InstrumentLeg", "LegSymbol"]);
+    /// ```
+    pub fn layout_item_path_to_field(&self, msg_type: &str, tag: TagU32) -> Option<Vec<String>> {
+        let message = self.message_by_msgtype(msg_type)?;
+        let mut path = Vec::new();
+        if layout_item_path(message.layout(), tag, &mut path) {
+            Some(path)
+        } else {
+            None
+        }
     }
 
-    /// Checks whether `field` appears in the definition of `self` and returns
-    /// `true` if it does, `false` otherwise.
-    pub fn contains_field(&self, field: &Field) -> bool {
-        self.items().any(|layout_item| {
-            if let LayoutItemKind::Field(f) = layout_item.kind() {
-                f.tag() == field.tag()
-            } else {
-                false
-            }
+    /// Returns a depth-first iterator over the layout items of all
+    /// [`Message`]'s and [`Component`]'s in this [`Dictionary`], including
+    /// the contents of repeating groups. Items that refer to other components
+    /// are yielded but not expanded, as the referenced components are visited
+    /// on their own. The ordering of containers is not specified.
+    ///
+    /// ```
+    /// use fefix_dictionary::{Dictionary, LayoutItemKind};
+    ///
+    /// let dict = Dictionary::fix44();
+    /// let parents = dict
+    ///     .iter_all_layout_items()
+    ///     .filter(|item| match item.item.kind() {
+    ///         LayoutItemKind::Field(field) => field.name() == "PartyRole",
+    ///         _ => false,
+    ///     })
+    ///     .map(|item| item.parent)
+    ///     .collect::<Vec<_>>();
+    /// assert!(parents.contains(&"NoPartyIDs"));
+    /// ```
+    pub fn iter_all_layout_items(&self) -> impl Iterator<Item = LayoutItemRef<'_>> {
+        let messages = self
+            .messages_by_msgtype
+            .values()
+            .map(|message| (message.name.as_str(), &message.layout_items));
+        let components = self
+            .components_by_name
+            .values()
+            .map(|component| (component.name.as_str(), &component.layout_items));
+        messages.chain(components).flat_map(move |(name, items)| {
+            let mut stack = vec![(name, 0, items.iter())];
+            std::iter::from_fn(move || loop {
+                let (parent, depth, items) = stack.last_mut()?;
+                let (parent, depth) = (*parent, *depth);
+                if let Some(data) = items.next() {
+                    if let LayoutItemKindData::Group {
+                        len_field_tag,
+                        items,
+                    } = &data.kind
+                    {
+                        // Groups whose `NumInGroup` field is undefined are
+                        // named after their container instead.
+                        let group_name = self
+                            .fields_by_tags
+                            .get(len_field_tag)
+                            .map_or(parent, |field| field.name.as_str());
+                        stack.push((group_name, depth + 1, items.iter()));
+                    }
+                    return Some(LayoutItemRef {
+                        item: LayoutItem(self, data),
+                        parent,
+                        depth,
+                    });
+                }
+                stack.pop();
+            })
         })
     }
-}
 
-/// Component type (FIXML-specific information).
-#[derive(Clone, Debug, PartialEq)]
-#[allow(dead_code)]
-pub enum FixmlComponentAttributes {
-    Xml,
-    Block {
-        is_repeating: bool,
-        is_implicit: bool,
-        is_optimized: bool,
-    },
-    Message,
+    /// Returns an [`Iterator`] over all distinct repeating groups in this
+    /// [`Dictionary`], across all messages and components. Groups are
+    /// deduplicated by their `NumInGroup` field: if the same field announces
+    /// different groups in different places, only one of them is returned. The
+    /// ordering of items is not specified.
+    ///
+    /// ```
+    /// use fefix_dictionary::Dictionary;
+    ///
+    /// let dict = Dictionary::fix44();
+    /// let group = dict
+    ///     .iter_groups()
+    ///     .find(|group| group.count_field.name() == "NoPartyIDs")
+    ///     .unwrap();
+    /// assert_eq!(group.delimiter_field.name(), "PartyID");
+    /// ```
+    pub fn iter_groups(&self) -> impl Iterator<Item = GroupDef<'_>> {
+        let mut count_tags = FnvHashSet::default();
+        self.iter_all_layout_items().filter_map(move |item_ref| {
+            match layout_item_kind(&item_ref.item.1.kind, self) {
+                LayoutItemKind::Group(count_field, items)
+                    if !count_tags.contains(&count_field.tag()) =>
+                {
+                    let mut member_tags = Vec::new();
+                    group_member_tags(items.iter().cloned(), &mut member_tags);
+                    // Empty groups have no delimiter, but the same count field
+                    // may announce a proper group further on.
+                    let delimiter_field = self.field_by_tag(member_tags.first()?.get())?;
+                    count_tags.insert(count_field.tag());
+                    Some(GroupDef {
+                        count_field,
+                        delimiter_field,
+                        items,
+                    })
+                }
+                _ => None,
+            }
+        })
+    }
+
+    /// Returns an [`Iterator`] over all fields with enumerated values (see
+    /// [`Field::enums`]) whose tags fall in the inclusive range `start..=end`,
+    /// sorted by tag.
+    ///
+    /// ```
+    /// use fefix_dictionary::{Dictionary, TagU32};
+    ///
+    /// let dict = Dictionary::fix44();
+    /// let start = TagU32::new(50).unwrap();
+    /// let end = TagU32::new(60).unwrap();
+    /// let names = dict
+    ///     .iter_enum_restricted_fields_in_range(start, end)
+    ///     .map(|field| field.name().to_string())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(names, &["Side", "TimeInForce"]);
+    /// ```
+    pub fn iter_enum_restricted_fields_in_range(
+        &self,
+        start: TagU32,
+        end: TagU32,
+    ) -> impl Iterator<Item = Field<'_>> {
+        let mut fields = self
+            .fields_by_tags
+            .values()
+            .filter(|data| (start.get()..=end.get()).contains(&data.tag))
+            .filter(|data| data.value_restrictions.is_some())
+            .map(|data| Field(self, data))
+            .collect::<Vec<_>>();
+        fields.sort_by_key(|field| field.tag());
+        fields.into_iter()
+    }
+
+    /// Computes a map from the tag of each `NumInGroup` field to the set of
+    /// tags that can appear inside the corresponding repeating group, across
+    /// all messages and components. Tags of nested groups are included in the
+    /// sets of their parent groups. If the same `NumInGroup` field announces
+    /// different groups in different places, its set is the union of all of
+    /// them.
+    ///
+    /// The result is also available through [`Dictionary::group_tags`],
+    /// without recomputing it.
+    pub fn build_group_tag_map(&self) -> FnvHashMap<TagU32, FnvHashSet<TagU32>> {
+        let mut map = FnvHashMap::<TagU32, FnvHashSet<TagU32>>::default();
+        for item_ref in self.iter_all_layout_items() {
+            if let LayoutItemKindData::Group {
+                len_field_tag,
+                items,
+            } = &item_ref.item.1.kind
+            {
+                let mut tags = Vec::new();
+                let items = items.iter().map(|item| LayoutItem(self, item));
+                expand_layout_tags(items, &mut tags);
+                if let Some(count_tag) = TagU32::new(*len_field_tag) {
+                    map.entry(count_tag).or_default().extend(tags);
+                }
+            }
+        }
+        map
+    }
+
+    /// Returns the set of tags that can appear inside the repeating group
+    /// announced by the `NumInGroup` field `count_tag`, or `None` if
+    /// `count_tag` doesn't announce any group. See
+    /// [`Dictionary::build_group_tag_map`].
+    ///
+    /// ```
+    /// use fefix_dictionary::{Dictionary, TagU32};
+    ///
+    /// let dict = Dictionary::fix44();
+    /// let no_party_ids = TagU32::new(453).unwrap();
+    /// let tags = dict.group_tags(no_party_ids).unwrap();
+    /// assert!(tags.contains(&TagU32::new(448).unwrap()));
+    /// // PartySubID, from the nested `NoPartySubIDs` group.
+    /// assert!(tags.contains(&TagU32::new(523).unwrap()));
+    /// ```
+    pub fn group_tags(&self, count_tag: TagU32) -> Option<&FnvHashSet<TagU32>> {
+        self.group_tags_by_count_tag.get(&count_tag)
+    }
+
+    /// Returns the tags of all fields which are mandatory in at least one
+    /// message, header and trailer included (see
+    /// [`Dictionary::full_message_layout`]). A field is mandatory if it's
+    /// required and so are all the components and repeating groups that
+    /// enclose it.
+    ///
+    /// ```
+    /// use fefix_dictionary::{Dictionary, TagU32};
+    ///
+    /// let dict = Dictionary::fix44();
+    /// let tags = dict.mandatory_field_tags();
+    /// // MsgType and ClOrdID.
+    /// assert!(tags.contains(&TagU32::new(35).unwrap()));
+    /// assert!(tags.contains(&TagU32::new(11).unwrap()));
+    /// // TestReqID is optional in `Heartbeat` and required in `TestRequest`.
+    /// assert!(tags.contains(&TagU32::new(112).unwrap()));
+    /// ```
+    pub fn mandatory_field_tags(&self) -> FnvHashSet<TagU32> {
+        self.message_field_tags().0
+    }
+
+    /// Returns the tags of all fields which appear in some message, but are
+    /// never mandatory (as defined by [`Dictionary::mandatory_field_tags`]).
+    ///
+    /// ```
+    /// use fefix_dictionary::{Dictionary, TagU32};
+    ///
+    /// let dict = Dictionary::fix44();
+    /// let tags = dict.optional_only_field_tags();
+    /// // PossDupFlag.
+    /// assert!(tags.contains(&TagU32::new(43).unwrap()));
+    /// assert!(!tags.contains(&TagU32::new(35).unwrap()));
+    /// ```
+    pub fn optional_only_field_tags(&self) -> FnvHashSet<TagU32> {
+        let (mandatory, all) = self.message_field_tags();
+        all.difference(&mandatory).copied().collect()
+    }
+
+    /// Returns the mandatory tags of all messages and all their tags.
+    fn message_field_tags(&self) -> (FnvHashSet<TagU32>, FnvHashSet<TagU32>) {
+        let mut mandatory = FnvHashSet::default();
+        let mut all = FnvHashSet::default();
+        for msg_type in self.messages_by_msgtype.keys() {
+            if let Some(layout) = self.full_message_layout(msg_type) {
+                collect_field_tags(layout.into_iter(), true, &mut mandatory, &mut all);
+            }
+        }
+        (mandatory, all)
+    }
+
+    /// Returns the complete layout of the message with type `msg_type`: the
+    /// items of the `StandardHeader` component, followed by the message body,
+    /// followed by the items of the `StandardTrailer` component. Returns
+    /// `None` if there's no such message.
+    ///
+    /// ```
+    /// use fefix_dictionary::{Dictionary, LayoutItemKind};
+    ///
+    /// let dict = Dictionary::fix44();
+    /// let layout = dict.full_message_layout("0").unwrap();
+    /// match layout[0].kind() {
+    ///     LayoutItemKind::Field(field) => assert_eq!(field.name(), "BeginString"),
+    ///     _ => unreachable!(),
+    /// }
+    /// ```
+    pub fn full_message_layout(&self, msg_type: &str) -> Option<Vec<LayoutItem<'_>>> {
+        let message = self.messages_by_msgtype.get(msg_type)?;
+        let (header, trailer) = if Message(self, message).includes_standard_header() {
+            (None, None)
+        } else {
+            (
+                self.components_by_name.get("StandardHeader"),
+                self.components_by_name.get("StandardTrailer"),
+            )
+        };
+        let items = header
+            .into_iter()
+            .flat_map(|c| c.layout_items.iter())
+            .chain(message.layout_items.iter())
+            .chain(trailer.into_iter().flat_map(|c| c.layout_items.iter()))
+            .map(|data| LayoutItem(self, data))
+            .collect();
+        Some(items)
+    }
+
+    /// Returns all field tags of the message with type `msg_type` in the order
+    /// in which they must appear on the wire: header fields first, then body
+    /// fields, then trailer fields, as laid out by
+    /// [`Dictionary::full_message_layout`]. Components are expanded in place, and
+    /// repeating groups contribute their `NumInGroup` field followed by the
+    /// tags of a single group instance. Returns `None` if there's no such
+    /// message.
+    ///
+    /// ```
+    /// use fefix_dictionary::Dictionary;
+    ///
+    /// let dict = Dictionary::fix44();
+    /// let tags = dict.wire_tag_order("0").unwrap();
+    /// assert_eq!(tags[0].get(), 8);
+    /// assert_eq!(tags.last().unwrap().get(), 10);
+    /// ```
+    pub fn wire_tag_order(&self, msg_type: &str) -> Option<Vec<TagU32>> {
+        let layout = self.full_message_layout(msg_type)?;
+        let mut tags = Vec::new();
+        expand_layout_tags(layout.into_iter(), &mut tags);
+        Some(tags)
+    }
+
+    /// Returns the ordinal position of the field `tag` among the top-level
+    /// fields of the body of the message with type `msg_type`, i.e. in the
+    /// order of the specification with components expanded in place. Repeating
+    /// groups only contribute their `NumInGroup` field, as the fields inside
+    /// them aren't top-level. Returns `None` if there's no such message or if
+    /// `tag` isn't a top-level field of it.
+    ///
+    /// ```
+    /// use fefix_dictionary::{Dictionary, TagU32};
+    ///
+    /// let dict = Dictionary::fix44();
+    /// let tag = |t| TagU32::new(t).unwrap();
+    /// // `TestReqID <112>` is the only field of `Heartbeat`.
+    /// assert_eq!(dict.field_position_in("0", tag(112)), Some(0));
+    /// assert_eq!(dict.field_position_in("0", tag(11)), None);
+    /// // `ClOrdID <11>` comes before `Symbol <55>`, from the `Instrument`
+    /// // component, in `NewOrderSingle`.
+    /// let cl_ord_id = dict.field_position_in("D", tag(11)).unwrap();
+    /// let symbol = dict.field_position_in("D", tag(55)).unwrap();
+    /// assert!(cl_ord_id < symbol);
+    /// ```
+    pub fn field_position_in(&self, msg_type: &str, tag: TagU32) -> Option<usize> {
+        let message = self.messages_by_msgtype.get(msg_type)?;
+        let mut tags = Vec::new();
+        top_level_tags(self, &message.layout_items, &mut tags);
+        tags.iter().position(|t| *t == tag.get())
+    }
+
+    /// Returns the message from which the message with type `msg_type`
+    /// inherits its fields, if any. Inheritance is declared in QuickFIX
+    /// specifications with a `base` attribute on `<message>` elements, which
+    /// contains the name of the base message (e.g. FIX 5.0 SP2
+    /// `RequestForPositions` extending a `RequestForPositionsBase`).
+    /// Returns `None` if either message can't be found.
+    pub fn message_inherits_from(&self, msg_type: &str) -> Option<Message<'_>> {
+        let message = self.messages_by_msgtype.get(msg_type)?;
+        self.message_by_name(message.base_message.as_deref()?)
+    }
+
+    /// Returns an estimate of the size in bytes of a minimally valid message
+    /// with type `msg_type`, for pre-allocating encode buffers. Only required
+    /// fields are counted, including those of the header and trailer, with
+    /// values of typical size (see [`FixDatatype::byte_count_hint`]). Required
+    /// repeating groups are counted with a single instance. Returns `None` if
+    /// there's no such message.
+    ///
+    /// ```
+    /// use fefix_dictionary::Dictionary;
+    ///
+    /// let dict = Dictionary::fix44();
+    /// let heartbeat = dict.message_wire_size_estimate("0").unwrap();
+    /// let new_order_single = dict.message_wire_size_estimate("D").unwrap();
+    /// assert!(heartbeat < new_order_single);
+    /// ```
+    pub fn message_wire_size_estimate(&self, msg_type: &str) -> Option<usize> {
+        let layout = self.full_message_layout(msg_type)?;
+        Some(required_wire_size(layout.into_iter()))
+    }
+
+    /// Returns the [`FramingSpec`] of this FIX version, i.e. which fields must
+    /// open and close every message. It's derived from the `StandardHeader`
+    /// and `StandardTrailer` components: the first three header fields
+    /// (normally `BeginString`, `BodyLength`, and `MsgType`) have fixed
+    /// positions, and so does the last trailer field (normally `CheckSum`).
+    /// Both are empty for application-only versions (e.g. FIX 5.0), which rely
+    /// on FIXT for their session layer.
+    ///
+    /// ```
+    /// use fefix_dictionary::Dictionary;
+    ///
+    /// let framing = Dictionary::fix44().framing_spec();
+    /// let leading_tags = framing.leading_tags.iter().map(|t| t.get()).collect::<Vec<_>>();
+    /// assert_eq!(leading_tags, &[8, 9, 35]);
+    /// assert_eq!(framing.trailing_tags[0].get(), 10);
+    /// ```
+    pub fn framing_spec(&self) -> FramingSpec {
+        let fields_of = |name: &str| {
+            self.component_by_name(name)
+                .map(|component| {
+                    component
+                        .items()
+                        .filter_map(|item| match item.kind() {
+                            LayoutItemKind::Field(field) => Some(field.tag()),
+                            _ => None,
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .unwrap_or_default()
+        };
+        let mut leading_tags = fields_of("StandardHeader");
+        leading_tags.truncate(3);
+        let mut trailing_tags = fields_of("StandardTrailer");
+        trailing_tags.drain(..trailing_tags.len().saturating_sub(1));
+        FramingSpec {
+            leading_tags,
+            trailing_tags,
+        }
+    }
+
+    /// Returns the definition of the repeating group announced by the
+    /// `NumInGroup` field `count_tag` inside the message with type `msg_type`.
+    /// Nested groups and groups inside components are found as well. Returns
+    /// `None` if either the message or the group can't be found.
+    ///
+    /// ```
+    /// use fefix_dictionary::{Dictionary, TagU32};
+    ///
+    /// let dict = Dictionary::fix44();
+    /// let no_legs = TagU32::new(555).unwrap();
+    /// let group = dict.group_for_count_tag("8", no_legs).unwrap();
+    /// assert_eq!(group.delimiter_tag.get(), 600);
+    /// ```
+    pub fn group_for_count_tag(&self, msg_type: &str, count_tag: TagU32) -> Option<GroupInfo> {
+        let message = self.message_by_msgtype(msg_type)?;
+        find_group(message.layout(), count_tag)
+    }
+
+    /// Returns the names of all components that are referenced directly by the
+    /// component named `name`, including references from within its repeating
+    /// groups. Names are sorted and deduplicated. Returns an empty [`Vec`] if
+    /// there's no such component.
+    ///
+    /// ```
+    /// use fefix_dictionary::Dictionary;
+    ///
+    /// let dict = Dictionary::fix44();
+    /// let deps = dict.component_dependencies("InstrmtLegGrp");
+    /// assert_eq!(deps, &["InstrumentLeg"]);
+    /// ```
+    pub fn component_dependencies(&self, name: &str) -> Vec<String> {
+        let mut names = Vec::new();
+        if let Some(component) = self.components_by_name.get(name) {
+            referenced_components(&component.layout_items, &mut names);
+        }
+        names.sort_unstable();
+        names.dedup();
+        names.into_iter().map(str::to_string).collect()
+    }
+
+    /// Returns the names of all components and messages that reference the
+    /// component named `name` directly, including references from within their
+    /// repeating groups. Names are sorted and deduplicated.
+    ///
+    /// ```
+    /// use fefix_dictionary::Dictionary;
+    ///
+    /// let dict = Dictionary::fix44();
+    /// let dependents = dict.component_dependents("InstrumentLeg");
+    /// assert!(dependents.contains(&"InstrmtLegGrp".to_string()));
+    /// ```
+    pub fn component_dependents(&self, name: &str) -> Vec<String> {
+        let messages = self
+            .messages_by_msgtype
+            .values()
+            .map(|message| (message.name.as_str(), &message.layout_items));
+        let components = self
+            .components_by_name
+            .values()
+            .map(|component| (component.name.as_str(), &component.layout_items));
+        let mut names = messages
+            .chain(components)
+            .filter(|(_, items)| {
+                let mut references = Vec::new();
+                referenced_components(items, &mut references);
+                references.contains(&name)
+            })
+            .map(|(container, _)| container.to_string())
+            .collect::<Vec<_>>();
+        names.sort_unstable();
+        names.dedup();
+        names
+    }
+
+    /// Returns all components that contain the field `tag` directly, i.e.
+    /// not through other components they reference. Fields inside the
+    /// repeating groups of a component count as direct, and so do
+    /// `NumInGroup` fields. Components are sorted by name.
+    ///
+    /// ```
+    /// use fefix_dictionary::{Dictionary, TagU32};
+    ///
+    /// let dict = Dictionary::fix44();
+    /// let party_id = TagU32::new(448).unwrap();
+    /// let components = dict.components_containing_field(party_id);
+    /// assert!(components.iter().any(|c| c.name() == "Parties"));
+    /// // `Parties` is referenced by `NewOrderSingle`, but that's not a
+    /// // component.
+    /// assert!(!components.iter().any(|c| c.name() == "NewOrderSingle"));
+    /// ```
+    pub fn components_containing_field(&self, tag: TagU32) -> Vec<Component<'_>> {
+        self.component_names_by_field_tag
+            .get(&tag)
+            .into_iter()
+            .flatten()
+            .filter_map(|name| self.component_by_name(name))
+            .collect()
+    }
+
+    /// Returns a [Graphviz](https://graphviz.org/) DOT graph with a node for
+    /// each component and an edge from each component to every component it
+    /// references directly, as in [`Dictionary::component_dependencies`].
+    /// Nodes and edges are sorted by name, so the output is stable across
+    /// runs.
+    ///
+    /// ```
+    /// use fefix_dictionary::Dictionary;
+    ///
+    /// let dict = Dictionary::fix44();
+    /// let dot = dict.component_dependency_dot();
+    /// assert!(dot.starts_with("digraph components {"));
+    /// assert!(dot.contains("    \"InstrmtLegGrp\" -> \"InstrumentLeg\";\n"));
+    /// ```
+    pub fn component_dependency_dot(&self) -> String {
+        let mut names = self
+            .components_by_name
+            .keys()
+            .map(|name| name.as_str())
+            .collect::<Vec<_>>();
+        names.sort_unstable();
+        let mut dot = String::from("digraph components {\n");
+        for name in names.iter() {
+            dot.push_str(&format!("    {};\n", dot_id(name)));
+        }
+        for name in names {
+            for dependency in self.component_dependencies(name) {
+                dot.push_str(&format!(
+                    "    {} -> {};\n",
+                    dot_id(name),
+                    dot_id(&dependency)
+                ));
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
 }
 
-#[derive(Clone, Debug, PartialEq)]
-struct DatatypeData {
-    /// **Primary key.** Identifier of the datatype.
-    datatype: FixDatatype,
-    /// Human readable description of this Datatype.
-    description: String,
-    /// A string that contains examples values for a datatype
-    examples: Vec<String>,
-    // TODO: 'XML'.
+/// Quotes `name` as a DOT identifier.
+fn dot_id(name: &str) -> String {
+    format!("\"{}\"", name.replace('\\', "\\\\").replace('"', "\\\""))
 }
 
-/// A FIX data type defined as part of a [`Dictionary`].
-#[derive(Debug)]
-pub struct Datatype<'a>(&'a Dictionary, &'a DatatypeData);
+/// Collects the tags of all fields in `items`, with components and groups
+/// expanded, into `all`, and those which are mandatory into `mandatory`.
+/// `required` tells whether everything that encloses `items` is required.
+fn collect_field_tags<'a>(
+    items: impl Iterator<Item = LayoutItem<'a>>,
+    required: bool,
+    mandatory: &mut FnvHashSet<TagU32>,
+    all: &mut FnvHashSet<TagU32>,
+) {
+    for item in items {
+        let required = required && item.required();
+        match item.kind() {
+            LayoutItemKind::Field(field) => {
+                all.insert(field.tag());
+                if required {
+                    mandatory.insert(field.tag());
+                }
+            }
+            LayoutItemKind::Group(len_field, items) => {
+                all.insert(len_field.tag());
+                if required {
+                    mandatory.insert(len_field.tag());
+                }
+                collect_field_tags(items.into_iter(), required, mandatory, all);
+            }
+            LayoutItemKind::Component(component) => {
+                collect_field_tags(component.items(), required, mandatory, all);
+            }
+        }
+    }
+}
 
-impl<'a> Datatype<'a> {
-    /// Returns the name of `self`.  This is also guaranteed to be a valid Rust
-    /// identifier.
-    pub fn name(&self) -> &str {
-        self.1.datatype.name()
+/// Collects the tags of all fields in `items` and nested groups, including
+/// their `NumInGroup` fields, but not those of referenced components.
+fn contained_field_tags(items: &[LayoutItemData], tags: &mut Vec<u32>) {
+    for item in items {
+        match &item.kind {
+            LayoutItemKindData::Field { tag } => tags.push(*tag),
+            LayoutItemKindData::Group {
+                len_field_tag,
+                items,
+            } => {
+                tags.push(*len_field_tag);
+                contained_field_tags(items, tags);
+            }
+            LayoutItemKindData::Component { .. } => {}
+        }
     }
+}
 
-    /// Returns `self` as an `enum`.
-    pub fn basetype(&self) -> FixDatatype {
-        self.1.datatype
+fn referenced_components<'a>(items: &'a [LayoutItemData], names: &mut Vec<&'a str>) {
+    for item in items {
+        match &item.kind {
+            LayoutItemKindData::Field { .. } => {}
+            LayoutItemKindData::Group { items, .. } => referenced_components(items, names),
+            LayoutItemKindData::Component { name } => names.push(name.as_str()),
+        }
     }
 }
 
-mod datatype {
-    use strum::IntoEnumIterator;
-    use strum_macros::{EnumIter, IntoStaticStr};
+/// A repeating group definition. See [`Dictionary::iter_groups`].
+#[derive(Clone, Debug)]
+pub struct GroupDef<'a> {
+    /// The `NumInGroup` field which announces the number of group instances.
+    pub count_field: Field<'a>,
+    /// The first field of every group instance.
+    pub delimiter_field: Field<'a>,
+    /// The layout of a single group instance.
+    pub items: Vec<LayoutItem<'a>>,
+}
 
-    /// Sum type for all possible FIX data types ever defined across all FIX
-    /// application versions.
-    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, EnumIter, IntoStaticStr)]
-    #[repr(u8)]
-    #[non_exhaustive]
-    pub enum FixDatatype {
-        /// Single character value, can include any alphanumeric character or
-        /// punctuation except the delimiter. All char fields are case sensitive
-        /// (i.e. m != M). The following fields are based on char.
-        Char,
-        /// char field containing one of two values: 'Y' = True/Yes 'N' = False/No.
-        Boolean,
-        /// Sequence of digits with optional decimal point and sign character (ASCII
-        /// characters "-", "0" - "9" and "."); the absence of the decimal point
-        /// within the string will be interpreted as the float representation of an
-        /// integer value. All float fields must accommodate up to fifteen
-        /// significant digits. The number of decimal places used should be a factor
-        /// of business/market needs and mutual agreement between counterparties.
-        /// Note that float values may contain leading zeros (e.g. "00023.23" =
-        /// "23.23") and may contain or omit trailing zeros after the decimal point
-        /// (e.g. "23.0" = "23.0000" = "23" = "23."). Note that fields which are
-        /// derived from float may contain negative values unless explicitly
-        /// specified otherwise. The following data types are based on float.
-        Float,
-        /// float field typically representing a Price times a Qty.
-        Amt,
-        /// float field representing a price. Note the number of decimal places may
-        /// vary. For certain asset classes prices may be negative values. For
-        /// example, prices for options strategies can be negative under certain
-        /// market conditions. Refer to Volume 7: FIX Usage by Product for asset
-        /// classes that support negative price values.
-        Price,
-        /// float field representing a price offset, which can be mathematically
-        /// added to a "Price". Note the number of decimal places may vary and some
-        /// fields such as LastForwardPoints may be negative.
-        PriceOffset,
-        /// float field capable of storing either a whole number (no decimal places)
-        /// of "shares" (securities denominated in whole units) or a decimal value
-        /// containing decimal places for non-share quantity asset classes
-        /// (securities denominated in fractional units).
-        Qty,
-        /// float field representing a percentage (e.g. 0.05 represents 5% and 0.9525
-        /// represents 95.25%). Note the number of decimal places may vary.
-        Percentage,
-        /// Sequence of digits without commas or decimals and optional sign character
-        /// (ASCII characters "-" and "0" - "9" ). The sign character utilizes one
-        /// byte (i.e. positive int is "99999" while negative int is "-99999"). Note
-        /// that int values may contain leading zeros (e.g. "00023" = "23").
-        /// Examples: 723 in field 21 would be mapped int as |21=723|. -723 in field
-        /// 12 would be mapped int as |12=-723| The following data types are based on
-        /// int.
-        Int,
-        /// int field representing a day during a particular monthy (values 1 to 31).
-        DayOfMonth,
-        /// int field representing the length in bytes. Value must be positive.
-        Length,
-        /// int field representing the number of entries in a repeating group. Value
-        /// must be positive.
-        NumInGroup,
-        /// int field representing a message sequence number. Value must be positive.
-        SeqNum,
-        /// `int` field representing a field's tag number when using FIX "Tag=Value"
-        /// syntax. Value must be positive and may not contain leading zeros.
-        TagNum,
-        /// Alpha-numeric free format strings, can include any character or
-        /// punctuation except the delimiter. All String fields are case sensitive
-        /// (i.e. morstatt != Morstatt).
-        String,
-        /// string field containing raw data with no format or content restrictions.
-        /// Data fields are always immediately preceded by a length field. The length
-        /// field should specify the number of bytes of the value of the data field
+/// The fields with fixed positions in every message of a FIX version, for
+/// framing validation. See [`Dictionary::framing_spec`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FramingSpec {
+    /// The tags that must open every message, in order.
+    pub leading_tags: Vec<TagU32>,
+    /// The tags that must close every message, in order.
+    pub trailing_tags: Vec<TagU32>,
+}
+
+/// The definition of a repeating group inside a message. See
+/// [`Dictionary::group_for_count_tag`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GroupInfo {
+    /// The tag of the `NumInGroup` field which announces the number of group
+    /// instances.
+    pub count_tag: TagU32,
+    /// The tag of the first field of every group instance.
+    pub delimiter_tag: TagU32,
+    /// The tags of all fields that can appear directly inside a group
+    /// instance, in definition order and with components expanded. Nested
+    /// groups only contribute their own count tag.
+    pub member_tags: Vec<TagU32>,
+}
+
+fn find_group<'a>(
+    items: impl Iterator<Item = LayoutItem<'a>>,
+    count_tag: TagU32,
+) -> Option<GroupInfo> {
+    for item in items {
+        let group = match item.kind() {
+            LayoutItemKind::Field(_) => None,
+            LayoutItemKind::Group(len_field, items) if len_field.tag() == count_tag => {
+                let mut member_tags = Vec::new();
+                group_member_tags(items.into_iter(), &mut member_tags);
+                // Empty groups have no delimiter, but the same count tag may
+                // announce a proper group further on.
+                let Some(delimiter_tag) = member_tags.first().copied() else {
+                    continue;
+                };
+                Some(GroupInfo {
+                    count_tag,
+                    delimiter_tag,
+                    member_tags,
+                })
+            }
+            LayoutItemKind::Group(_, items) => find_group(items.into_iter(), count_tag),
+            LayoutItemKind::Component(component) => find_group(component.items(), count_tag),
+        };
+        if group.is_some() {
+            return group;
+        }
+    }
+    None
+}
+
+fn group_member_tags<'a>(items: impl Iterator<Item = LayoutItem<'a>>, tags: &mut Vec<TagU32>) {
+    for item in items {
+        match item.kind() {
+            LayoutItemKind::Field(field) => tags.push(field.tag()),
+            LayoutItemKind::Group(len_field, _) => tags.push(len_field.tag()),
+            LayoutItemKind::Component(component) => group_member_tags(component.items(), tags),
+        }
+    }
+}
+
+fn required_wire_size<'a>(items: impl Iterator<Item = LayoutItem<'a>>) -> usize {
+    // `tag=value<SOH>`
+    let field_size = |field: Field, value_size: usize| field.tag_byte_width() + value_size + 2;
+    items
+        .filter(|item| item.required())
+        .map(|item| match item.kind() {
+            LayoutItemKind::Field(field) => {
+                field_size(field, field.fix_datatype().byte_count_hint())
+            }
+            // A single instance.
+            LayoutItemKind::Group(len_field, items) => {
+                field_size(len_field, 1) + required_wire_size(items.into_iter())
+            }
+            LayoutItemKind::Component(component) => required_wire_size(component.items()),
+        })
+        .sum()
+}
+
+fn expand_layout_tags<'a>(items: impl Iterator<Item = LayoutItem<'a>>, tags: &mut Vec<TagU32>) {
+    for item in items {
+        match item.kind() {
+            LayoutItemKind::Field(field) => tags.push(field.tag()),
+            LayoutItemKind::Group(len_field, items) => {
+                tags.push(len_field.tag());
+                expand_layout_tags(items.into_iter(), tags);
+            }
+            LayoutItemKind::Component(component) => expand_layout_tags(component.items(), tags),
+        }
+    }
+}
+
+/// Returns the tag of the `Length` field that immediately precedes the data
+/// field `items[i]`, if any.
+fn preceding_length_field(dict: &Dictionary, items: &[LayoutItemData], i: usize) -> Option<u32> {
+    let previous = items.get(i.checked_sub(1)?)?;
+    match previous.kind {
+        LayoutItemKindData::Field { tag } => {
+            let field = dict.field_by_tag(tag)?;
+            Some(tag).filter(|_| field.fix_datatype() == FixDatatype::Length)
+        }
+        _ => None,
+    }
+}
+
+/// Collects `(length_tag, data_tag)` pairs from `items` and nested groups.
+fn find_data_field_links(dict: &Dictionary, items: &[LayoutItemData], links: &mut Vec<(u32, u32)>) {
+    for (i, item) in items.iter().enumerate() {
+        match &item.kind {
+            LayoutItemKindData::Field { tag } if dict.field_is_data(*tag) => {
+                if let Some(length_tag) = preceding_length_field(dict, items, i) {
+                    links.push((length_tag, *tag));
+                }
+            }
+            LayoutItemKindData::Group { items, .. } => find_data_field_links(dict, items, links),
+            _ => {}
+        }
+    }
+}
+
+fn top_level_tags(dict: &Dictionary, items: &[LayoutItemData], tags: &mut Vec<u32>) {
+    for item in items {
+        match &item.kind {
+            LayoutItemKindData::Field { tag } => tags.push(*tag),
+            LayoutItemKindData::Group { len_field_tag, .. } => tags.push(*len_field_tag),
+            LayoutItemKindData::Component { name } => {
+                if let Some(component) = dict.components_by_name.get(name) {
+                    top_level_tags(dict, &component.layout_items, tags);
+                }
+            }
+        }
+    }
+}
+
+fn layout_item_path<'a>(
+    items: impl Iterator<Item = LayoutItem<'a>>,
+    tag: TagU32,
+    path: &mut Vec<String>,
+) -> bool {
+    for item in items {
+        path.push(item.tag_text());
+        let found = match item.kind() {
+            LayoutItemKind::Field(field) => field.tag() == tag,
+            LayoutItemKind::Group(len_field, items) => {
+                len_field.tag() == tag || layout_item_path(items.into_iter(), tag, path)
+            }
+            LayoutItemKind::Component(component) => layout_item_path(component.items(), tag, path),
+        };
+        if found {
+            return true;
+        }
+        path.pop();
+    }
+    false
+}
+
+/// Venue-specific customization
+impl Dictionary {
+    /// Changes the version string of `self`, e.g. to label a venue-specific
+    /// variant of a standard specification. See [`Dictionary::version`].
+    ///
+    /// ```
+    /// use fefix_dictionary::Dictionary;
+    ///
+    /// let mut dict = Dictionary::fix44();
+    /// dict.set_version("FIX.4.4-ACME");
+    /// assert_eq!(dict.version(), "FIX.4.4-ACME");
+    /// ```
+    pub fn set_version(&mut self, version: &str) {
+        self.version = version.to_string();
+    }
+
+    /// Marks the field `tag` as required (or optional) within the message with
+    /// type `msg_type`, e.g. because a venue mandates a field which is optional
+    /// according to the FIX specification.
+    ///
+    /// Components are shared by many messages, so when the field lives inside
+    /// a component, the reference to that component is replaced by a copy of
+    /// its items in this message only, and the message no longer depends on
+    /// the component. Items of an optional component become optional.
+    ///
+    /// ```
+    /// use fefix_dictionary::{Dictionary, TagU32};
+    ///
+    /// let mut dict = Dictionary::fix44();
+    /// let symbol = TagU32::new(55).unwrap();
+    /// dict.set_field_required_in_message("D", symbol, true).unwrap();
+    /// let message = dict.message_by_msgtype("D").unwrap();
+    /// assert!(message
+    ///     .layout()
+    ///     .any(|item| item.tag_text() == "Symbol" && item.required()));
+    /// assert!(dict.component_by_name("Instrument").is_some());
+    /// ```
+    pub fn set_field_required_in_message(
+        &mut self,
+        msg_type: &str,
+        tag: TagU32,
+        required: bool,
+    ) -> Result<(), DictError> {
+        let message = self
+            .messages_by_msgtype
+            .get_mut(msg_type)
+            .ok_or(DictError::MessageNotFound)?;
+        let found = set_field_required(
+            &self.components_by_name,
+            &mut message.layout_items,
+            tag.get(),
+            required,
+        );
+        if !found {
+            return Err(DictError::FieldNotFound);
+        }
+        self.index_layouts();
+        Ok(())
+    }
+
+    /// Renames the field `old_name` to `new_name`, e.g. for firm-specific
+    /// extension dictionaries. Layout items refer to fields by tag, so they
+    /// reflect the new name automatically.
+    ///
+    /// ```
+    /// use fefix_dictionary::{DictError, Dictionary};
+    ///
+    /// let mut dict = Dictionary::fix44();
+    /// dict.apply_field_rename("Text", "FreeText").unwrap();
+    /// assert_eq!(dict.field_by_name("FreeText").unwrap().tag().get(), 58);
+    /// assert!(dict.field_by_name("Text").is_none());
+    /// assert_eq!(
+    ///     dict.apply_field_rename("FreeText", "Symbol"),
+    ///     Err(DictError::NameConflict)
+    /// );
+    /// ```
+    pub fn apply_field_rename(&mut self, old_name: &str, new_name: &str) -> Result<(), DictError> {
+        let tag = *self
+            .field_tags_by_name
+            .get(old_name)
+            .ok_or(DictError::FieldNotFound)?;
+        if old_name == new_name {
+            return Ok(());
+        }
+        if self.field_tags_by_name.contains_key(new_name) {
+            return Err(DictError::NameConflict);
+        }
+        self.field_tags_by_name.remove(old_name);
+        self.field_tags_by_lowercase_name
+            .remove(old_name.to_ascii_lowercase().as_str());
+        let field = self.fields_by_tags.get_mut(&tag).unwrap();
+        field.name = new_name.into();
+        self.field_tags_by_name.insert(new_name.into(), tag);
+        self.field_tags_by_lowercase_name
+            .insert(new_name.to_ascii_lowercase().into(), tag);
+        Ok(())
+    }
+
+    /// Removes the field `tag` from `self`, together with all layout items
+    /// that refer to it in messages and components. Repeating groups announced
+    /// by `tag` are removed as a whole. Returns `None` if there's no such
+    /// field.
+    ///
+    /// Removing a required field makes the messages that contain it impossible
+    /// to validate against their original specification, so the containers in
+    /// which it was required are returned as well. Nothing is logged, as this
+    /// crate has no logging dependency; warn about
+    /// [`RemovedField::required_in`] if needed.
+    ///
+    /// ```
+    /// use fefix_dictionary::{Dictionary, TagU32};
+    ///
+    /// let mut dict = Dictionary::fix44();
+    /// let removed = dict.remove_field(TagU32::new(58).unwrap()).unwrap();
+    /// assert_eq!(removed.definition.name, "Text");
+    /// assert_eq!(removed.required_in, &["LinesOfTextGrp.NoLinesOfText"]);
+    /// assert!(dict.field_by_name("Text").is_none());
+    /// ```
+    pub fn remove_field(&mut self, tag: TagU32) -> Option<RemovedField> {
+        let field = self.fields_by_tags.get(&tag.get())?;
+        let definition = parts::field_def(self, field);
+        let mut required_in = Vec::new();
+        for message in self.messages_by_msgtype.values_mut() {
+            remove_layout_items(
+                &self.fields_by_tags,
+                &message.name,
+                &mut message.layout_items,
+                tag.get(),
+                &mut required_in,
+            );
+        }
+        for component in self.components_by_name.values_mut() {
+            remove_layout_items(
+                &self.fields_by_tags,
+                &component.name,
+                &mut component.layout_items,
+                tag.get(),
+                &mut required_in,
+            );
+        }
+        required_in.sort();
+        required_in.dedup();
+        self.remove_field_names(tag.get());
+        self.fields_by_tags.remove(&tag.get());
+        self.index_layouts();
+        Some(RemovedField {
+            definition,
+            required_in,
+        })
+    }
+
+    /// Removes the message with type `msg_type` from `self` and returns its
+    /// definition, or `None` if there's no such message. Other messages stop
+    /// listing it among their responses. Together with
+    /// [`Dictionary::remove_field`] and [`Dictionary::remove_component`], this
+    /// allows to tailor minimal dictionaries for specific use cases.
+    ///
+    /// ```
+    /// use fefix_dictionary::Dictionary;
+    ///
+    /// let mut dict = Dictionary::fix44();
+    /// let removed = dict.remove_message("D").unwrap();
+    /// assert_eq!(removed.name, "NewOrderSingle");
+    /// assert!(dict.message_by_name("NewOrderSingle").is_none());
+    /// ```
+    pub fn remove_message(&mut self, msg_type: &str) -> Option<MessageDef> {
+        let message = self.messages_by_msgtype.get(msg_type)?;
+        let definition = parts::message_def(self, message);
+        self.remove_message_data(msg_type);
+        for message in self.messages_by_msgtype.values_mut() {
+            message.responses.retain(|response| response != msg_type);
+        }
+        self.index_layouts();
+        Some(definition)
+    }
+
+    /// Removes the component `name` from `self`, together with all references
+    /// to it in messages and other components, and returns its definition.
+    /// Repeating groups which are left without items are removed as well.
+    /// Returns `None` if there's no such component.
+    ///
+    /// ```
+    /// use fefix_dictionary::Dictionary;
+    ///
+    /// let mut dict = Dictionary::fix44();
+    /// let removed = dict.remove_component("Instrument").unwrap();
+    /// assert_eq!(removed.name, "Instrument");
+    /// assert!(dict.component_dependents("Instrument").is_empty());
+    /// ```
+    pub fn remove_component(&mut self, name: &str) -> Option<ComponentDef> {
+        let component = self.components_by_name.get(name)?;
+        let definition = parts::component_def(self, component);
+        self.component_names_by_id.remove(&component.id);
+        self.components_by_name.remove(name);
+        for message in self.messages_by_msgtype.values_mut() {
+            remove_component_references(&mut message.layout_items, name);
+        }
+        for component in self.components_by_name.values_mut() {
+            remove_component_references(&mut component.layout_items, name);
+        }
+        self.index_layouts();
+        Some(definition)
+    }
+
+    /// Makes sure that `self` has `StandardHeader` and `StandardTrailer`
+    /// components which frame messages correctly (see
+    /// [`Dictionary::framing_spec`]): the header must start with
+    /// `BeginString <8>`, `BodyLength <9>`, and `MsgType <35>`, and the
+    /// trailer must end with `CheckSum <10>`, all of them required. Missing
+    /// components and field definitions are added, and misplaced framing
+    /// fields are moved into position.
+    ///
+    /// This is mostly useful for dictionaries built with
+    /// [`Dictionary::from_parts`], whose header and trailer are empty unless
+    /// given explicitly. Returns the tags of the framing fields which had to
+    /// be added or moved.
+    ///
+    /// ```
+    /// use fefix_dictionary::Dictionary;
+    ///
+    /// let mut dict = Dictionary::fix44();
+    /// assert!(dict.ensure_standard_header_trailer().is_empty());
+    /// ```
+    pub fn ensure_standard_header_trailer(&mut self) -> Vec<TagU32> {
+        const HEADER: &[(u32, &str, FixDatatype)] = &[
+            (8, "BeginString", FixDatatype::String),
+            (9, "BodyLength", FixDatatype::Length),
+            (35, "MsgType", FixDatatype::String),
+        ];
+        const TRAILER: &[(u32, &str, FixDatatype)] = &[(10, "CheckSum", FixDatatype::String)];
+        let mut fixed = Vec::new();
+        for (tag, name, datatype) in HEADER.iter().chain(TRAILER) {
+            if self.fields_by_tags.contains_key(tag) {
+                continue;
+            }
+            if self.datatype_by_name(datatype.name()).is_none() {
+                self.add_datatype(DatatypeData {
+                    datatype: *datatype,
+                    description: String::new(),
+                    examples: Vec::new(),
+                });
+            }
+            self.add_field(FieldData {
+                name: (*name).into(),
+                tag: *tag,
+                data_type_name: datatype.name().into(),
+                associated_data_tag: None,
+                value_restrictions: None,
+                required: true,
+                abbr_name: None,
+                base_category_abbr_name: None,
+                base_category_id: None,
+                description: None,
+                extra_attributes: FnvHashMap::default(),
+            });
+        }
+        for (name, framing, at_start) in [
+            ("StandardHeader", HEADER, true),
+            ("StandardTrailer", TRAILER, false),
+        ] {
+            if !self.components_by_name.contains_key(name) {
+                self.add_component(ComponentData {
+                    id: 0,
+                    component_type: FixmlComponentAttributes::Block {
+                        is_implicit: false,
+                        is_repeating: false,
+                        is_optimized: false,
+                    },
+                    layout_items: LayoutItems::new(),
+                    category_name: "".into(),
+                    name: name.into(),
+                    abbr_name: None,
+                });
+            }
+            let items = &mut self.components_by_name.get_mut(name).unwrap().layout_items;
+            let position = if at_start {
+                0
+            } else {
+                items.len().saturating_sub(framing.len())
+            };
+            let framing_tags = framing.iter().map(|(tag, _, _)| *tag).collect::<Vec<_>>();
+            let misplaced = framing_tags
+                .iter()
+                .enumerate()
+                .filter(|(i, tag)| {
+                    !items
+                        .get(position + i)
+                        .is_some_and(|item| item.required && field_tag(item) == Some(**tag))
+                })
+                .filter_map(|(_, tag)| TagU32::new(*tag))
+                .collect::<Vec<_>>();
+            if misplaced.is_empty() {
+                continue;
+            }
+            items.retain(|item| !field_tag(item).is_some_and(|tag| framing_tags.contains(&tag)));
+            let framing_items = framing.iter().map(|(tag, _, _)| LayoutItemData {
+                required: true,
+                kind: LayoutItemKindData::Field { tag: *tag },
+            });
+            if at_start {
+                items.splice(0..0, framing_items);
+            } else {
+                items.extend(framing_items);
+            }
+            fixed.extend(misplaced);
+        }
+        if !fixed.is_empty() {
+            self.index_layouts();
+        }
+        fixed
+    }
+}
+
+/// Returns the tag of `item` if it's a plain field.
+fn field_tag(item: &LayoutItemData) -> Option<u32> {
+    match item.kind {
+        LayoutItemKindData::Field { tag } => Some(tag),
+        _ => None,
+    }
+}
+
+fn remove_component_references(items: &mut Vec<LayoutItemData>, name: &str) {
+    items.retain_mut(|item| match &mut item.kind {
+        LayoutItemKindData::Component { name: n } => n != name,
+        LayoutItemKindData::Group { items, .. } => {
+            let was_empty = items.is_empty();
+            remove_component_references(items, name);
+            was_empty || !items.is_empty()
+        }
+        LayoutItemKindData::Field { .. } => true,
+    });
+}
+
+/// A field removed with [`Dictionary::remove_field`].
+#[derive(Clone, Debug)]
+pub struct RemovedField {
+    /// The definition of the field.
+    pub definition: FieldDef,
+    /// The messages and components in which the field was required, sorted.
+    /// Repeating groups are named after their parent container, followed by a
+    /// dot and the name of their `NumInGroup` field (e.g.
+    /// `ExecutionReport.NoLegs`).
+    pub required_in: Vec<String>,
+}
+
+/// Removes all items of `items` that refer to the field `tag`, including
+/// groups announced by it, and records in `required_in` whether any of them
+/// was required.
+fn remove_layout_items(
+    fields: &FnvHashMap<u32, FieldData>,
+    container: &str,
+    items: &mut Vec<LayoutItemData>,
+    tag: u32,
+    required_in: &mut Vec<String>,
+) {
+    items.retain(|item| {
+        let refers_to_tag = match &item.kind {
+            LayoutItemKindData::Field { tag: t } => *t == tag,
+            LayoutItemKindData::Group { len_field_tag, .. } => *len_field_tag == tag,
+            LayoutItemKindData::Component { .. } => false,
+        };
+        if refers_to_tag && item.required {
+            required_in.push(container.to_string());
+        }
+        !refers_to_tag
+    });
+    for item in items.iter_mut() {
+        if let LayoutItemKindData::Group {
+            len_field_tag,
+            items,
+        } = &mut item.kind
+        {
+            let group_name = fields
+                .get(len_field_tag)
+                .map(|field| field.name.as_str())
+                .unwrap_or_default();
+            let group_container = format!("{}.{}", container, group_name);
+            remove_layout_items(fields, &group_container, items, tag, required_in);
+        }
+    }
+}
+
+/// The error type that can arise when customizing a [`Dictionary`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DictError {
+    /// The [`Dictionary`] doesn't contain the field, or the message doesn't
+    /// contain it.
+    FieldNotFound,
+    /// The [`Dictionary`] doesn't contain the message type.
+    MessageNotFound,
+    /// The new name is already used by another field.
+    NameConflict,
+}
+
+/// Marks the first item of `items` which refers to the field `tag` as
+/// `required`, inlining the components that contain it. Returns `false` if
+/// there's no such item.
+fn set_field_required(
+    components: &FnvHashMap<SmartString, ComponentData>,
+    items: &mut LayoutItems,
+    tag: u32,
+    required: bool,
+) -> bool {
+    for i in 0..items.len() {
+        let item = &mut items[i];
+        match &mut item.kind {
+            LayoutItemKindData::Field { tag: t } => {
+                if *t == tag {
+                    item.required = required;
+                    return true;
+                }
+            }
+            LayoutItemKindData::Group {
+                len_field_tag,
+                items,
+            } => {
+                if *len_field_tag == tag {
+                    item.required = required;
+                    return true;
+                }
+                if set_field_required(components, items, tag, required) {
+                    return true;
+                }
+            }
+            LayoutItemKindData::Component { name } => {
+                let component = match components.get(name.as_str()) {
+                    Some(component) => component,
+                    None => continue,
+                };
+                if !layout_contains_tag(components, &component.layout_items, tag) {
+                    continue;
+                }
+                let component_required = item.required;
+                let inlined = component.layout_items.iter().map(|item| LayoutItemData {
+                    required: item.required && component_required,
+                    kind: item.kind.clone(),
+                });
+                items.splice(i..i + 1, inlined.collect::<Vec<_>>());
+                return set_field_required(components, items, tag, required);
+            }
+        }
+    }
+    false
+}
+
+/// Returns `true` if `items`, including nested groups and components, refer
+/// to the field `tag`.
+fn layout_contains_tag(
+    components: &FnvHashMap<SmartString, ComponentData>,
+    items: &[LayoutItemData],
+    tag: u32,
+) -> bool {
+    items.iter().any(|item| match &item.kind {
+        LayoutItemKindData::Field { tag: t } => *t == tag,
+        LayoutItemKindData::Group {
+            len_field_tag,
+            items,
+        } => *len_field_tag == tag || layout_contains_tag(components, items, tag),
+        LayoutItemKindData::Component { name } => components
+            .get(name.as_str())
+            .is_some_and(|component| layout_contains_tag(components, &component.layout_items, tag)),
+    })
+}
+
+/// Builder utilities
+impl Dictionary {
+    fn add_field(&mut self, field: FieldData) {
+        self.field_tags_by_name
+            .insert(field.name.clone(), field.tag);
+        self.field_tags_by_lowercase_name
+            .insert(field.name.to_ascii_lowercase().into(), field.tag);
+        if let Some(abbr_name) = &field.abbr_name {
+            self.field_tags_by_abbr_name
+                .insert(abbr_name.as_str().into(), field.tag);
+        }
+        self.fields_by_tags.insert(field.tag, field);
+    }
+
+    /// Removes all name-based index entries of the field `tag`, so that it
+    /// can be redefined under a different name.
+    fn remove_field_names(&mut self, tag: u32) {
+        self.field_tags_by_name.retain(|_, t| *t != tag);
+        self.field_tags_by_lowercase_name.retain(|_, t| *t != tag);
+        self.field_tags_by_abbr_name.retain(|_, t| *t != tag);
+    }
+
+    fn add_message(&mut self, message: MessageData) {
+        self.message_msgtypes_by_name
+            .insert(message.name.clone(), message.msg_type.clone());
+        self.message_msgtypes_by_lowercase_name.insert(
+            message.name.to_ascii_lowercase().into(),
+            message.msg_type.clone(),
+        );
+        self.messages_by_msgtype
+            .insert(message.msg_type.clone(), message);
+    }
+
+    /// Removes the message `msg_type`, which must exist, from all indexes but
+    /// the layout ones, and returns it.
+    fn remove_message_data(&mut self, msg_type: &str) -> MessageData {
+        let message = self.messages_by_msgtype.remove(msg_type).unwrap();
+        self.message_msgtypes_by_name.remove(&message.name);
+        self.message_msgtypes_by_lowercase_name
+            .remove(message.name.to_ascii_lowercase().as_str());
+        message
+    }
+
+    /// Adds `component`, replacing any component with the same name. Components
+    /// are numbered sequentially starting from 1, in order of addition;
+    /// replacements keep the ID of the component they replace. IDs of removed
+    /// components aren't reused.
+    fn add_component(&mut self, mut component: ComponentData) {
+        component.id = match self.components_by_name.get(&component.name) {
+            Some(existing) => existing.id,
+            None => self
+                .component_names_by_id
+                .keys()
+                .max()
+                .map_or(1, |id| id + 1),
+        };
+        self.component_names_by_id
+            .insert(component.id, component.name.clone());
+        self.components_by_name
+            .insert(component.name.clone(), component);
+    }
+
+    fn add_datatype(&mut self, datatype: DatatypeData) {
+        self.data_types_by_name
+            .insert(datatype.datatype.name().into(), datatype);
+    }
+
+    fn add_category(&mut self, category: CategoryData) {
+        self.categories_by_name
+            .insert(category.name.clone().into(), category);
+    }
+
+    /// Associates data fields to their length fields. QuickFIX specifications
+    /// don't do so explicitly: instead, a data field is always immediately
+    /// preceded by its `Length` field in layouts. See [`Field::data_tag`].
+    fn link_data_fields(&mut self) {
+        for field in self.fields_by_tags.values_mut() {
+            field.associated_data_tag = None;
+        }
+        let mut links = Vec::new();
+        let containers = self
+            .messages_by_msgtype
+            .values()
+            .map(|message| &message.layout_items)
+            .chain(
+                self.components_by_name
+                    .values()
+                    .map(|component| &component.layout_items),
+            );
+        for items in containers {
+            find_data_field_links(self, items, &mut links);
+        }
+        for (length_tag, data_tag) in links {
+            if let Some(field) = self.fields_by_tags.get_mut(&length_tag) {
+                field.associated_data_tag = Some(data_tag as usize);
+            }
+        }
+    }
+
+    /// Computes the indexes that depend on the complete set of fields and on
+    /// the layout of messages and components. It must be called once all
+    /// definitions have been added.
+    fn index_layouts(&mut self) {
+        self.group_tags_by_count_tag = self.build_group_tag_map();
+        self.link_data_fields();
+        let mut field_tags_by_enum_value: FnvHashMap<SmartString, Vec<TagU32>> =
+            FnvHashMap::default();
+        for field in self.fields_by_tags.values() {
+            for value in field.value_restrictions.iter().flatten() {
+                field_tags_by_enum_value
+                    .entry(value.value.as_str().into())
+                    .or_default()
+                    .push(TagU32::new(field.tag).unwrap());
+            }
+        }
+        for tags in field_tags_by_enum_value.values_mut() {
+            tags.sort();
+            tags.dedup();
+        }
+        self.field_tags_by_enum_value = field_tags_by_enum_value;
+        self.datatypes_by_tag = self
+            .fields()
+            .into_iter()
+            .map(|field| (field.tag(), field.fix_datatype()))
+            .collect();
+        self.data_tags = self
+            .datatypes_by_tag
+            .iter()
+            .filter(|(_, datatype)| datatype.is_length_prefixed())
+            .map(|(tag, _)| *tag)
+            .collect();
+        let mut component_names_by_field_tag: FnvHashMap<TagU32, Vec<SmartString>> =
+            FnvHashMap::default();
+        for component in self.components_by_name.values() {
+            let mut tags = Vec::new();
+            contained_field_tags(&component.layout_items, &mut tags);
+            tags.sort_unstable();
+            tags.dedup();
+            for tag in tags.into_iter().filter_map(TagU32::new) {
+                component_names_by_field_tag
+                    .entry(tag)
+                    .or_default()
+                    .push(component.name.clone());
+            }
+        }
+        for names in component_names_by_field_tag.values_mut() {
+            names.sort_unstable();
+        }
+        self.component_names_by_field_tag = component_names_by_field_tag;
+    }
+}
+
+#[derive(Clone, Debug)]
+struct AbbreviationData {
+    abbreviation: SmartString,
+    is_last: bool,
+}
+
+/// An [`Abbreviation`] is a standardized abbreviated form for a specific word,
+/// pattern, or name. Abbreviation data is mostly meant for documentation
+/// purposes, but in general it can have other uses as well, e.g. FIXML field
+/// naming.
+#[derive(Debug)]
+pub struct Abbreviation<'a>(&'a Dictionary, &'a AbbreviationData);
+
+impl<'a> Abbreviation<'a> {
+    /// Returns the full term (non-abbreviated) associated with `self`.
+    pub fn term(&self) -> &str {
+        self.1.abbreviation.as_str()
+    }
+}
+
+#[derive(Clone, Debug)]
+struct CategoryData {
+    /// **Primary key**. A string uniquely identifying this category.
+    name: String,
+    /// The FIXML file name for a Category.
+    fixml_filename: String,
+}
+
+/// A [`Category`] is a collection of loosely related FIX messages or components
+/// all belonging to the same [`Section`].
+#[derive(Clone, Debug)]
+pub struct Category<'a>(&'a Dictionary, &'a CategoryData);
+
+impl<'a> Category<'a> {
+    /// Returns the name of `self`, e.g. `app`.
+    pub fn name(&self) -> &str {
+        self.1.name.as_str()
+    }
+}
+
+#[derive(Clone, Debug)]
+struct ComponentData {
+    /// **Primary key.** The unique integer identifier of this component
+    /// type.
+    id: usize,
+    component_type: FixmlComponentAttributes,
+    layout_items: Vec<LayoutItemData>,
+    category_name: SmartString,
+    /// The human readable name of the component.
+    name: SmartString,
+    /// The name for this component when used in an XML context.
+    abbr_name: Option<SmartString>,
+}
+
+/// A [`Component`] is an ordered collection of fields and/or other components.
+/// There are two kinds of components: (1) common blocks and (2) repeating
+/// groups. Common blocks are merely commonly reused sequences of the same
+/// fields/components
+/// which are given names for simplicity, i.e. they serve as "macros". Repeating
+/// groups, on the other hand, are components which can appear zero or more times
+/// inside FIX messages (or other components, for that matter).
+#[derive(Clone, Debug)]
+pub struct Component<'a>(&'a Dictionary, &'a ComponentData);
+
+impl<'a> Component<'a> {
+    /// Returns the unique numeric ID of `self`. IDs are assigned sequentially,
+    /// starting from 1, as components are defined.
+    pub fn id(&self) -> u32 {
+        self.1.id as u32
+    }
+
+    /// Returns the name of `self`. The name of every [`Component`] is unique
+    /// across a [`Dictionary`].
+    pub fn name(&self) -> &str {
+        self.1.name.as_str()
+    }
+
+    /// Returns the abbreviated name of `self`, used as element name in FIXML.
+    pub fn abbr_name(&self) -> Option<&str> {
+        self.1.abbr_name.as_deref()
+    }
+
+    /// Returns `true` if and only if `self` is a "group" component; `false`
+    /// otherwise.
+    pub fn is_group(&self) -> bool {
+        match self.1.component_type {
+            FixmlComponentAttributes::Block { is_repeating, .. } => is_repeating,
+            _ => false,
+        }
+    }
+
+    /// Returns the [`Category`] to which `self` belongs, if any. QuickFIX
+    /// specifications can assign components to categories with the optional
+    /// `category` attribute of `<component>` elements. See
+    /// [`Dictionary::components_in_category`].
+    pub fn category(&self) -> Option<Category<'_>> {
+        self.0.category_by_name(self.1.category_name.as_str())
+    }
+
+    /// Returns an [`Iterator`] over all items that are part of `self`.
+    pub fn items(&self) -> impl Iterator<Item = LayoutItem> {
+        self.1
+            .layout_items
+            .iter()
+            .map(move |data| LayoutItem(self.0, data))
+    }
+
+    /// Returns an [`Iterator`] over all items of `self` which are required,
+    /// i.e. whose [`LayoutItem::required`] is `true`.
+    pub fn required_items(&self) -> impl Iterator<Item = LayoutItem<'_>> {
+        self.items().filter(|item| item.required())
+    }
+
+    /// Checks whether `field` appears in the definition of `self` and returns
+    /// `true` if it does, `false` otherwise.
+    pub fn contains_field(&self, field: &Field) -> bool {
+        self.items().any(|layout_item| {
+            if let LayoutItemKind::Field(f) = layout_item.kind() {
+                f.tag() == field.tag()
+            } else {
+                false
+            }
+        })
+    }
+
+    /// Returns the only item of `self` if it's a repeating group, i.e. if
+    /// `self` is just a shell around the group; `None` otherwise.
+    ///
+    /// ```
+    /// use fefix_dictionary::{Dictionary, LayoutItemKind};
+    ///
+    /// let dict = Dictionary::fix44();
+    /// let parties = dict.component_by_name("Parties").unwrap();
+    /// let group = parties.single_group().unwrap();
+    /// assert!(matches!(group.kind(), LayoutItemKind::Group(field, _) if field.name() == "NoPartyIDs"));
+    /// assert!(dict.component_by_name("Instrument").unwrap().single_group().is_none());
+    /// ```
+    pub fn single_group(&self) -> Option<LayoutItem<'a>> {
+        match self.1.layout_items.as_slice() {
+            [item @ LayoutItemData {
+                kind: LayoutItemKindData::Group { .. },
+                ..
+            }] => Some(LayoutItem(self.0, item)),
+            _ => None,
+        }
+    }
+}
+
+/// [`Component`]'s are equal if they have the same name, category, and
+/// layout, even if they belong to different [`Dictionary`]'s. IDs and other
+/// attributes are ignored, and layout items refer to fields by name.
+///
+/// ```
+/// use fefix_dictionary::Dictionary;
+///
+/// let (a, b) = (Dictionary::fix44(), Dictionary::fix44());
+/// let parties = a.component_by_name("Parties").unwrap();
+/// assert_eq!(parties, b.component_by_name("Parties").unwrap());
+/// assert_ne!(parties, b.component_by_name("Instrument").unwrap());
+/// ```
+impl<'a> PartialEq for Component<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        parts::component_def(self.0, self.1) == parts::component_def(other.0, other.1)
+    }
+}
+
+impl<'a> Eq for Component<'a> {}
+
+/// Component type (FIXML-specific information).
+#[derive(Clone, Debug, PartialEq)]
+#[allow(dead_code)]
+pub enum FixmlComponentAttributes {
+    Xml,
+    Block {
+        is_repeating: bool,
+        is_implicit: bool,
+        is_optimized: bool,
+    },
+    Message,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct DatatypeData {
+    /// **Primary key.** Identifier of the datatype.
+    datatype: FixDatatype,
+    /// Human readable description of this Datatype.
+    description: String,
+    /// A string that contains examples values for a datatype
+    examples: Vec<String>,
+    // TODO: 'XML'.
+}
+
+/// A FIX data type defined as part of a [`Dictionary`].
+#[derive(Debug)]
+pub struct Datatype<'a>(&'a Dictionary, &'a DatatypeData);
+
+impl<'a> Datatype<'a> {
+    /// Returns the name of `self`.  This is also guaranteed to be a valid Rust
+    /// identifier.
+    pub fn name(&self) -> &str {
+        self.1.datatype.name()
+    }
+
+    /// Returns `self` as an `enum`.
+    pub fn basetype(&self) -> FixDatatype {
+        self.1.datatype
+    }
+
+    /// Returns the example values of `self`, as given by the specification.
+    /// See [`Dictionary::validate_datatype_examples`].
+    pub fn examples(&self) -> &[String] {
+        &self.1.examples
+    }
+}
+
+mod datatype {
+    use strum::IntoEnumIterator;
+    use strum_macros::{EnumIter, IntoStaticStr};
+
+    /// Sum type for all possible FIX data types ever defined across all FIX
+    /// application versions.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, EnumIter, IntoStaticStr)]
+    #[repr(u8)]
+    #[non_exhaustive]
+    pub enum FixDatatype {
+        /// Single character value, can include any alphanumeric character or
+        /// punctuation except the delimiter. All char fields are case sensitive
+        /// (i.e. m != M). The following fields are based on char.
+        Char,
+        /// char field containing one of two values: 'Y' = True/Yes 'N' = False/No.
+        Boolean,
+        /// Sequence of digits with optional decimal point and sign character (ASCII
+        /// characters "-", "0" - "9" and "."); the absence of the decimal point
+        /// within the string will be interpreted as the float representation of an
+        /// integer value. All float fields must accommodate up to fifteen
+        /// significant digits. The number of decimal places used should be a factor
+        /// of business/market needs and mutual agreement between counterparties.
+        /// Note that float values may contain leading zeros (e.g. "00023.23" =
+        /// "23.23") and may contain or omit trailing zeros after the decimal point
+        /// (e.g. "23.0" = "23.0000" = "23" = "23."). Note that fields which are
+        /// derived from float may contain negative values unless explicitly
+        /// specified otherwise. The following data types are based on float.
+        Float,
+        /// float field typically representing a Price times a Qty.
+        Amt,
+        /// float field representing a price. Note the number of decimal places may
+        /// vary. For certain asset classes prices may be negative values. For
+        /// example, prices for options strategies can be negative under certain
+        /// market conditions. Refer to Volume 7: FIX Usage by Product for asset
+        /// classes that support negative price values.
+        Price,
+        /// float field representing a price offset, which can be mathematically
+        /// added to a "Price". Note the number of decimal places may vary and some
+        /// fields such as LastForwardPoints may be negative.
+        PriceOffset,
+        /// float field capable of storing either a whole number (no decimal places)
+        /// of "shares" (securities denominated in whole units) or a decimal value
+        /// containing decimal places for non-share quantity asset classes
+        /// (securities denominated in fractional units).
+        Qty,
+        /// float field representing a percentage (e.g. 0.05 represents 5% and 0.9525
+        /// represents 95.25%). Note the number of decimal places may vary.
+        Percentage,
+        /// Sequence of digits without commas or decimals and optional sign character
+        /// (ASCII characters "-" and "0" - "9" ). The sign character utilizes one
+        /// byte (i.e. positive int is "99999" while negative int is "-99999"). Note
+        /// that int values may contain leading zeros (e.g. "00023" = "23").
+        /// Examples: 723 in field 21 would be mapped int as |21=723|. -723 in field
+        /// 12 would be mapped int as |12=-723| The following data types are based on
+        /// int.
+        Int,
+        /// int field representing a day during a particular monthy (values 1 to 31).
+        DayOfMonth,
+        /// int field representing the length in bytes. Value must be positive.
+        Length,
+        /// int field representing the number of entries in a repeating group. Value
+        /// must be positive.
+        NumInGroup,
+        /// int field representing a message sequence number. Value must be positive.
+        SeqNum,
+        /// `int` field representing a field's tag number when using FIX "Tag=Value"
+        /// syntax. Value must be positive and may not contain leading zeros.
+        TagNum,
+        /// Alpha-numeric free format strings, can include any character or
+        /// punctuation except the delimiter. All String fields are case sensitive
+        /// (i.e. morstatt != Morstatt).
+        String,
+        /// string field containing raw data with no format or content restrictions.
+        /// Data fields are always immediately preceded by a length field. The length
+        /// field should specify the number of bytes of the value of the data field
         /// (up to but not including the terminating SOH). Caution: the value of one
         /// of these fields may contain the delimiter (SOH) character. Note that the
         /// value specified for this field should be followed by the delimiter (SOH)
@@ -737,684 +3143,2903 @@ mod datatype {
         Country,
     }
 
-    impl FixDatatype {
-        /// Compares `name` to the set of strings commonly used by QuickFIX's custom
-        /// specification format and returns its associated
-        /// [`Datatype`](super::Datatype) if a match
-        /// was found. The query is case-insensitive.
-        ///
-        /// # Examples
-        ///
-        /// ```
-        /// use fefix_dictionary::FixDatatype;
-        ///
-        /// assert_eq!(FixDatatype::from_quickfix_name("AMT"), Some(FixDatatype::Amt));
-        /// assert_eq!(FixDatatype::from_quickfix_name("Amt"), Some(FixDatatype::Amt));
-        /// assert_eq!(FixDatatype::from_quickfix_name("MONTHYEAR"), Some(FixDatatype::MonthYear));
-        /// assert_eq!(FixDatatype::from_quickfix_name(""), None);
-        /// ```
-        pub fn from_quickfix_name(name: &str) -> Option<Self> {
-            // https://github.com/quickfix/quickfix/blob/b6760f55ac6a46306b4e081bb13b65e6220ab02d/src/C%2B%2B/DataDictionary.cpp#L646-L680
-            Some(match name.to_ascii_uppercase().as_str() {
-                "AMT" => FixDatatype::Amt,
-                "BOOLEAN" => FixDatatype::Boolean,
-                "CHAR" => FixDatatype::Char,
-                "COUNTRY" => FixDatatype::Country,
-                "CURRENCY" => FixDatatype::Currency,
-                "DATA" => FixDatatype::Data,
-                "DATE" => FixDatatype::UtcDateOnly, // FIXME?
-                "DAYOFMONTH" => FixDatatype::DayOfMonth,
-                "EXCHANGE" => FixDatatype::Exchange,
-                "FLOAT" => FixDatatype::Float,
-                "INT" => FixDatatype::Int,
-                "LANGUAGE" => FixDatatype::Language,
-                "LENGTH" => FixDatatype::Length,
-                "LOCALMKTDATE" => FixDatatype::LocalMktDate,
-                "MONTHYEAR" => FixDatatype::MonthYear,
-                "MULTIPLECHARVALUE" | "MULTIPLEVALUESTRING" => FixDatatype::MultipleCharValue,
-                "MULTIPLESTRINGVALUE" => FixDatatype::MultipleStringValue,
-                "NUMINGROUP" => FixDatatype::NumInGroup,
-                "PERCENTAGE" => FixDatatype::Percentage,
-                "PRICE" => FixDatatype::Price,
-                "PRICEOFFSET" => FixDatatype::PriceOffset,
-                "QTY" => FixDatatype::Qty,
-                "STRING" => FixDatatype::String,
-                "TZTIMEONLY" => FixDatatype::UtcTimeOnly, // FIXME
-                "TZTIMESTAMP" => FixDatatype::UtcTimestamp, // FIXME
-                "UTCDATE" => FixDatatype::UtcDateOnly,
-                "UTCDATEONLY" => FixDatatype::UtcDateOnly,
-                "UTCTIMEONLY" => FixDatatype::UtcTimeOnly,
-                "UTCTIMESTAMP" => FixDatatype::UtcTimestamp,
-                "SEQNUM" => FixDatatype::SeqNum,
-                "TIME" => FixDatatype::UtcTimestamp,
-                "XMLDATA" => FixDatatype::XmlData,
-                _ => {
-                    return None;
-                }
+    impl FixDatatype {
+        /// Compares `name` to the set of strings commonly used by QuickFIX's custom
+        /// specification format and returns its associated
+        /// [`Datatype`](super::Datatype) if a match
+        /// was found. The query is case-insensitive.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use fefix_dictionary::FixDatatype;
+        ///
+        /// assert_eq!(FixDatatype::from_quickfix_name("AMT"), Some(FixDatatype::Amt));
+        /// assert_eq!(FixDatatype::from_quickfix_name("Amt"), Some(FixDatatype::Amt));
+        /// assert_eq!(FixDatatype::from_quickfix_name("MONTHYEAR"), Some(FixDatatype::MonthYear));
+        /// assert_eq!(FixDatatype::from_quickfix_name(""), None);
+        /// ```
+        pub fn from_quickfix_name(name: &str) -> Option<Self> {
+            // https://github.com/quickfix/quickfix/blob/b6760f55ac6a46306b4e081bb13b65e6220ab02d/src/C%2B%2B/DataDictionary.cpp#L646-L680
+            Some(match name.to_ascii_uppercase().as_str() {
+                "AMT" => FixDatatype::Amt,
+                "BOOLEAN" => FixDatatype::Boolean,
+                "CHAR" => FixDatatype::Char,
+                "COUNTRY" => FixDatatype::Country,
+                "CURRENCY" => FixDatatype::Currency,
+                "DATA" => FixDatatype::Data,
+                "DATE" => FixDatatype::UtcDateOnly, // FIXME?
+                "DAYOFMONTH" => FixDatatype::DayOfMonth,
+                "EXCHANGE" => FixDatatype::Exchange,
+                "FLOAT" => FixDatatype::Float,
+                "INT" => FixDatatype::Int,
+                "LANGUAGE" => FixDatatype::Language,
+                "LENGTH" => FixDatatype::Length,
+                "LOCALMKTDATE" => FixDatatype::LocalMktDate,
+                "MONTHYEAR" => FixDatatype::MonthYear,
+                "MULTIPLECHARVALUE" | "MULTIPLEVALUESTRING" => FixDatatype::MultipleCharValue,
+                "MULTIPLESTRINGVALUE" => FixDatatype::MultipleStringValue,
+                "NUMINGROUP" => FixDatatype::NumInGroup,
+                "PERCENTAGE" => FixDatatype::Percentage,
+                "PRICE" => FixDatatype::Price,
+                "PRICEOFFSET" => FixDatatype::PriceOffset,
+                "QTY" => FixDatatype::Qty,
+                "STRING" => FixDatatype::String,
+                "TZTIMEONLY" => FixDatatype::UtcTimeOnly, // FIXME
+                "TZTIMESTAMP" => FixDatatype::UtcTimestamp, // FIXME
+                "UTCDATE" => FixDatatype::UtcDateOnly,
+                "UTCDATEONLY" => FixDatatype::UtcDateOnly,
+                "UTCTIMEONLY" => FixDatatype::UtcTimeOnly,
+                "UTCTIMESTAMP" => FixDatatype::UtcTimestamp,
+                "SEQNUM" => FixDatatype::SeqNum,
+                "TIME" => FixDatatype::UtcTimestamp,
+                "XMLDATA" => FixDatatype::XmlData,
+                _ => {
+                    return None;
+                }
+            })
+        }
+
+        /// Returns the name adopted by QuickFIX for `self`.
+        pub fn to_quickfix_name(&self) -> &str {
+            match self {
+                FixDatatype::Int => "INT",
+                FixDatatype::Length => "LENGTH",
+                FixDatatype::Char => "CHAR",
+                FixDatatype::Boolean => "BOOLEAN",
+                FixDatatype::Float => "FLOAT",
+                FixDatatype::Amt => "AMT",
+                FixDatatype::Price => "PRICE",
+                FixDatatype::PriceOffset => "PRICEOFFSET",
+                FixDatatype::Qty => "QTY",
+                FixDatatype::Percentage => "PERCENTAGE",
+                FixDatatype::DayOfMonth => "DAYOFMONTH",
+                FixDatatype::NumInGroup => "NUMINGROUP",
+                FixDatatype::Language => "LANGUAGE",
+                FixDatatype::SeqNum => "SEQNUM",
+                FixDatatype::TagNum => "TAGNUM",
+                FixDatatype::String => "STRING",
+                FixDatatype::Data => "DATA",
+                FixDatatype::MonthYear => "MONTHYEAR",
+                FixDatatype::Currency => "CURRENCY",
+                FixDatatype::Exchange => "EXCHANGE",
+                FixDatatype::LocalMktDate => "LOCALMKTDATE",
+                FixDatatype::MultipleStringValue => "MULTIPLESTRINGVALUE",
+                FixDatatype::UtcTimeOnly => "UTCTIMEONLY",
+                FixDatatype::UtcTimestamp => "UTCTIMESTAMP",
+                FixDatatype::UtcDateOnly => "UTCDATEONLY",
+                FixDatatype::Country => "COUNTRY",
+                FixDatatype::MultipleCharValue => "MULTIPLECHARVALUE",
+                FixDatatype::XmlData => "XMLDATA",
+            }
+        }
+
+        /// Returns the name of `self`, character by character identical to the name
+        /// that appears in the official guidelines. **Generally** primitive datatypes
+        /// will use `snake_case` and non-primitive ones will have `PascalCase`, but
+        /// that's not true for every [`Datatype`](super::Datatype).
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use fefix_dictionary::FixDatatype;
+        ///
+        /// assert_eq!(FixDatatype::Qty.name(), "Qty");
+        /// assert_eq!(FixDatatype::Float.name(), "float");
+        /// assert_eq!(FixDatatype::String.name(), "String");
+        /// ```
+        pub fn name(&self) -> &'static str {
+            // 1. Most primitive data types have `snake_case` names.
+            // 2. Most derivative data types have `PascalCase` names.
+            // 3. `data` and `String` ruin the party and mess it up.
+            //    Why, you ask? Oh, you sweet summer child. You'll learn soon enough
+            //    that nothing makes sense in FIX land.
+            match self {
+                FixDatatype::Int => "int",
+                FixDatatype::Length => "Length",
+                FixDatatype::Char => "char",
+                FixDatatype::Boolean => "Boolean",
+                FixDatatype::Float => "float",
+                FixDatatype::Amt => "Amt",
+                FixDatatype::Price => "Price",
+                FixDatatype::PriceOffset => "PriceOffset",
+                FixDatatype::Qty => "Qty",
+                FixDatatype::Percentage => "Percentage",
+                FixDatatype::DayOfMonth => "DayOfMonth",
+                FixDatatype::NumInGroup => "NumInGroup",
+                FixDatatype::Language => "Language",
+                FixDatatype::SeqNum => "SeqNum",
+                FixDatatype::TagNum => "TagNum",
+                FixDatatype::String => "String",
+                FixDatatype::Data => "data",
+                FixDatatype::MonthYear => "MonthYear",
+                FixDatatype::Currency => "Currency",
+                FixDatatype::Exchange => "Exchange",
+                FixDatatype::LocalMktDate => "LocalMktDate",
+                FixDatatype::MultipleStringValue => "MultipleStringValue",
+                FixDatatype::UtcTimeOnly => "UTCTimeOnly",
+                FixDatatype::UtcTimestamp => "UTCTimestamp",
+                FixDatatype::UtcDateOnly => "UTCDateOnly",
+                FixDatatype::Country => "Country",
+                FixDatatype::MultipleCharValue => "MultipleCharValue",
+                FixDatatype::XmlData => "XMLData",
+            }
+        }
+
+        /// Returns `true` if and only if `self` is a "base type", i.e. a primitive;
+        /// returns `false` otherwise.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use fefix_dictionary::FixDatatype;
+        ///
+        /// assert_eq!(FixDatatype::Float.is_base_type(), true);
+        /// assert_eq!(FixDatatype::Price.is_base_type(), false);
+        /// ```
+        pub fn is_base_type(&self) -> bool {
+            match self {
+                Self::Char | Self::Float | Self::Int | Self::String => true,
+                _ => false,
+            }
+        }
+
+        /// Returns `true` if and only if values of `self` must be preceded by a
+        /// length field, i.e. `self` is either [`FixDatatype::Data`] or
+        /// [`FixDatatype::XmlData`]. Such values may contain the delimiter and
+        /// can only be read once the length is known.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use fefix_dictionary::FixDatatype;
+        ///
+        /// assert_eq!(FixDatatype::Data.is_length_prefixed(), true);
+        /// assert_eq!(FixDatatype::XmlData.is_length_prefixed(), true);
+        /// assert_eq!(FixDatatype::String.is_length_prefixed(), false);
+        /// ```
+        pub fn is_length_prefixed(&self) -> bool {
+            matches!(self, Self::Data | Self::XmlData)
+        }
+
+        /// Synonym for [`FixDatatype::is_length_prefixed`].
+        pub fn requires_associated_length_field(&self) -> bool {
+            self.is_length_prefixed()
+        }
+
+        /// Returns the typical size in bytes of a value of `self`, for
+        /// pre-allocating buffers. Values may be larger (or smaller) than this.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use fefix_dictionary::FixDatatype;
+        ///
+        /// assert_eq!(FixDatatype::Char.byte_count_hint(), 1);
+        /// assert_eq!(FixDatatype::UtcTimestamp.byte_count_hint(), 21);
+        /// ```
+        pub fn byte_count_hint(&self) -> usize {
+            match self {
+                Self::Char | Self::Boolean | Self::NumInGroup => 1,
+                Self::DayOfMonth | Self::Language | Self::Country => 2,
+                Self::Length | Self::TagNum | Self::Currency | Self::MultipleCharValue => 3,
+                Self::Int | Self::Exchange => 4,
+                Self::SeqNum | Self::MonthYear => 6,
+                Self::Float
+                | Self::Amt
+                | Self::Price
+                | Self::PriceOffset
+                | Self::Qty
+                | Self::Percentage => 8,
+                Self::LocalMktDate | Self::UtcDateOnly | Self::MultipleStringValue => 8,
+                // HH:MM:SS.sss
+                Self::UtcTimeOnly => 12,
+                Self::String => 16,
+                // YYYYMMDD-HH:MM:SS.sss
+                Self::UtcTimestamp => 21,
+                Self::Data | Self::XmlData => 32,
+            }
+        }
+
+        /// Returns the primitive [`Datatype`](super::Datatype) from which `self` is derived. If
+        /// `self` is primitive already, returns `self` unchanged.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use fefix_dictionary::FixDatatype;
+        ///
+        /// assert_eq!(FixDatatype::Float.base_type(), FixDatatype::Float);
+        /// assert_eq!(FixDatatype::Price.base_type(), FixDatatype::Float);
+        /// ```
+        pub fn base_type(&self) -> Self {
+            let dt = match self {
+                Self::Char | Self::Boolean => Self::Char,
+                Self::Float
+                | Self::Amt
+                | Self::Price
+                | Self::PriceOffset
+                | Self::Qty
+                | Self::Percentage => Self::Float,
+                Self::Int
+                | Self::DayOfMonth
+                | Self::Length
+                | Self::NumInGroup
+                | Self::SeqNum
+                | Self::TagNum => Self::Int,
+                _ => Self::String,
+            };
+            debug_assert!(dt.is_base_type());
+            dt
+        }
+
+        /// Returns an [`Iterator`] over all variants of
+        /// [`Datatype`](super::Datatype).
+        pub fn iter_all() -> impl Iterator<Item = Self> {
+            <Self as IntoEnumIterator>::iter()
+        }
+    }
+
+    #[cfg(test)]
+    mod test {
+        use super::*;
+        use std::collections::HashSet;
+
+        #[test]
+        fn iter_all_unique() {
+            let as_vec = FixDatatype::iter_all().collect::<Vec<FixDatatype>>();
+            let as_set = FixDatatype::iter_all().collect::<HashSet<FixDatatype>>();
+            assert_eq!(as_vec.len(), as_set.len());
+        }
+
+        #[test]
+        fn more_than_20_datatypes() {
+            // According to the official documentation, FIX has "about 20 data
+            // types". Including recent revisions, we should well exceed that
+            // number.
+            assert!(FixDatatype::iter_all().count() > 20);
+        }
+
+        #[test]
+        fn names_are_unique() {
+            let as_vec = FixDatatype::iter_all()
+                .map(|dt| dt.name())
+                .collect::<Vec<&str>>();
+            let as_set = FixDatatype::iter_all()
+                .map(|dt| dt.name())
+                .collect::<HashSet<&str>>();
+            assert_eq!(as_vec.len(), as_set.len());
+        }
+
+        #[test]
+        fn base_type_is_itself() {
+            for dt in FixDatatype::iter_all() {
+                if dt.is_base_type() {
+                    assert_eq!(dt.base_type(), dt);
+                } else {
+                    assert_ne!(dt.base_type(), dt);
+                }
+            }
+        }
+
+        #[test]
+        fn only_data_and_xml_data_are_length_prefixed() {
+            let length_prefixed = FixDatatype::iter_all()
+                .filter(|dt| dt.is_length_prefixed())
+                .collect::<HashSet<FixDatatype>>();
+            assert_eq!(
+                length_prefixed,
+                [FixDatatype::Data, FixDatatype::XmlData]
+                    .into_iter()
+                    .collect()
+            );
+            for dt in FixDatatype::iter_all() {
+                assert_eq!(
+                    dt.is_length_prefixed(),
+                    dt.requires_associated_length_field()
+                );
+            }
+        }
+
+        #[test]
+        fn base_type_is_actually_base_type() {
+            for dt in FixDatatype::iter_all() {
+                assert!(dt.base_type().is_base_type());
+            }
+        }
+    }
+}
+
+/// A field is identified by a unique tag number and a name. Each field in a
+/// message is associated with a value.
+#[derive(Clone, Debug)]
+struct FieldData {
+    /// A human readable string representing the name of the field.
+    name: SmartString,
+    /// **Primary key.** A positive integer representing the unique
+    /// identifier for this field type.
+    tag: u32,
+    /// The datatype of the field.
+    data_type_name: SmartString,
+    /// The associated data field. If given, this field represents the length of
+    /// the referenced data field
+    associated_data_tag: Option<usize>,
+    value_restrictions: Option<Vec<FieldEnumData>>,
+    /// Abbreviated form of the Name, typically to specify the element name when
+    /// the field is used in an XML message. Can be overridden by BaseCategory /
+    /// BaseCategoryAbbrName.
+    abbr_name: Option<String>,
+    /// Specifies the base message category when field is used in an XML message.
+    base_category_id: Option<usize>,
+    /// If BaseCategory is specified, this is the XML element identifier to use
+    /// for this field, overriding AbbrName.
+    base_category_abbr_name: Option<String>,
+    /// Indicates whether the field is required in an XML message.
+    required: bool,
+    description: Option<String>,
+    /// Attributes of the field definition that aren't part of the standard
+    /// QuickFIX format, e.g. vendor extensions.
+    extra_attributes: FnvHashMap<String, String>,
+}
+
+#[derive(Clone, Debug)]
+struct FieldEnumData {
+    value: String,
+    description: String,
+}
+
+/// A limitation imposed on the value of a specific FIX [`Field`].  Also known as
+/// "code set".
+#[derive(Debug)]
+pub struct FieldEnum<'a>(&'a Dictionary, &'a FieldEnumData);
+
+impl<'a> FieldEnum<'a> {
+    /// Returns the string representation of this field variant.
+    pub fn value(&self) -> &str {
+        &self.1.value[..]
+    }
+
+    /// Returns the documentation description for `self`.
+    pub fn description(&self) -> &str {
+        &self.1.description[..]
+    }
+
+    /// Returns the description of `self` as a Rust constant name, in
+    /// `SCREAMING_SNAKE_CASE`. Characters other than ASCII letters and digits
+    /// separate words and are stripped; names that would start with a digit
+    /// are prefixed with `V_`.
+    ///
+    /// ```
+    /// use fefix_dictionary::Dictionary;
+    ///
+    /// let dict = Dictionary::fix44();
+    /// let field = dict.field_by_name("OrdType").unwrap();
+    /// let market = field.enums().unwrap().find(|e| e.value() == "1").unwrap();
+    /// assert_eq!(market.rust_const_name(), "MARKET");
+    /// ```
+    pub fn rust_const_name(&self) -> String {
+        let name = description_words(self.description())
+            .iter()
+            .map(|word| word.to_ascii_uppercase())
+            .collect::<Vec<_>>()
+            .join("_");
+        if name.is_empty() || name.starts_with(|c: char| c.is_ascii_digit()) {
+            format!("V_{}", name)
+        } else {
+            name
+        }
+    }
+
+    /// Returns the description of `self` as a Rust enum variant name, in
+    /// `PascalCase`. Characters other than ASCII letters and digits separate
+    /// words and are stripped; names that would start with a digit are
+    /// prefixed with `V`.
+    ///
+    /// ```
+    /// use fefix_dictionary::Dictionary;
+    ///
+    /// let dict = Dictionary::fix44();
+    /// let field = dict.field_by_name("OrdType").unwrap();
+    /// let limit = field.enums().unwrap().find(|e| e.value() == "2").unwrap();
+    /// assert_eq!(limit.rust_variant_name(), "Limit");
+    /// ```
+    pub fn rust_variant_name(&self) -> String {
+        let name = description_words(self.description())
+            .iter()
+            .map(|word| {
+                let (first, rest) = word.split_at(1);
+                first.to_ascii_uppercase() + rest
+            })
+            .collect::<String>();
+        if name.is_empty() || name.starts_with(|c: char| c.is_ascii_digit()) {
+            format!("V{}", name)
+        } else {
+            name
+        }
+    }
+}
+
+/// Splits a free-form description into lowercase ASCII words, on both
+/// non-alphanumeric characters and case boundaries.
+fn description_words(description: &str) -> Vec<String> {
+    description
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|chunk| !chunk.is_empty())
+        .flat_map(|chunk| {
+            snake_case(chunk)
+                .split('_')
+                .map(str::to_string)
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// A field is the most granular message structure abstraction. It carries a
+/// specific business meaning as described by the FIX specifications. The data
+/// domain of a [`Field`] is either a [`Datatype`] or a "code set", i.e.
+/// enumeration.
+#[derive(Debug, Copy, Clone)]
+pub struct Field<'a>(&'a Dictionary, &'a FieldData);
+
+const ONIXS_URL_PREFIX: &str = "https://www.onixs.biz/fix-dictionary/";
+
+impl<'a> Field<'a> {
+    pub fn doc_url_onixs(&self, version: &str) -> String {
+        let v = match version {
+            "FIX.4.0" => "4.0",
+            "FIX.4.1" => "4.1",
+            "FIX.4.2" => "4.2",
+            "FIX.4.3" => "4.3",
+            "FIX.4.4" => "4.4",
+            "FIX.5.0" => "5.0",
+            "FIX.5.0SP1" => "5.0.SP1",
+            "FIX.5.0SP2" => "5.0.SP2",
+            "FIXT.1.1" => "FIXT.1.1",
+            s => s,
+        };
+        format!(
+            "{}{}/tagNum_{}.html",
+            ONIXS_URL_PREFIX,
+            v,
+            self.1.tag.to_string().as_str()
+        )
+    }
+
+    pub fn is_num_in_group(&self) -> bool {
+        fn nth_char_is_uppercase(s: &str, i: usize) -> bool {
+            s.chars().nth(i).map(|c| c.is_ascii_uppercase()) == Some(true)
+        }
+
+        self.fix_datatype().base_type() == FixDatatype::NumInGroup
+            || self.name().ends_with("Len")
+            || (self.name().starts_with("No") && nth_char_is_uppercase(self.name(), 2))
+    }
+
+    /// Returns the [`FixDatatype`] of `self`.
+    pub fn fix_datatype(&self) -> FixDatatype {
+        self.data_type().basetype()
+    }
+
+    /// Returns an owned copy of the definition of `self`, with `datatype`
+    /// instead of its own. Everything else, including enumerated values, is
+    /// preserved.
+    ///
+    /// The copy has the same tag and name as `self`: it must replace the
+    /// original definition rather than being added next to it, or
+    /// [`Dictionary::from_parts`] fails with [`BuildError::DuplicateTag`].
+    ///
+    /// ```
+    /// use fefix_dictionary::{Dictionary, FixDatatype};
+    ///
+    /// let dict = Dictionary::fix44();
+    /// let price = dict.field_by_name("Price").unwrap();
+    /// let price_as_string = price.clone_with_new_datatype(FixDatatype::String);
+    ///
+    /// let (mut fields, datatypes, components, messages) = dict.to_parts();
+    /// for field in fields.iter_mut().filter(|field| field.tag == 44) {
+    ///     *field = price_as_string.clone();
+    /// }
+    /// let dict =
+    ///     Dictionary::from_parts(dict.version(), &fields, &datatypes, &components, &messages)
+    ///         .unwrap();
+    /// let price = dict.field_by_name("Price").unwrap();
+    /// assert_eq!(price.fix_datatype(), FixDatatype::String);
+    /// ```
+    pub fn clone_with_new_datatype(&self, datatype: FixDatatype) -> FieldDef {
+        FieldDef {
+            datatype,
+            ..parts::field_def(self.0, self.1)
+        }
+    }
+
+    /// Returns the name of `self`. Field names are unique across each FIX
+    /// [`Dictionary`].
+    pub fn name(&self) -> &str {
+        self.1.name.as_str()
+    }
+
+    /// Returns the name of `self` in `snake_case`, e.g. for use as a Rust
+    /// identifier. Acronyms are kept together, including their plural form.
+    ///
+    /// ```
+    /// use fefix_dictionary::Dictionary;
+    ///
+    /// let dict = Dictionary::fix44();
+    /// let field = dict.field_by_name("MDReqID").unwrap();
+    /// assert_eq!(field.name_snake_case(), "md_req_id");
+    /// ```
+    pub fn name_snake_case(&self) -> String {
+        snake_case(self.name())
+    }
+
+    /// Returns the numeric tag of `self`. Field tags are unique across each FIX
+    /// [`Dictionary`].
+    pub fn tag(&self) -> TagU32 {
+        TagU32::new(self.1.tag).unwrap()
+    }
+
+    /// Returns the numeric tag of `self` as a plain [`u32`], e.g. for logging
+    /// or FFI. [`TagU32`] converts to [`u32`] via [`From`] as well.
+    ///
+    /// ```
+    /// use fefix_dictionary::Dictionary;
+    ///
+    /// let dict = Dictionary::fix44();
+    /// let field = dict.field_by_name("ClOrdID").unwrap();
+    /// assert_eq!(field.tag_u32(), 11);
+    /// assert_eq!(u32::from(field.tag()), field.tag_u32());
+    /// assert_eq!(field.tag().to_string(), "11");
+    /// assert_eq!(dict.field_by_tag(field.tag_u32()).unwrap().name(), "ClOrdID");
+    /// ```
+    pub fn tag_u32(&self) -> u32 {
+        self.1.tag
+    }
+
+    /// Returns the number of ASCII digits of the tag of `self` in `tag=value`
+    /// encoding, e.g. 1 for `BeginString <8>` and 3 for `TestReqID <112>`.
+    ///
+    /// ```
+    /// use fefix_dictionary::Dictionary;
+    ///
+    /// let dict = Dictionary::fix44();
+    /// assert_eq!(dict.field_by_tag(8).unwrap().tag_byte_width(), 1);
+    /// assert_eq!(dict.field_by_tag(112).unwrap().tag_byte_width(), 3);
+    /// ```
+    pub fn tag_byte_width(&self) -> usize {
+        // Tags are never zero.
+        self.1.tag.ilog10() as usize + 1
+    }
+
+    /// In case this field allows any value, it returns `None`; otherwise; it
+    /// returns an [`Iterator`] of all allowed values.
+    pub fn enums(&self) -> Option<impl Iterator<Item = FieldEnum>> {
+        self.1
+            .value_restrictions
+            .as_ref()
+            .map(move |v| v.iter().map(move |f| FieldEnum(self.0, f)))
+    }
+
+    /// Returns the [`Datatype`] of `self`.
+    pub fn data_type(&self) -> Datatype {
+        self.0
+            .datatype_by_name(self.1.data_type_name.as_str())
+            .unwrap()
+    }
+
+    /// Returns the tag of the data field whose length is given by `self`, if
+    /// `self` is a `Length` field.
+    ///
+    /// ```
+    /// use fefix_dictionary::Dictionary;
+    ///
+    /// let dict = Dictionary::fix44();
+    /// let raw_data_length = dict.field_by_name("RawDataLength").unwrap();
+    /// assert_eq!(raw_data_length.data_tag().unwrap().get(), 96);
+    /// assert!(dict.field_by_name("BodyLength").unwrap().data_tag().is_none());
+    /// ```
+    pub fn data_tag(&self) -> Option<TagU32> {
+        self.1
+            .associated_data_tag
+            .map(|tag| TagU32::new(tag as u32).unwrap())
+    }
+
+    /// Returns the abbreviated name of `self`, used as element name in FIXML.
+    pub fn abbr_name(&self) -> Option<&str> {
+        self.1.abbr_name.as_deref()
+    }
+
+    /// Returns the value of the attribute `name` of this field's definition, if
+    /// it's not part of the standard QuickFIX format. Vendors sometimes extend
+    /// field definitions with custom attributes such as `maxLength`.
+    ///
+    /// ```
+    /// use fefix_dictionary::Dictionary;
+    ///
+    /// let spec = r#"
+    /// <fix type='FIX' major='4' minor='4' servicepack='0'>
+    ///     <header />
+    ///     <messages />
+    ///     <trailer />
+    ///     <components />
+    ///     <fields>
+    ///         <field number='60' name='TransactTime' type='UTCTIMESTAMP' tz='CET' />
+    ///     </fields>
+    /// </fix>
+    /// "#;
+    /// let dict = Dictionary::from_quickfix_spec(spec).unwrap();
+    /// let field = dict.field_by_tag(60).unwrap();
+    /// assert_eq!(field.extra_attribute("tz"), Some("CET"));
+    /// assert_eq!(field.extra_attribute("type"), None);
+    /// ```
+    pub fn extra_attribute(&self, name: &str) -> Option<&str> {
+        self.1.extra_attributes.get(name).map(String::as_str)
+    }
+
+    /// Returns an [`Iterator`] over all attributes of this field's definition
+    /// that aren't part of the standard QuickFIX format, as name-value pairs.
+    /// The ordering of items is not specified.
+    pub fn extra_attributes(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.1
+            .extra_attributes
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_str()))
+    }
+
+    /// Returns `false` if `self` is left out of FIXML messages, e.g. because
+    /// the XML encoding already conveys its value, as for `BodyLength <9>`.
+    /// QuickFIX specs can mark such fields with `notReqXML='Y'`.
+    pub fn required_in_xml_messages(&self) -> bool {
+        self.1.required
+    }
+
+    /// Returns the description of `self`, if any.
+    pub fn description(&self) -> Option<&str> {
+        self.1.description.as_ref().map(|s| s.as_str())
+    }
+
+    /// Returns the [`EffectiveType`] of `self`, i.e. its [`Datatype`] wrapped
+    /// according to whether `self` is restricted to a code set or not.
+    ///
+    /// ```
+    /// use fefix_dictionary::{Dictionary, EffectiveType};
+    ///
+    /// let dict = Dictionary::fix44();
+    /// let side = dict.field_by_name("Side").unwrap();
+    /// assert!(matches!(side.effective_type(), EffectiveType::CodeSet(_)));
+    /// ```
+    pub fn effective_type(&self) -> EffectiveType<'_> {
+        if self.1.value_restrictions.is_some() {
+            EffectiveType::CodeSet(self.data_type())
+        } else {
+            EffectiveType::Plain(self.data_type())
+        }
+    }
+}
+
+/// Converts the `PascalCase` name of a FIX field, component, or message into
+/// `snake_case`. A new word starts at every uppercase letter which follows a
+/// lowercase letter or a digit, and at the last uppercase letter of an acronym
+/// that is followed by a lowercase letter (unless that's just a plural `s`).
+fn snake_case(name: &str) -> String {
+    let chars = name.chars().collect::<Vec<char>>();
+    let mut snake = String::with_capacity(name.len() + 4);
+    for (i, c) in chars.iter().enumerate() {
+        if i > 0 && c.is_ascii_uppercase() {
+            let prev = chars[i - 1];
+            let next = chars.get(i + 1).copied();
+            let after_next = chars.get(i + 2).copied();
+            let is_plural_s =
+                next == Some('s') && !matches!(after_next, Some(c) if c.is_ascii_lowercase());
+            let starts_word = prev.is_ascii_lowercase()
+                || prev.is_ascii_digit()
+                || (prev.is_ascii_uppercase()
+                    && matches!(next, Some(c) if c.is_ascii_lowercase())
+                    && !is_plural_s);
+            if starts_word {
+                snake.push('_');
+            }
+        }
+        snake.push(c.to_ascii_lowercase());
+    }
+    snake
+}
+
+/// The data domain of a [`Field`], taking code sets into account. See
+/// [`Field::effective_type`].
+#[derive(Debug)]
+pub enum EffectiveType<'a> {
+    /// The field only allows the values of its code set, all of which are of
+    /// the given [`Datatype`].
+    CodeSet(Datatype<'a>),
+    /// The field allows any value of the given [`Datatype`].
+    Plain(Datatype<'a>),
+}
+
+impl<'a> EffectiveType<'a> {
+    /// Returns the underlying [`Datatype`] of `self`, regardless of code sets.
+    pub fn datatype(&self) -> &Datatype<'a> {
+        match self {
+            EffectiveType::CodeSet(dt) => dt,
+            EffectiveType::Plain(dt) => dt,
+        }
+    }
+}
+
+impl<'a> IsFieldDefinition for Field<'a> {
+    fn name(&self) -> &str {
+        self.1.name.as_str()
+    }
+
+    fn tag(&self) -> TagU32 {
+        TagU32::new(self.1.tag).expect("Invalid FIX tag (0)")
+    }
+
+    fn location(&self) -> FieldLocation {
+        FieldLocation::Body // FIXME
+    }
+}
+
+#[derive(Clone, Debug)]
+#[allow(dead_code)]
+enum LayoutItemKindData {
+    Component {
+        name: SmartString,
+    },
+    Group {
+        len_field_tag: u32,
+        items: Vec<LayoutItemData>,
+    },
+    Field {
+        tag: u32,
+    },
+}
+
+#[derive(Clone, Debug)]
+struct LayoutItemData {
+    required: bool,
+    kind: LayoutItemKindData,
+}
+
+pub trait IsFieldDefinition {
+    /// Returns the FIX tag associated with `self`.
+    fn tag(&self) -> TagU32;
+
+    /// Returns the official, ASCII, human-readable name associated with `self`.
+    fn name(&self) -> &str;
+
+    /// Returns the field location of `self`.
+    fn location(&self) -> FieldLocation;
+}
+
+fn layout_item_kind<'a>(item: &'a LayoutItemKindData, dict: &'a Dictionary) -> LayoutItemKind<'a> {
+    match item {
+        LayoutItemKindData::Component { name } => {
+            LayoutItemKind::Component(dict.component_by_name(name).unwrap())
+        }
+        LayoutItemKindData::Group {
+            len_field_tag,
+            items: items_data,
+        } => {
+            let items = items_data
+                .iter()
+                .map(|item_data| LayoutItem(dict, item_data))
+                .collect::<Vec<_>>();
+            let len_field = dict.field_by_tag(*len_field_tag).unwrap();
+            LayoutItemKind::Group(len_field, items)
+        }
+        LayoutItemKindData::Field { tag } => {
+            LayoutItemKind::Field(dict.field_by_tag(*tag).unwrap())
+        }
+    }
+}
+
+/// An entry in a sequence of FIX field definitions.
+#[derive(Clone, Debug)]
+pub struct LayoutItem<'a>(&'a Dictionary, &'a LayoutItemData);
+
+/// The kind of element contained in a [`Message`].
+#[derive(Debug)]
+pub enum LayoutItemKind<'a> {
+    /// This component item is another component.
+    Component(Component<'a>),
+    /// This component item is a FIX repeating group.
+    Group(Field<'a>, Vec<LayoutItem<'a>>),
+    /// This component item is a FIX field.
+    Field(Field<'a>),
+}
+
+/// A [`LayoutItem`] together with its position inside the layout of a
+/// [`Dictionary`]. See [`Dictionary::iter_all_layout_items`].
+#[derive(Clone, Debug)]
+pub struct LayoutItemRef<'a> {
+    /// The layout item itself.
+    pub item: LayoutItem<'a>,
+    /// The name of the [`Message`] or [`Component`] that contains
+    /// [`LayoutItemRef::item`], or the name of the `NumInGroup` field of the
+    /// enclosing repeating group. If that field isn't defined, the group
+    /// inherits the parent of its own layout item.
+    pub parent: &'a str,
+    /// The number of repeating groups between [`LayoutItemRef::item`] and the
+    /// top-level [`Message`] or [`Component`]. Top-level items have depth 0.
+    pub depth: usize,
+}
+
+impl<'a> LayoutItem<'a> {
+    /// Returns `true` if `self` is required in order to have a valid definition
+    /// of its parent container, `false` otherwise.
+    pub fn required(&self) -> bool {
+        self.1.required
+    }
+
+    /// Returns the [`LayoutItemKind`] of `self`.
+    pub fn kind(&self) -> LayoutItemKind {
+        layout_item_kind(&self.1.kind, self.0)
+    }
+
+    /// Returns the human-readable name of `self`.
+    pub fn tag_text(&self) -> String {
+        match &self.1.kind {
+            LayoutItemKindData::Component { name } => {
+                self.0.component_by_name(name).unwrap().name().to_string()
+            }
+            LayoutItemKindData::Group {
+                len_field_tag,
+                items: _items,
+            } => self
+                .0
+                .field_by_tag(*len_field_tag)
+                .unwrap()
+                .name()
+                .to_string(),
+            LayoutItemKindData::Field { tag } => {
+                self.0.field_by_tag(*tag).unwrap().name().to_string()
+            }
+        }
+    }
+}
+
+type LayoutItems = Vec<LayoutItemData>;
+
+#[derive(Clone, Debug)]
+struct MessageData {
+    /// The unique integer identifier of this message type.
+    component_id: u32,
+    /// **Primary key**. The unique character identifier of this message
+    /// type; used literally in FIX messages.
+    msg_type: SmartString,
+    /// The name of this message type.
+    name: SmartString,
+    /// Identifier of the category to which this message belongs.
+    category_name: SmartString,
+    /// Identifier of the section to which this message belongs.
+    section_id: String,
+    layout_items: LayoutItems,
+    /// The abbreviated name of this message, when used in an XML context.
+    abbr_name: Option<SmartString>,
+    /// A boolean used to indicate if the message is to be generated as part
+    /// of FIXML.
+    required: bool,
+    description: String,
+    elaboration: Option<String>,
+    /// The name of the message from which this message inherits its fields,
+    /// if any.
+    base_message: Option<SmartString>,
+    /// The message types of the messages that are expected in response to
+    /// this message.
+    responses: Vec<SmartString>,
+}
+
+/// A [`Message`] is a unit of information sent on the wire between
+/// counterparties. Every [`Message`] is composed of fields and/or components.
+#[derive(Debug)]
+pub struct Message<'a>(&'a Dictionary, &'a MessageData);
+
+impl<'a> Message<'a> {
+    /// Returns the human-readable name of `self`.
+    pub fn name(&self) -> &str {
+        self.1.name.as_str()
+    }
+
+    /// Returns the name of the message from which `self` inherits its fields,
+    /// if any. See [`Dictionary::message_inherits_from`].
+    pub fn base_message(&self) -> Option<&str> {
+        self.1.base_message.as_deref()
+    }
+
+    /// Returns the [`Category`] to which `self` belongs, if any.
+    pub fn category(&self) -> Option<Category<'_>> {
+        self.0.category_by_name(self.1.category_name.as_str())
+    }
+
+    /// Returns the message types of the messages that are expected in response
+    /// to `self`, if known. See [`Dictionary::responses_for`].
+    pub fn response_msg_types(&self) -> impl Iterator<Item = &str> {
+        self.1.responses.iter().map(|msg_type| msg_type.as_str())
+    }
+
+    /// Returns all fields of the body of `self`, with components and repeating
+    /// groups expanded in place. The fields of the base message (if any) come
+    /// first. Each field appears only once, at its first position.
+    ///
+    /// ```
+    /// use fefix_dictionary::Dictionary;
+    ///
+    /// let dict = Dictionary::fix44();
+    /// let msg = dict.message_by_msgtype("0").unwrap();
+    /// let fields = msg.flatten_fields();
+    /// assert_eq!(fields[0].name(), "TestReqID");
+    /// ```
+    pub fn flatten_fields(&self) -> Vec<Field<'a>> {
+        let mut tags = Vec::new();
+        let mut message = Some(self.1);
+        let mut visited = FnvHashSet::default();
+        let mut bodies = Vec::new();
+        // Walk up the inheritance chain, then expand from its root down.
+        while let Some(data) = message.filter(|data| visited.insert(&data.name)) {
+            bodies.push(data);
+            message = data
+                .base_message
+                .as_ref()
+                .and_then(|name| self.0.message_msgtypes_by_name.get(name))
+                .and_then(|msg_type| self.0.messages_by_msgtype.get(msg_type));
+        }
+        for data in bodies.iter().rev() {
+            let items = data
+                .layout_items
+                .iter()
+                .map(|item| LayoutItem(self.0, item));
+            expand_layout_tags(items, &mut tags);
+        }
+        let mut seen = FnvHashSet::default();
+        tags.into_iter()
+            .filter(|tag| seen.insert(*tag))
+            .filter_map(|tag| self.0.field_by_tag(tag.get()))
+            .collect()
+    }
+
+    /// Returns the name of `self` in `snake_case`. See
+    /// [`Field::name_snake_case`].
+    ///
+    /// ```
+    /// use fefix_dictionary::Dictionary;
+    ///
+    /// let dict = Dictionary::fix44();
+    /// let msg = dict.message_by_msgtype("V").unwrap();
+    /// assert_eq!(msg.name_snake_case(), "market_data_request");
+    /// ```
+    pub fn name_snake_case(&self) -> String {
+        snake_case(self.name())
+    }
+
+    /// Returns the name of `self` in `PascalCase`, following Rust naming
+    /// conventions: acronyms are capitalized like any other word. Most message
+    /// names are already in this form.
+    ///
+    /// ```
+    /// use fefix_dictionary::Dictionary;
+    ///
+    /// let dict = Dictionary::fix44();
+    /// let msg = dict.message_by_msgtype("6").unwrap();
+    /// assert_eq!(msg.name(), "IOI");
+    /// assert_eq!(msg.name_pascal_case(), "Ioi");
+    /// ```
+    pub fn name_pascal_case(&self) -> String {
+        snake_case(self.name())
+            .split('_')
+            .map(|word| {
+                let mut chars = word.chars();
+                chars
+                    .next()
+                    .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+                    .unwrap_or_default()
             })
+            .collect()
+    }
+
+    /// Returns a valid Rust module name for `self`, i.e. its name in
+    /// `snake_case` with any character that isn't allowed in identifiers
+    /// replaced by an underscore.
+    pub fn module_name(&self) -> String {
+        let name = snake_case(self.name())
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect::<String>();
+        if name.starts_with(|c: char| c.is_ascii_digit()) {
+            format!("_{}", name)
+        } else {
+            name
         }
+    }
 
-        /// Returns the name adopted by QuickFIX for `self`.
-        pub fn to_quickfix_name(&self) -> &str {
-            match self {
-                FixDatatype::Int => "INT",
-                FixDatatype::Length => "LENGTH",
-                FixDatatype::Char => "CHAR",
-                FixDatatype::Boolean => "BOOLEAN",
-                FixDatatype::Float => "FLOAT",
-                FixDatatype::Amt => "AMT",
-                FixDatatype::Price => "PRICE",
-                FixDatatype::PriceOffset => "PRICEOFFSET",
-                FixDatatype::Qty => "QTY",
-                FixDatatype::Percentage => "PERCENTAGE",
-                FixDatatype::DayOfMonth => "DAYOFMONTH",
-                FixDatatype::NumInGroup => "NUMINGROUP",
-                FixDatatype::Language => "LANGUAGE",
-                FixDatatype::SeqNum => "SEQNUM",
-                FixDatatype::TagNum => "TAGNUM",
-                FixDatatype::String => "STRING",
-                FixDatatype::Data => "DATA",
-                FixDatatype::MonthYear => "MONTHYEAR",
-                FixDatatype::Currency => "CURRENCY",
-                FixDatatype::Exchange => "EXCHANGE",
-                FixDatatype::LocalMktDate => "LOCALMKTDATE",
-                FixDatatype::MultipleStringValue => "MULTIPLESTRINGVALUE",
-                FixDatatype::UtcTimeOnly => "UTCTIMEONLY",
-                FixDatatype::UtcTimestamp => "UTCTIMESTAMP",
-                FixDatatype::UtcDateOnly => "UTCDATEONLY",
-                FixDatatype::Country => "COUNTRY",
-                FixDatatype::MultipleCharValue => "MULTIPLECHARVALUE",
-                FixDatatype::XmlData => "XMLDATA",
+    /// Returns the message type of `self`.
+    pub fn msg_type(&self) -> &str {
+        self.1.msg_type.as_str()
+    }
+
+    /// Returns the description associated with `self`.
+    pub fn description(&self) -> &str {
+        &self.1.description
+    }
+
+    /// Returns the name of the [`Section`] to which `self` belongs, if known.
+    /// Standard messages are always assigned to their section, even when the
+    /// specification file doesn't carry this information.
+    ///
+    /// ```
+    /// use fefix_dictionary::Dictionary;
+    ///
+    /// let dict = Dictionary::fix44();
+    /// let msg = dict.message_by_name("NewOrderSingle").unwrap();
+    /// assert_eq!(msg.section(), Some("Trade"));
+    /// ```
+    pub fn section(&self) -> Option<&str> {
+        if self.1.section_id.is_empty() {
+            None
+        } else {
+            Some(self.1.section_id.as_str())
+        }
+    }
+
+    pub fn group_info(&self, num_in_group_tag: TagU32) -> Option<TagU32> {
+        self.layout().find_map(|layout_item| {
+            if let LayoutItemKind::Group(field, items) = layout_item.kind() {
+                if field.tag() == num_in_group_tag {
+                    if let LayoutItemKind::Field(f) = items[0].kind() {
+                        Some(f.tag())
+                    } else {
+                        None
+                    }
+                } else {
+                    None
+                }
+            } else if let LayoutItemKind::Component(_component) = layout_item.kind() {
+                None
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Returns the component ID of `self`.
+    pub fn component_id(&self) -> u32 {
+        self.1.component_id
+    }
+
+    /// Returns an [`Iterator`] over all items that are part of `self`.
+    ///
+    /// Messages parsed from QuickFIX specification files only contain their
+    /// body, i.e. there's no `BeginString <8>`, `BodyLength <9>`, `MsgType
+    /// <35>`, nor `CheckSum <10>`; see [`Message::includes_standard_header`]
+    /// and [`Dictionary::full_message_layout`].
+    pub fn layout(&self) -> impl Iterator<Item = LayoutItem> {
+        self.1
+            .layout_items
+            .iter()
+            .map(move |data| LayoutItem(self.0, data))
+    }
+
+    /// Returns `true` if [`Message::layout`] also contains the standard header
+    /// (and, presumably, trailer) of `self`, `false` if it only contains the
+    /// message body. This is always `false` for messages parsed from QuickFIX
+    /// specification files, which define the standard header and trailer
+    /// separately; use [`Dictionary::full_message_layout`] to get everything.
+    ///
+    /// ```
+    /// use fefix_dictionary::Dictionary;
+    ///
+    /// let dict = Dictionary::fix44();
+    /// let msg = dict.message_by_name("Heartbeat").unwrap();
+    /// assert!(!msg.includes_standard_header());
+    /// ```
+    pub fn includes_standard_header(&self) -> bool {
+        self.layout().any(|item| match item.kind() {
+            LayoutItemKind::Component(component) => component.name() == "StandardHeader",
+            LayoutItemKind::Field(field) => field.tag().get() == 8,
+            LayoutItemKind::Group(..) => false,
+        })
+    }
+
+    pub fn fixml_required(&self) -> bool {
+        self.1.required
+    }
+}
+
+/// A [`Section`] is a collection of many [`Component`]-s. It has no practical
+/// effect on encoding and decoding of FIX data and it's only used for
+/// documentation and human readability.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Section {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::cmp::Ordering;
+    use std::collections::HashSet;
+
+    #[test]
+    fn fix44_quickfix_is_ok() {
+        let dict = Dictionary::fix44();
+        let msg_heartbeat = dict.message_by_name("Heartbeat").unwrap();
+        assert_eq!(msg_heartbeat.msg_type(), "0");
+        assert_eq!(msg_heartbeat.name(), "Heartbeat".to_string());
+        assert!(msg_heartbeat.layout().any(|c| {
+            if let LayoutItemKind::Field(f) = c.kind() {
+                f.name() == "TestReqID"
+            } else {
+                false
+            }
+        }));
+    }
+
+    #[test]
+    fn all_datatypes_are_used_at_least_once() {
+        for dict in Dictionary::common_dictionaries().iter() {
+            let datatypes_count = dict.datatypes().len();
+            let mut datatypes = HashSet::new();
+            for field in dict.fields() {
+                datatypes.insert(field.data_type().name().to_string());
             }
+            assert_eq!(datatypes_count, datatypes.len());
+        }
+    }
+
+    #[test]
+    fn at_least_one_datatype() {
+        for dict in Dictionary::common_dictionaries().iter() {
+            assert!(dict.datatypes().len() >= 1);
         }
+    }
 
-        /// Returns the name of `self`, character by character identical to the name
-        /// that appears in the official guidelines. **Generally** primitive datatypes
-        /// will use `snake_case` and non-primitive ones will have `PascalCase`, but
-        /// that's not true for every [`Datatype`](super::Datatype).
-        ///
-        /// # Examples
-        ///
-        /// ```
-        /// use fefix_dictionary::FixDatatype;
-        ///
-        /// assert_eq!(FixDatatype::Qty.name(), "Qty");
-        /// assert_eq!(FixDatatype::Float.name(), "float");
-        /// assert_eq!(FixDatatype::String.name(), "String");
-        /// ```
-        pub fn name(&self) -> &'static str {
-            // 1. Most primitive data types have `snake_case` names.
-            // 2. Most derivative data types have `PascalCase` names.
-            // 3. `data` and `String` ruin the party and mess it up.
-            //    Why, you ask? Oh, you sweet summer child. You'll learn soon enough
-            //    that nothing makes sense in FIX land.
-            match self {
-                FixDatatype::Int => "int",
-                FixDatatype::Length => "Length",
-                FixDatatype::Char => "char",
-                FixDatatype::Boolean => "Boolean",
-                FixDatatype::Float => "float",
-                FixDatatype::Amt => "Amt",
-                FixDatatype::Price => "Price",
-                FixDatatype::PriceOffset => "PriceOffset",
-                FixDatatype::Qty => "Qty",
-                FixDatatype::Percentage => "Percentage",
-                FixDatatype::DayOfMonth => "DayOfMonth",
-                FixDatatype::NumInGroup => "NumInGroup",
-                FixDatatype::Language => "Language",
-                FixDatatype::SeqNum => "SeqNum",
-                FixDatatype::TagNum => "TagNum",
-                FixDatatype::String => "String",
-                FixDatatype::Data => "data",
-                FixDatatype::MonthYear => "MonthYear",
-                FixDatatype::Currency => "Currency",
-                FixDatatype::Exchange => "Exchange",
-                FixDatatype::LocalMktDate => "LocalMktDate",
-                FixDatatype::MultipleStringValue => "MultipleStringValue",
-                FixDatatype::UtcTimeOnly => "UTCTimeOnly",
-                FixDatatype::UtcTimestamp => "UTCTimestamp",
-                FixDatatype::UtcDateOnly => "UTCDateOnly",
-                FixDatatype::Country => "Country",
-                FixDatatype::MultipleCharValue => "MultipleCharValue",
-                FixDatatype::XmlData => "XMLData",
+    #[test]
+    fn clone_without_layout_keeps_field_metadata() {
+        let dict = Dictionary::fix44();
+        let light = dict.clone_without_layout();
+        assert_eq!(light.version(), dict.version());
+        assert_eq!(light.fields().len(), dict.fields().len());
+        assert_eq!(light.datatypes().len(), dict.datatypes().len());
+        assert_eq!(light.messages().len(), dict.messages().len());
+        let side = light.field_by_name("Side").unwrap();
+        assert_eq!(side.fix_datatype(), FixDatatype::Char);
+        assert!(side.enums().unwrap().any(|e| e.value() == "1"));
+        let stats = light.stats();
+        assert_eq!(stats.groups, 0);
+        assert_eq!(stats.max_nesting_depth, 0);
+        assert!(light.group_tags(TagU32::new(453).unwrap()).is_none());
+        assert!(light
+            .components()
+            .iter()
+            .all(|component| component.items().count() == 0));
+    }
+
+    #[test]
+    fn tag_byte_width_is_the_number_of_digits() {
+        for dict in Dictionary::common_dictionaries().iter() {
+            for field in dict.fields() {
+                assert_eq!(field.tag_byte_width(), field.tag().to_string().len());
             }
         }
+    }
 
-        /// Returns `true` if and only if `self` is a "base type", i.e. a primitive;
-        /// returns `false` otherwise.
-        ///
-        /// # Examples
-        ///
-        /// ```
-        /// use fefix_dictionary::FixDatatype;
-        ///
-        /// assert_eq!(FixDatatype::Float.is_base_type(), true);
-        /// assert_eq!(FixDatatype::Price.is_base_type(), false);
-        /// ```
-        pub fn is_base_type(&self) -> bool {
-            match self {
-                Self::Char | Self::Float | Self::Int | Self::String => true,
-                _ => false,
+    #[test]
+    fn well_known_tags_match_dictionaries() {
+        let tags = Dictionary::well_known_tags();
+        assert!(tags.windows(2).all(|w| w[0].0 < w[1].0));
+        let mut dicts = Dictionary::common_dictionaries();
+        dicts.push(Dictionary::fix44());
+        for (tag, name, _) in tags {
+            assert!(Dictionary::is_well_known_tag(*tag));
+            for dict in dicts.iter() {
+                if let Some(field) = dict.field_by_tag(*tag) {
+                    assert_eq!(field.name(), *name, "{}", dict.version());
+                }
             }
         }
+        // FIX 4.4 is always enabled, and FIX 5.0 session fields are defined
+        // by FIXT.1.1.
+        let fix44 = Dictionary::fix44();
+        assert!(tags
+            .iter()
+            .filter(|(tag, _, _)| *tag < 1128)
+            .all(|(tag, _, _)| fix44.field_by_tag(*tag).is_some()));
+    }
 
-        /// Returns the primitive [`Datatype`](super::Datatype) from which `self` is derived. If
-        /// `self` is primitive already, returns `self` unchanged.
-        ///
-        /// # Examples
-        ///
-        /// ```
-        /// use fefix_dictionary::FixDatatype;
-        ///
-        /// assert_eq!(FixDatatype::Float.base_type(), FixDatatype::Float);
-        /// assert_eq!(FixDatatype::Price.base_type(), FixDatatype::Float);
-        /// ```
-        pub fn base_type(&self) -> Self {
-            let dt = match self {
-                Self::Char | Self::Boolean => Self::Char,
-                Self::Float
-                | Self::Amt
-                | Self::Price
-                | Self::PriceOffset
-                | Self::Qty
-                | Self::Percentage => Self::Float,
-                Self::Int
-                | Self::DayOfMonth
-                | Self::Length
-                | Self::NumInGroup
-                | Self::SeqNum
-                | Self::TagNum => Self::Int,
-                _ => Self::String,
-            };
-            debug_assert!(dt.is_base_type());
-            dt
+    #[test]
+    fn fix_version_ordering() {
+        let application_versions = &FixVersion::ALL[..FixVersion::ALL.len() - 1];
+        for (i, a) in application_versions.iter().enumerate() {
+            for (j, b) in application_versions.iter().enumerate() {
+                assert_eq!(a.partial_cmp(b), Some(i.cmp(&j)), "{:?} vs {:?}", a, b);
+            }
+            assert_eq!(a.partial_cmp(&FixVersion::Fixt11), None);
+            assert_eq!(FixVersion::Fixt11.partial_cmp(a), None);
         }
+        assert!(FixVersion::Fixt11 >= FixVersion::Fixt11);
+        assert!(FixVersion::Fixt11 <= FixVersion::Fixt11);
+    }
 
-        /// Returns an [`Iterator`] over all variants of
-        /// [`Datatype`](super::Datatype).
-        pub fn iter_all() -> impl Iterator<Item = Self> {
-            <Self as IntoEnumIterator>::iter()
+    #[test]
+    fn data_tags_are_length_prefixed_fields() {
+        let mut dicts = Dictionary::common_dictionaries();
+        dicts.push(Dictionary::fix44());
+        for dict in dicts {
+            let data_tags = dict
+                .fields()
+                .into_iter()
+                .filter(|field| dict.field_is_data(field.tag().get()))
+                .map(|field| field.tag())
+                .collect::<FnvHashSet<_>>();
+            assert!(dict.data_tags().is_superset(&data_tags));
+            for tag in dict.data_tags() {
+                let datatype = dict.datatype_of_tag(*tag).unwrap();
+                assert!(datatype.is_length_prefixed());
+            }
         }
+        let mut dict = Dictionary::fix44();
+        let xml_data = TagU32::new(213).unwrap();
+        assert!(dict.data_tags().contains(&xml_data));
+        dict.remove_field(xml_data);
+        assert!(!dict.data_tags().contains(&xml_data));
     }
 
-    #[cfg(test)]
-    mod test {
-        use super::*;
-        use std::collections::HashSet;
+    #[test]
+    fn appl_ver_ids() {
+        for version in FixVersion::ALL {
+            if let Some(appl_ver_id) = version.appl_ver_id() {
+                assert_eq!(FixVersion::from_appl_ver_id(appl_ver_id), Some(*version));
+            }
+        }
+        assert_eq!(FixVersion::from_appl_ver_id("0"), None);
+        assert_eq!(FixVersion::Fixt11.appl_ver_id(), None);
+        #[cfg(feature = "fix50sp2")]
+        {
+            let dict = Dictionary::fix50sp2();
+            assert_eq!(dict.appl_ver_id(), Some("9"));
+            let field = dict.field_by_name("ApplVerID").unwrap();
+            for value in field.enums().unwrap() {
+                let version = FixVersion::from_appl_ver_id(value.value());
+                let name = version.map(|v| v.as_str().replace(['.', '-'], ""));
+                if let Some(name) = name {
+                    assert_eq!(name, value.description());
+                }
+            }
+        }
+    }
 
-        #[test]
-        fn iter_all_unique() {
-            let as_vec = FixDatatype::iter_all().collect::<Vec<FixDatatype>>();
-            let as_set = FixDatatype::iter_all().collect::<HashSet<FixDatatype>>();
-            assert_eq!(as_vec.len(), as_set.len());
+    #[test]
+    fn session_layer_fields_match_header_and_trailer() {
+        let mut dicts = Dictionary::common_dictionaries();
+        dicts.push(Dictionary::fix44());
+        #[cfg(feature = "fixt11")]
+        dicts.push(Dictionary::fixt11());
+        for dict in dicts
+            .iter()
+            .filter(|dict| !dict.version().starts_with("FIX.5"))
+        {
+            let mut tags = Vec::new();
+            for name in ["StandardHeader", "StandardTrailer"] {
+                let component = dict.component_by_name(name).unwrap();
+                expand_layout_tags(component.items(), &mut tags);
+            }
+            let tags = tags.into_iter().collect::<FnvHashSet<_>>();
+            assert_eq!(dict.session_layer_fields(), tags, "{}", dict.version());
         }
+        #[cfg(feature = "fix50")]
+        {
+            let tags = Dictionary::fix50().session_layer_fields();
+            assert!(tags.contains(&TagU32::new(1128).unwrap()));
+            assert!(!tags.contains(&TagU32::new(370).unwrap()));
+            assert_eq!(tags.len(), 35);
+        }
+    }
 
-        #[test]
-        fn more_than_20_datatypes() {
-            // According to the official documentation, FIX has "about 20 data
-            // types". Including recent revisions, we should well exceed that
-            // number.
-            assert!(FixDatatype::iter_all().count() > 20);
+    #[test]
+    #[cfg(feature = "fixt11")]
+    fn fixt11_is_session_only() {
+        let dict = Dictionary::fixt11();
+        assert_eq!(dict.version(), "FIXT.1.1");
+        assert_eq!(dict.fix_version(), Some(FixVersion::Fixt11));
+        assert!(dict.is_session_only());
+        let session_msg_types = dict.known_session_message_types();
+        for msg in dict.messages() {
+            assert!(
+                session_msg_types.contains(&msg.msg_type()),
+                "{}",
+                msg.name()
+            );
         }
+        let xml = dict.to_quickfix_string();
+        assert!(xml.contains("<fix type='FIXT' major='1' minor='1' servicepack='0'>"));
+        assert!(Dictionary::from_quickfix_spec(&xml)
+            .unwrap()
+            .is_session_only());
+        for dict in Dictionary::common_dictionaries().iter() {
+            assert_eq!(dict.is_session_only(), dict.version() == "FIXT.1.1");
+        }
+    }
 
-        #[test]
-        fn names_are_unique() {
-            let as_vec = FixDatatype::iter_all()
-                .map(|dt| dt.name())
-                .collect::<Vec<&str>>();
-            let as_set = FixDatatype::iter_all()
-                .map(|dt| dt.name())
-                .collect::<HashSet<&str>>();
-            assert_eq!(as_vec.len(), as_set.len());
+    #[test]
+    fn fix_version_strings_roundtrip() {
+        for version in FixVersion::ALL {
+            assert_eq!(
+                FixVersion::from_version_str(version.as_str()),
+                Some(*version)
+            );
         }
+        assert_eq!(FixVersion::from_version_str("FIX.4.4-VENUE"), None);
+    }
 
-        #[test]
-        fn base_type_is_itself() {
-            for dt in FixDatatype::iter_all() {
-                if dt.is_base_type() {
-                    assert_eq!(dt.base_type(), dt);
-                } else {
-                    assert_ne!(dt.base_type(), dt);
-                }
+    #[test]
+    fn dictionary_version_comparisons() {
+        for dict in Dictionary::common_dictionaries()
+            .iter()
+            .chain([Dictionary::fix44()].iter())
+        {
+            let version = dict.fix_version().unwrap();
+            for other in FixVersion::ALL {
+                let ordering = version.partial_cmp(other);
+                assert_eq!(
+                    dict.version_is_at_least(*other),
+                    matches!(ordering, Some(Ordering::Greater | Ordering::Equal))
+                );
+                assert_eq!(
+                    dict.version_is_at_most(*other),
+                    matches!(ordering, Some(Ordering::Less | Ordering::Equal))
+                );
             }
         }
+        let venue = Dictionary::new("FIX.4.4-VENUE");
+        assert!(!venue.version_is_at_least(FixVersion::Fix40));
+        assert!(!venue.version_is_at_most(FixVersion::Fix50SP2));
+    }
 
-        #[test]
-        fn base_type_is_actually_base_type() {
-            for dt in FixDatatype::iter_all() {
-                assert!(dt.base_type().is_base_type());
-            }
+    #[test]
+    fn std_header_and_trailer_always_present() {
+        for dict in Dictionary::common_dictionaries().iter() {
+            let std_header = dict.component_by_name("StandardHeader");
+            let std_trailer = dict.component_by_name("StandardTrailer");
+            assert!(std_header.is_some() && std_trailer.is_some());
         }
     }
-}
 
-/// A field is identified by a unique tag number and a name. Each field in a
-/// message is associated with a value.
-#[derive(Clone, Debug)]
-struct FieldData {
-    /// A human readable string representing the name of the field.
-    name: SmartString,
-    /// **Primary key.** A positive integer representing the unique
-    /// identifier for this field type.
-    tag: u32,
-    /// The datatype of the field.
-    data_type_name: SmartString,
-    /// The associated data field. If given, this field represents the length of
-    /// the referenced data field
-    associated_data_tag: Option<usize>,
-    value_restrictions: Option<Vec<FieldEnumData>>,
-    /// Abbreviated form of the Name, typically to specify the element name when
-    /// the field is used in an XML message. Can be overridden by BaseCategory /
-    /// BaseCategoryAbbrName.
-    abbr_name: Option<String>,
-    /// Specifies the base message category when field is used in an XML message.
-    base_category_id: Option<usize>,
-    /// If BaseCategory is specified, this is the XML element identifier to use
-    /// for this field, overriding AbbrName.
-    base_category_abbr_name: Option<String>,
-    /// Indicates whether the field is required in an XML message.
-    required: bool,
-    description: Option<String>,
-}
+    #[test]
+    fn fix44_field_28_has_three_variants() {
+        let dict = Dictionary::fix44();
+        let field_28 = dict.field_by_tag(28).unwrap();
+        assert_eq!(field_28.name(), "IOITransType");
+        assert_eq!(field_28.enums().unwrap().count(), 3);
+    }
+
+    #[test]
+    fn fix44_field_36_has_no_variants() {
+        let dict = Dictionary::fix44();
+        let field_36 = dict.field_by_tag(36).unwrap();
+        assert_eq!(field_36.name(), "NewSeqNo");
+        assert!(field_36.enums().is_none());
+    }
+
+    #[test]
+    fn fix44_effective_type_of_code_set_and_plain_fields() {
+        let dict = Dictionary::fix44();
+        let field_28 = dict.field_by_tag(28).unwrap();
+        let field_36 = dict.field_by_tag(36).unwrap();
+        assert!(matches!(
+            field_28.effective_type(),
+            EffectiveType::CodeSet(_)
+        ));
+        assert!(matches!(field_36.effective_type(), EffectiveType::Plain(_)));
+        assert_eq!(
+            field_36.effective_type().datatype().basetype(),
+            FixDatatype::SeqNum
+        );
+    }
+
+    #[test]
+    fn fix44_field_167_has_eucorp_variant() {
+        let dict = Dictionary::fix44();
+        let field_167 = dict.field_by_tag(167).unwrap();
+        assert_eq!(field_167.name(), "SecurityType");
+        assert!(field_167.enums().unwrap().any(|e| e.value() == "EUCORP"));
+    }
+
+    #[test]
+    fn msg_type_for_admin_agrees_with_known_session_message_types() {
+        const KINDS: &[AdminMessageKind] = &[
+            AdminMessageKind::Heartbeat,
+            AdminMessageKind::Logon,
+            AdminMessageKind::Logout,
+            AdminMessageKind::TestRequest,
+            AdminMessageKind::ResendRequest,
+            AdminMessageKind::SequenceReset,
+            AdminMessageKind::Reject,
+        ];
+        for dict in Dictionary::common_dictionaries().iter() {
+            for kind in KINDS {
+                match dict.msg_type_for_admin(*kind) {
+                    Some(msg_type) => {
+                        assert!(dict.known_session_message_types().contains(&msg_type))
+                    }
+                    None => assert!(dict.version().starts_with("FIX.5.0")),
+                }
+            }
+        }
+        let dict = Dictionary::fix44();
+        assert_eq!(dict.msg_type_for_admin(AdminMessageKind::Logout), Some("5"));
+        assert_eq!(dict.msg_type_for_admin(AdminMessageKind::Reject), Some("3"));
+    }
 
-#[derive(Clone, Debug)]
-struct FieldEnumData {
-    value: String,
-    description: String,
-}
+    #[test]
+    fn known_session_message_types_are_defined() {
+        for dict in Dictionary::common_dictionaries().iter() {
+            let msg_types = dict.known_session_message_types();
+            assert!(!msg_types.is_empty(), "{}", dict.version());
+            if dict.version().starts_with("FIX.5.0") {
+                continue;
+            }
+            for msg_type in msg_types {
+                let msg = dict.message_by_msgtype(msg_type).unwrap();
+                assert_eq!(msg.section(), Some("Session"));
+            }
+        }
+    }
 
-/// A limitation imposed on the value of a specific FIX [`Field`].  Also known as
-/// "code set".
-#[derive(Debug)]
-pub struct FieldEnum<'a>(&'a Dictionary, &'a FieldEnumData);
+    #[test]
+    fn fix44_set_field_required_in_message() {
+        let mut dict = Dictionary::fix44();
+        let component_count = dict.components().len();
+        let tag = |t: u32| TagU32::new(t).unwrap();
+        let is_required = |items: &[LayoutItemData], t: u32| {
+            items
+                .iter()
+                .find(|item| matches!(item.kind, LayoutItemKindData::Field { tag } if tag == t))
+                .unwrap()
+                .required
+        };
+        let layout = |dict: &Dictionary| dict.messages_by_msgtype["D"].layout_items.clone();
+        // `ClOrdLinkID` is part of the message itself.
+        dict.set_field_required_in_message("D", tag(583), true)
+            .unwrap();
+        assert!(is_required(&layout(&dict), 583));
+        // `Symbol` is part of the shared `Instrument` component, which is
+        // inlined.
+        dict.set_field_required_in_message("D", tag(55), true)
+            .unwrap();
+        dict.set_field_required_in_message("D", tag(65), true)
+            .unwrap();
+        assert!(is_required(&layout(&dict), 55));
+        assert!(is_required(&layout(&dict), 65));
+        let instrument = &dict.components_by_name["Instrument"];
+        assert!(!is_required(&instrument.layout_items, 55));
+        assert!(!dict
+            .component_dependents("Instrument")
+            .contains(&"NewOrderSingle".to_string()));
+        assert!(dict.component_dependents("Instrument").len() > 1);
+        // `NoSecurityAltID` members are nested one component deeper.
+        dict.set_field_required_in_message("D", tag(455), true)
+            .unwrap();
+        assert_eq!(
+            dict.layout_item_path_to_field("D", tag(455)).unwrap(),
+            &["NoSecurityAltID", "SecurityAltID"]
+        );
+        assert_eq!(dict.components().len(), component_count);
+        assert_eq!(
+            dict.components_containing_field(tag(55))
+                .iter()
+                .map(|c| c.name())
+                .collect::<Vec<_>>(),
+            vec!["Instrument"]
+        );
+        assert_eq!(
+            dict.set_field_required_in_message("D", tag(9999), true),
+            Err(DictError::FieldNotFound)
+        );
+        assert_eq!(
+            dict.set_field_required_in_message("FOOBAR", tag(55), true),
+            Err(DictError::MessageNotFound)
+        );
+        assert_eq!(dict.verify_no_duplicate_fields(), vec![]);
+        let xml = String::from_utf8(dict.to_quickfix_xml()).unwrap();
+        let reparsed = Dictionary::from_quickfix_spec(&xml).unwrap();
+        assert!(is_required(
+            &reparsed.messages_by_msgtype["D"].layout_items,
+            55
+        ));
+    }
 
-impl<'a> FieldEnum<'a> {
-    /// Returns the string representation of this field variant.
-    pub fn value(&self) -> &str {
-        &self.1.value[..]
+    #[test]
+    fn fix44_component_required_items() {
+        let dict = Dictionary::fix44();
+        let header = dict.component_by_name("StandardHeader").unwrap();
+        let required = header
+            .required_items()
+            .map(|item| item.tag_text())
+            .collect::<Vec<String>>();
+        assert_eq!(
+            &required[..4],
+            &["BeginString", "BodyLength", "MsgType", "SenderCompID"]
+        );
+        assert!(!required.contains(&"PossDupFlag".to_string()));
+        let instrument = dict.component_by_name("Instrument").unwrap();
+        assert_eq!(instrument.required_items().count(), 0);
     }
 
-    /// Returns the documentation description for `self`.
-    pub fn description(&self) -> &str {
-        &self.1.description[..]
+    #[test]
+    fn fix44_field_names_in_snake_case() {
+        let dict = Dictionary::fix44();
+        let cases = [
+            ("Account", "account"),
+            ("BeginString", "begin_string"),
+            ("BodyLength", "body_length"),
+            ("CheckSum", "check_sum"),
+            ("ClOrdID", "cl_ord_id"),
+            ("MsgSeqNum", "msg_seq_num"),
+            ("MDReqID", "md_req_id"),
+            ("MDEntryPx", "md_entry_px"),
+            ("NoMDEntries", "no_md_entries"),
+            ("NoPartyIDs", "no_party_ids"),
+            ("SecurityIDSource", "security_id_source"),
+            ("CFICode", "cfi_code"),
+            ("IOIQltyInd", "ioi_qlty_ind"),
+            ("XmlDataLen", "xml_data_len"),
+            ("SettlDate2", "settl_date2"),
+            ("OrderQty2", "order_qty2"),
+            ("EncodedLegSecurityDescLen", "encoded_leg_security_desc_len"),
+            ("TotNoQuoteEntries", "tot_no_quote_entries"),
+            ("NoSecurityAltID", "no_security_alt_id"),
+            ("PossDupFlag", "poss_dup_flag"),
+            ("SenderCompID", "sender_comp_id"),
+            ("OrigClOrdID", "orig_cl_ord_id"),
+            ("ExecID", "exec_id"),
+            ("CxlRejResponseTo", "cxl_rej_response_to"),
+            ("TestReqID", "test_req_id"),
+        ];
+        for (name, snake) in cases.iter() {
+            let field = dict.field_by_name(name).unwrap();
+            assert_eq!(field.name_snake_case(), *snake);
+        }
+        for field in dict.fields() {
+            let snake = field.name_snake_case();
+            assert!(
+                !snake.contains("__") && !snake.starts_with('_'),
+                "{}",
+                snake
+            );
+        }
     }
-}
 
-/// A field is the most granular message structure abstraction. It carries a
-/// specific business meaning as described by the FIX specifications. The data
-/// domain of a [`Field`] is either a [`Datatype`] or a "code set", i.e.
-/// enumeration.
-#[derive(Debug, Copy, Clone)]
-pub struct Field<'a>(&'a Dictionary, &'a FieldData);
+    #[test]
+    fn fix44_full_message_layout() {
+        let dict = Dictionary::fix44();
+        assert!(dict.full_message_layout("FOOBAR").is_none());
+        for msg in dict.messages() {
+            assert!(!msg.includes_standard_header());
+            let layout = dict.full_message_layout(msg.msg_type()).unwrap();
+            let names = layout
+                .iter()
+                .map(|item| item.tag_text())
+                .collect::<Vec<String>>();
+            assert_eq!(&names[..3], &["BeginString", "BodyLength", "MsgType"]);
+            assert_eq!(names.last().unwrap(), "CheckSum");
+            assert_eq!(
+                names.len(),
+                msg.layout().count()
+                    + dict
+                        .component_by_name("StandardHeader")
+                        .unwrap()
+                        .items()
+                        .count()
+                    + dict
+                        .component_by_name("StandardTrailer")
+                        .unwrap()
+                        .items()
+                        .count()
+            );
+        }
+    }
 
-impl<'a> Field<'a> {
-    pub fn doc_url_onixs(&self, version: &str) -> String {
-        let v = match version {
-            "FIX.4.0" => "4.0",
-            "FIX.4.1" => "4.1",
-            "FIX.4.2" => "4.2",
-            "FIX.4.3" => "4.3",
-            "FIX.4.4" => "4.4",
-            "FIX.5.0" => "5.0",
-            "FIX.5.0SP1" => "5.0.SP1",
-            "FIX.5.0SP2" => "5.0.SP2",
-            "FIXT.1.1" => "FIXT.1.1",
-            s => s,
+    #[test]
+    fn fix44_group_for_count_tag() {
+        let dict = Dictionary::fix44();
+        let tag = |t: u32| TagU32::new(t).unwrap();
+        // `NoLegs` inside `InstrmtLegExecGrp`.
+        let group = dict.group_for_count_tag("8", tag(555)).unwrap();
+        assert_eq!(group.count_tag, tag(555));
+        assert_eq!(group.member_tags[0], group.delimiter_tag);
+        assert!(group.member_tags.contains(&tag(600)));
+        // `NoLegSecurityAltID` is nested inside `InstrumentLeg`.
+        assert!(group.member_tags.contains(&tag(604)));
+        assert!(!group.member_tags.contains(&tag(605)));
+        let nested = dict.group_for_count_tag("8", tag(604)).unwrap();
+        assert_eq!(nested.member_tags, vec![tag(605), tag(606)]);
+        assert!(dict.group_for_count_tag("8", tag(11)).is_none());
+        assert!(dict.group_for_count_tag("FOOBAR", tag(555)).is_none());
+
+        let mut dict = Dictionary::fix44();
+        let message = dict.messages_by_msgtype.get_mut("8").unwrap();
+        message.layout_items.insert(
+            0,
+            LayoutItemData {
+                required: false,
+                kind: LayoutItemKindData::Group {
+                    len_field_tag: 555,
+                    items: vec![],
+                },
+            },
+        );
+        assert_eq!(dict.group_for_count_tag("8", tag(555)), Some(group));
+    }
+
+    #[test]
+    fn fix44_wire_tag_order() {
+        let dict = Dictionary::fix44();
+        assert!(dict.wire_tag_order("FOOBAR").is_none());
+        let tags: Vec<u32> = dict
+            .wire_tag_order("1")
+            .unwrap()
+            .iter()
+            .map(|tag| tag.get())
+            .collect();
+        assert_eq!(&tags[..3], &[8, 9, 35]);
+        assert_eq!(&tags[tags.len() - 3..], &[93, 89, 10]);
+        let msg_type = tags.iter().position(|tag| *tag == 35).unwrap();
+        let test_req_id = tags.iter().position(|tag| *tag == 112).unwrap();
+        assert!(msg_type < test_req_id && test_req_id < tags.len() - 3);
+        let tags = dict.wire_tag_order("8").unwrap();
+        let no_legs = tags.iter().position(|tag| tag.get() == 555).unwrap();
+        let leg_symbol = tags.iter().position(|tag| tag.get() == 600).unwrap();
+        assert!(no_legs < leg_symbol);
+    }
+
+    #[test]
+    fn wire_tag_order_with_inlined_header() {
+        let field = |tag: u32, name: &str| FieldDef {
+            tag,
+            name: name.to_string(),
+            datatype: FixDatatype::String,
+            enums: vec![],
         };
-        format!(
-            "https://www.onixs.biz/fix-dictionary/{}/tagNum_{}.html",
-            v,
-            self.1.tag.to_string().as_str()
+        let fields = [
+            field(8, "BeginString"),
+            field(10, "CheckSum"),
+            field(112, "TestReqID"),
+        ];
+        let item = |name: &str| LayoutItemDef::Field {
+            name: name.to_string(),
+            required: true,
+        };
+        let component = |name: &str, items| ComponentDef {
+            name: name.to_string(),
+            category: String::new(),
+            items,
+        };
+        let components = [
+            component("StandardHeader", vec![item("BeginString")]),
+            component("StandardTrailer", vec![item("CheckSum")]),
+        ];
+        let messages = [MessageDef {
+            name: "Heartbeat".to_string(),
+            msg_type: "0".to_string(),
+            category: "admin".to_string(),
+            items: vec![
+                LayoutItemDef::Component {
+                    name: "StandardHeader".to_string(),
+                    required: true,
+                },
+                item("TestReqID"),
+                LayoutItemDef::Component {
+                    name: "StandardTrailer".to_string(),
+                    required: true,
+                },
+            ],
+            responses: vec![],
+        }];
+        let dict = Dictionary::from_parts(
+            "FIX.4.4",
+            &fields,
+            &[FixDatatype::String],
+            &components,
+            &messages,
         )
+        .unwrap();
+        assert!(dict
+            .message_by_msgtype("0")
+            .unwrap()
+            .includes_standard_header());
+        let tags: Vec<u32> = dict
+            .wire_tag_order("0")
+            .unwrap()
+            .iter()
+            .map(|tag| tag.get())
+            .collect();
+        assert_eq!(tags, &[8, 112, 10]);
     }
 
-    pub fn is_num_in_group(&self) -> bool {
-        fn nth_char_is_uppercase(s: &str, i: usize) -> bool {
-            s.chars().nth(i).map(|c| c.is_ascii_uppercase()) == Some(true)
-        }
+    #[test]
+    fn fix44_layout_item_path_to_unknown_field_is_none() {
+        let dict = Dictionary::fix44();
+        let tag = TagU32::new(600).unwrap();
+        assert!(dict.layout_item_path_to_field("0", tag).is_none());
+        assert!(dict.layout_item_path_to_field("FOOBAR", tag).is_none());
+        assert_eq!(
+            dict.layout_item_path_to_field("0", TagU32::new(112).unwrap()),
+            Some(vec!["TestReqID".to_string()])
+        );
+    }
 
-        self.fix_datatype().base_type() == FixDatatype::NumInGroup
-            || self.name().ends_with("Len")
-            || (self.name().starts_with("No") && nth_char_is_uppercase(self.name(), 2))
+    #[test]
+    fn quickfix_xml_round_trip() {
+        for dict in Dictionary::common_dictionaries().iter() {
+            let xml = String::from_utf8(dict.to_quickfix_xml()).unwrap();
+            let dict2 = Dictionary::from_quickfix_spec(&xml).unwrap();
+            assert_eq!(dict.version(), dict2.version());
+            assert_eq!(dict.fields().len(), dict2.fields().len());
+            assert_eq!(dict.messages().len(), dict2.messages().len());
+            assert_eq!(dict.components().len(), dict2.components().len());
+            for msg in dict.messages() {
+                let msg2 = dict2.message_by_msgtype(msg.msg_type()).unwrap();
+                assert_eq!(msg.layout().count(), msg2.layout().count());
+            }
+        }
     }
 
-    /// Returns the [`FixDatatype`] of `self`.
-    pub fn fix_datatype(&self) -> FixDatatype {
-        self.data_type().basetype()
+    #[test]
+    fn fix44_quickfix_xml_round_trip_keeps_group_members() {
+        let dict = Dictionary::fix44();
+        let xml = String::from_utf8(dict.to_quickfix_xml()).unwrap();
+        let dict2 = Dictionary::from_quickfix_spec(&xml).unwrap();
+        let tag = TagU32::new(600).unwrap();
+        assert_eq!(
+            dict.layout_item_path_to_field("8", tag),
+            dict2.layout_item_path_to_field("8", tag)
+        );
+        let group_len = |dict: &Dictionary| {
+            let component = dict.component_by_name("InstrmtLegExecGrp").unwrap();
+            let item = component.items().next().unwrap();
+            match item.kind() {
+                LayoutItemKind::Group(_, items) => (items.len(), item.required()),
+                _ => panic!("Not a group."),
+            }
+        };
+        assert_eq!(group_len(&dict), group_len(&dict2));
     }
 
-    /// Returns the name of `self`. Field names are unique across each FIX
-    /// [`Dictionary`].
-    pub fn name(&self) -> &str {
-        self.1.name.as_str()
+    #[test]
+    fn fix44_quickfix_xml_round_trip_keeps_msgcat() {
+        let dict = Dictionary::fix44();
+        let xml = String::from_utf8(dict.to_quickfix_xml()).unwrap();
+        assert!(xml.contains("<message name='Heartbeat' msgtype='0' msgcat='admin'>"));
+        assert!(xml.contains("<message name='NewOrderSingle' msgtype='D' msgcat='app'>"));
+        let dict2 = Dictionary::from_quickfix_spec(&xml).unwrap();
+        for msg in dict.messages() {
+            let msg2 = dict2.message_by_msgtype(msg.msg_type()).unwrap();
+            assert_eq!(
+                msg.category().map(|c| c.name().to_string()),
+                msg2.category().map(|c| c.name().to_string())
+            );
+        }
+        let category = |msg_type: &str| {
+            let msg = dict2.message_by_msgtype(msg_type).unwrap();
+            msg.category().unwrap().name().to_string()
+        };
+        assert_eq!(category("0"), "admin");
+        assert_eq!(category("D"), "app");
     }
 
-    /// Returns the numeric tag of `self`. Field tags are unique across each FIX
-    /// [`Dictionary`].
-    pub fn tag(&self) -> TagU32 {
-        TagU32::new(self.1.tag).unwrap()
+    #[test]
+    fn quickfix_xml_escapes_names_and_skips_dangling_references() {
+        let fields = [FieldDef {
+            tag: 5001,
+            name: "O'Brien".to_string(),
+            datatype: FixDatatype::String,
+            enums: vec![],
+        }];
+        let components = [ComponentDef {
+            name: "Q&A".to_string(),
+            category: String::new(),
+            items: vec![LayoutItemDef::Field {
+                name: "O'Brien".to_string(),
+                required: true,
+            }],
+        }];
+        let messages = [MessageDef {
+            name: "<Custom>".to_string(),
+            msg_type: "U1".to_string(),
+            category: "app".to_string(),
+            items: vec![LayoutItemDef::Component {
+                name: "Q&A".to_string(),
+                required: false,
+            }],
+            responses: vec![],
+        }];
+        let mut dict = Dictionary::from_parts(
+            "FIX.4.4",
+            &fields,
+            &[FixDatatype::String],
+            &components,
+            &messages,
+        )
+        .unwrap();
+        let dict2 = Dictionary::from_quickfix_spec(&dict.to_quickfix_string()).unwrap();
+        assert_eq!(dict2.field_by_tag(5001).unwrap().name(), "O'Brien");
+        assert_eq!(dict2.message_by_msgtype("U1").unwrap().name(), "<Custom>");
+        assert!(dict2.component_by_name("Q&A").is_some());
+
+        let message = dict.messages_by_msgtype.get_mut("U1").unwrap();
+        message.layout_items.push(LayoutItemData {
+            required: true,
+            kind: LayoutItemKindData::Field { tag: 9999 },
+        });
+        dict.data_types_by_name.clear();
+        let dict2 = Dictionary::from_quickfix_spec(&dict.to_quickfix_string()).unwrap();
+        let message = dict2.message_by_msgtype("U1").unwrap();
+        assert_eq!(message.layout().count(), 1);
+        assert_eq!(
+            dict2.field_by_tag(5001).unwrap().fix_datatype(),
+            FixDatatype::String
+        );
     }
 
-    /// In case this field allows any value, it returns `None`; otherwise; it
-    /// returns an [`Iterator`] of all allowed values.
-    pub fn enums(&self) -> Option<impl Iterator<Item = FieldEnum>> {
-        self.1
-            .value_restrictions
-            .as_ref()
-            .map(move |v| v.iter().map(move |f| FieldEnum(self.0, f)))
+    #[test]
+    fn fix44_case_insensitive_lookups() {
+        let dict = Dictionary::fix44();
+        for name in ["NewOrderSingle", "newordersingle", "NEWORDERSINGLE"] {
+            let msg = dict.message_by_name_case_insensitive(name).unwrap();
+            assert_eq!(msg.msg_type(), "D");
+        }
+        for name in ["ClOrdID", "clordid", "CLORDID"] {
+            let field = dict.field_by_name_case_insensitive(name).unwrap();
+            assert_eq!(field.tag().get(), 11);
+        }
+        assert!(dict.message_by_name("newordersingle").is_none());
+        assert!(dict.field_by_name_case_insensitive("ClOrdIDs").is_none());
     }
 
-    /// Returns the [`Datatype`] of `self`.
-    pub fn data_type(&self) -> Datatype {
-        self.0
-            .datatype_by_name(self.1.data_type_name.as_str())
-            .unwrap()
+    #[test]
+    fn common_dictionaries_have_message_sections() {
+        for dict in Dictionary::common_dictionaries().iter() {
+            for msg in dict.messages() {
+                assert!(msg.section().is_some(), "{}", msg.name());
+            }
+        }
+        let dict = Dictionary::fix44();
+        assert_eq!(
+            dict.message_by_msgtype("0").unwrap().section(),
+            Some("Session")
+        );
+        assert_eq!(
+            dict.message_by_msgtype("J").unwrap().section(),
+            Some("PostTrade")
+        );
     }
 
-    pub fn data_tag(&self) -> Option<TagU32> {
-        self.1
-            .associated_data_tag
-            .map(|tag| TagU32::new(tag as u32).unwrap())
+    #[test]
+    fn quickfix_spec_progress() {
+        let spec = Dictionary::fix44().to_quickfix_string();
+        let mut events = Vec::new();
+        let dict = Dictionary::from_quickfix_spec_with_progress(&spec, |phase, done, total| {
+            events.push((phase, done, total))
+        })
+        .unwrap();
+        let phases = [
+            (ParsePhase::CheckingComponents, 1),
+            (ParsePhase::Fields, dict.fields().len()),
+            // `StandardHeader` and `StandardTrailer` are not part of the
+            // `<components>` section.
+            (ParsePhase::Components, dict.components().len() - 2),
+            (ParsePhase::Messages, dict.messages().len()),
+            (ParsePhase::Indexing, 1),
+        ];
+        let mut events = events.into_iter();
+        for (phase, total) in phases {
+            for done in 0..=total {
+                assert_eq!(events.next(), Some((phase, done, total)));
+            }
+        }
+        assert_eq!(events.next(), None);
     }
 
-    pub fn required_in_xml_messages(&self) -> bool {
-        self.1.required
+    #[test]
+    fn field_descriptions() {
+        let spec = include_str!("test_data/quickfix_specs/field_descriptions.xml");
+        let dict = Dictionary::from_quickfix_spec(spec).unwrap();
+        let names = |dict: &Dictionary| {
+            dict.fields_with_description()
+                .iter()
+                .map(|f| f.name().to_string())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(names(&dict), &["ClOrdID", "Symbol"]);
+        assert_eq!(dict.description_coverage(), 0.5);
+        let field = dict.field_by_name("Symbol").unwrap();
+        assert_eq!(field.description(), Some("Ticker symbol."));
+        assert_eq!(field.extra_attribute("description"), None);
+        let xml = String::from_utf8(dict.to_quickfix_xml()).unwrap();
+        let dict2 = Dictionary::from_quickfix_spec(&xml).unwrap();
+        assert_eq!(names(&dict2), names(&dict));
+        assert_eq!(dict2.field_by_name("Text").unwrap().description(), Some(""));
+        assert_eq!(
+            Dictionary::from_parts("FIX.4.4", &[], &[], &[], &[])
+                .unwrap()
+                .description_coverage(),
+            0.0
+        );
     }
 
-    pub fn description(&self) -> Option<&str> {
-        self.1.description.as_ref().map(|s| s.as_str())
+    #[test]
+    fn component_categories() {
+        let spec = include_str!("test_data/quickfix_specs/component_categories.xml");
+        let dict = Dictionary::from_quickfix_spec(spec).unwrap();
+        let category = |dict: &Dictionary, name: &str| {
+            let component = dict.component_by_name(name).unwrap();
+            component.category().map(|c| c.name().to_string())
+        };
+        let names = |components: Vec<Component>| {
+            components
+                .iter()
+                .map(|c| c.name().to_string())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(category(&dict, "Instrument"), Some("Common".to_string()));
+        assert_eq!(category(&dict, "Parties"), None);
+        assert_eq!(
+            names(dict.components_in_category("Common")),
+            &["Instrument", "OrderQtyData"]
+        );
+        assert!(dict.components_in_category("").is_empty());
+        assert!(dict.components_in_category("app").is_empty());
+        let xml = String::from_utf8(dict.to_quickfix_xml()).unwrap();
+        let dict2 = Dictionary::from_quickfix_spec(&xml).unwrap();
+        assert_eq!(category(&dict2, "Instrument"), Some("Common".to_string()));
+        assert_eq!(category(&dict2, "Parties"), None);
+        let (fields, datatypes, components, messages) = dict.to_parts();
+        let dict2 =
+            Dictionary::from_parts(dict.version(), &fields, &datatypes, &components, &messages)
+                .unwrap();
+        assert_eq!(category(&dict2, "Instrument"), Some("Common".to_string()));
+        assert_eq!(category(&dict2, "Parties"), None);
+        assert_eq!(dict2.to_quickfix_xml(), dict.to_quickfix_xml());
+        for dict in Dictionary::common_dictionaries().iter() {
+            assert!(dict.components().iter().all(|c| c.category().is_none()));
+        }
     }
-}
 
-impl<'a> IsFieldDefinition for Field<'a> {
-    fn name(&self) -> &str {
-        self.1.name.as_str()
+    #[test]
+    fn components_referenced_before_their_definition_keep_their_layout() {
+        // `NewOrderSingle` refers to all components before the
+        // `<components>` section defines them.
+        let spec = include_str!("test_data/quickfix_specs/component_categories.xml");
+        let dict = Dictionary::from_quickfix_spec(spec).unwrap();
+        let item_names = |name: &str| {
+            dict.component_by_name(name)
+                .unwrap()
+                .items()
+                .map(|item| item.tag_text())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(item_names("Instrument"), &["Symbol"]);
+        assert_eq!(item_names("OrderQtyData"), &["OrderQty"]);
+        assert_eq!(item_names("Parties"), &["NoPartyIDs"]);
     }
 
-    fn tag(&self) -> TagU32 {
-        TagU32::new(self.1.tag).expect("Invalid FIX tag (0)")
+    #[test]
+    fn field_by_abbr_name() {
+        let spec = include_str!("test_data/quickfix_specs/fixml_abbr_names.xml");
+        let dict = Dictionary::from_quickfix_spec(spec).unwrap();
+        let field = dict.field_by_abbr_name("TstReqID").unwrap();
+        assert_eq!(field.tag().get(), 112);
+        assert_eq!(field.abbr_name(), Some("TstReqID"));
+        assert!(dict.field_by_abbr_name("TestReqID").is_none());
+        assert!(dict.field_by_tag(8).unwrap().abbr_name().is_none());
+        let xml = String::from_utf8(dict.to_quickfix_xml()).unwrap();
+        let dict2 = Dictionary::from_quickfix_spec(&xml).unwrap();
+        assert_eq!(
+            dict2.field_by_abbr_name("TstReqID").unwrap().tag().get(),
+            112
+        );
     }
 
-    fn location(&self) -> FieldLocation {
-        FieldLocation::Body // FIXME
+    #[test]
+    fn field_tags_for_enum_value() {
+        for dict in Dictionary::common_dictionaries()
+            .iter()
+            .chain([Dictionary::fix44()].iter())
+        {
+            for field in dict.fields() {
+                for value in field.enums().into_iter().flatten() {
+                    let tags = dict.field_tags_for_enum_value(value.value());
+                    assert!(tags.contains(&field.tag()));
+                    assert!(tags.windows(2).all(|w| w[0] < w[1]));
+                }
+            }
+        }
     }
-}
 
-#[derive(Clone, Debug)]
-#[allow(dead_code)]
-enum LayoutItemKindData {
-    Component {
-        name: SmartString,
-    },
-    Group {
-        len_field_tag: u32,
-        items: Vec<LayoutItemData>,
-    },
-    Field {
-        tag: u32,
-    },
-}
+    #[test]
+    fn component_ids_are_unique() {
+        for dict in Dictionary::common_dictionaries()
+            .iter()
+            .chain([Dictionary::fix44()].iter())
+        {
+            let components = dict.components();
+            let ids = components
+                .iter()
+                .map(|component| component.id())
+                .collect::<HashSet<_>>();
+            assert_eq!(ids.len(), components.len());
+            assert!(!ids.contains(&0));
+            for component in components.iter() {
+                assert_eq!(
+                    dict.component_name_for_id(component.id() as usize),
+                    Some(component.name())
+                );
+            }
+        }
+    }
 
-#[derive(Clone, Debug)]
-struct LayoutItemData {
-    required: bool,
-    kind: LayoutItemKindData,
-}
+    #[test]
+    fn field_positions_follow_layout_order() {
+        let dict = Dictionary::fix44();
+        let tag = |t: u32| TagU32::new(t).unwrap();
+        // `NoPartyIDs <453>` is top-level, `PartyID <448>` isn't.
+        assert!(dict.field_position_in("D", tag(453)).is_some());
+        assert_eq!(dict.field_position_in("D", tag(448)), None);
+        // Header fields aren't part of the body.
+        assert_eq!(dict.field_position_in("D", tag(8)), None);
+        assert_eq!(dict.field_position_in("NOT_A_MSG_TYPE", tag(11)), None);
+        for message in dict.messages() {
+            let mut tags = Vec::new();
+            top_level_tags(&dict, &message.1.layout_items, &mut tags);
+            for (i, t) in tags.iter().enumerate() {
+                let position = dict.field_position_in(message.msg_type(), tag(*t));
+                // Some tags appear more than once, in which case the first
+                // position is returned.
+                assert!(position.unwrap() <= i);
+            }
+        }
+    }
 
-pub trait IsFieldDefinition {
-    /// Returns the FIX tag associated with `self`.
-    fn tag(&self) -> TagU32;
+    #[test]
+    fn field_accessors_by_tag() {
+        let dict = Dictionary::fix44();
+        for field in dict.fields() {
+            let tag = field.tag();
+            assert_eq!(dict.field_name(tag), Some(field.name()));
+            assert_eq!(dict.field_description(tag), field.description());
+            assert_eq!(
+                dict.field_datatype_name(tag),
+                Some(field.data_type().name())
+            );
+        }
+        let tag = TagU32::new(9999).unwrap();
+        assert_eq!(dict.field_name(tag), None);
+        assert_eq!(dict.field_description(tag), None);
+        assert_eq!(dict.field_datatype_name(tag), None);
+    }
 
-    /// Returns the official, ASCII, human-readable name associated with `self`.
-    fn name(&self) -> &str;
+    #[test]
+    fn datatypes_of_tags() {
+        let mut dicts = Dictionary::common_dictionaries();
+        dicts.push(Dictionary::fix44());
+        dicts.push(Dictionary::fix44_patched());
+        for dict in dicts.iter() {
+            for field in dict.fields() {
+                assert_eq!(
+                    dict.datatype_of_tag(field.tag()),
+                    Some(field.fix_datatype())
+                );
+            }
+        }
+        let dict = Dictionary::fix44();
+        assert_eq!(dict.datatype_of_tag(TagU32::new(9999).unwrap()), None);
+    }
 
-    /// Returns the field location of `self`.
-    fn location(&self) -> FieldLocation;
-}
+    #[test]
+    fn messages_by_msgtype_characteristics() {
+        let dict = Dictionary::fix44();
+        let all = dict.messages_by_msgtype_prefix("");
+        assert_eq!(all.len(), dict.messages().len());
+        assert!(all.windows(2).all(|w| w[0].msg_type() < w[1].msg_type()));
+        let single = dict.messages_with_msgtype_len(1);
+        let double = dict.messages_with_msgtype_len(2);
+        assert_eq!(single.len() + double.len(), all.len());
+        assert!(single.iter().any(|msg| msg.msg_type() == "0"));
+        assert!(dict.messages_with_msgtype_len(0).is_empty());
+        assert!(dict
+            .messages_by_msgtype_prefix("B")
+            .iter()
+            .all(|msg| msg.msg_type().starts_with('B')));
+    }
 
-fn layout_item_kind<'a>(item: &'a LayoutItemKindData, dict: &'a Dictionary) -> LayoutItemKind<'a> {
-    match item {
-        LayoutItemKindData::Component { name } => {
-            LayoutItemKind::Component(dict.component_by_name(name).unwrap())
+    #[test]
+    fn onixs_urls_round_trip() {
+        let dict = Dictionary::fix44();
+        for field in dict.fields() {
+            let url = field.doc_url_onixs(dict.version());
+            assert_eq!(dict.field_by_onixs_url(&url).unwrap().tag(), field.tag());
         }
-        LayoutItemKindData::Group {
-            len_field_tag,
-            items: items_data,
-        } => {
-            let items = items_data
+        let url = |page: &str| format!("{}5.0.SP2/{}", ONIXS_URL_PREFIX, page);
+        assert!(dict.field_by_onixs_url(&url("tagNum_55.html")).is_some());
+        assert!(dict.field_by_onixs_url(&url("tagNum_9999.html")).is_none());
+        assert!(dict.field_by_onixs_url(&url("tagNum_55")).is_none());
+        assert!(dict.field_by_onixs_url(&url("msgType_D_68.html")).is_none());
+    }
+
+    #[test]
+    fn find_messages_by_partial_name() {
+        let dict = Dictionary::fix44();
+        let names = |query: &str| {
+            dict.find_messages(query)
                 .iter()
-                .map(|item_data| LayoutItem(dict, item_data))
-                .collect::<Vec<_>>();
-            let len_field = dict.field_by_tag(*len_field_tag).unwrap();
-            LayoutItemKind::Group(len_field, items)
-        }
-        LayoutItemKindData::Field { tag } => {
-            LayoutItemKind::Field(dict.field_by_tag(*tag).unwrap())
-        }
+                .map(|msg| msg.name().to_string())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(names("HEARTbeat"), vec!["Heartbeat".to_string()]);
+        let orders = names("order");
+        assert!(orders.windows(2).all(|w| w[0] < w[1]));
+        assert!(orders.contains(&"NewOrderSingle".to_string()));
+        assert!(orders.contains(&"OrderCancelRequest".to_string()));
+        assert!(names("NoSuchMessage").is_empty());
+        assert_eq!(names("").len(), dict.messages().len());
+    }
+
+    #[test]
+    fn fixml_names() {
+        let spec = include_str!("test_data/quickfix_specs/fixml_abbr_names.xml");
+        let dict = Dictionary::from_quickfix_spec(spec).unwrap();
+        let tag = |t: u32| TagU32::new(t).unwrap();
+        assert_eq!(dict.fixml_name(tag(112)), Some("TstReqID"));
+        assert_eq!(dict.fixml_name(tag(8)), Some("BeginString"));
+        assert_eq!(dict.fixml_name(tag(9999)), None);
+        assert_eq!(dict.fixml_name_for_component("Instrument"), Some("Instrmt"));
+        assert_eq!(dict.fixml_name_for_component("Parties"), None);
+        let xml = String::from_utf8(dict.to_quickfix_xml()).unwrap();
+        let dict2 = Dictionary::from_quickfix_spec(&xml).unwrap();
+        let component = dict2.component_by_name("Instrument").unwrap();
+        assert_eq!(component.abbr_name(), Some("Instrmt"));
+        assert_eq!(
+            dict2
+                .component_by_name("StandardHeader")
+                .unwrap()
+                .abbr_name(),
+            None
+        );
+        assert_eq!(
+            dict2.fixml_name_for_component("StandardHeader"),
+            Some("StandardHeader")
+        );
+    }
+
+    #[test]
+    fn unparseable_fields_can_be_skipped() {
+        let spec = include_str!("test_data/quickfix_specs/unparseable_fields.xml");
+        assert!(Dictionary::from_quickfix_spec(spec).is_err());
+        let options = QuickFixOptions {
+            skip_unparseable_fields: true,
+        };
+        let (dict, warnings) = Dictionary::from_quickfix_spec_with_options(spec, &options).unwrap();
+        assert_eq!(dict.fields().len(), 2);
+        assert!(dict.field_by_tag(5001).is_none());
+        // The datatype of the skipped field isn't added either.
+        assert!(dict.datatype_by_name("Price").is_none());
+        let lines = warnings
+            .iter()
+            .map(|warning| warning.position().unwrap().line)
+            .collect::<Vec<_>>();
+        assert_eq!(lines, vec![15, 17]);
+        let options = QuickFixOptions::default();
+        assert!(Dictionary::from_quickfix_spec_with_options(spec, &options).is_err());
     }
-}
 
-/// An entry in a sequence of FIX field definitions.
-#[derive(Clone, Debug)]
-pub struct LayoutItem<'a>(&'a Dictionary, &'a LayoutItemData);
+    #[test]
+    fn field_extra_attributes() {
+        let spec = include_str!("test_data/quickfix_specs/fixml_abbr_names.xml");
+        let dict = Dictionary::from_quickfix_spec(spec).unwrap();
+        let field = dict.field_by_tag(112).unwrap();
+        assert_eq!(field.extra_attribute("maxLength"), Some("32"));
+        assert_eq!(field.extra_attributes().count(), 1);
+        assert_eq!(dict.field_by_tag(8).unwrap().extra_attributes().count(), 0);
+        let xml = String::from_utf8(dict.to_quickfix_xml()).unwrap();
+        let dict2 = Dictionary::from_quickfix_spec(&xml).unwrap();
+        let field = dict2.field_by_tag(112).unwrap();
+        assert_eq!(field.extra_attribute("maxLength"), Some("32"));
+    }
 
-/// The kind of element contained in a [`Message`].
-#[derive(Debug)]
-pub enum LayoutItemKind<'a> {
-    /// This component item is another component.
-    Component(Component<'a>),
-    /// This component item is a FIX repeating group.
-    Group(Field<'a>, Vec<LayoutItem<'a>>),
-    /// This component item is a FIX field.
-    Field(Field<'a>),
-}
+    const INVALID_QUICKFIX_SPECS: &[&str] = &[
+        include_str!("test_data/quickfix_specs/empty_file.xml"),
+        include_str!("test_data/quickfix_specs/missing_components.xml"),
+        include_str!("test_data/quickfix_specs/missing_fields.xml"),
+        include_str!("test_data/quickfix_specs/missing_header.xml"),
+        include_str!("test_data/quickfix_specs/missing_messages.xml"),
+        include_str!("test_data/quickfix_specs/missing_trailer.xml"),
+        include_str!("test_data/quickfix_specs/root_has_no_type_attr.xml"),
+        include_str!("test_data/quickfix_specs/root_has_no_version_attrs.xml"),
+        include_str!("test_data/quickfix_specs/root_is_not_fix.xml"),
+        include_str!("test_data/quickfix_specs/duplicate_field_tag.xml"),
+        include_str!("test_data/quickfix_specs/group_with_no_fields.xml"),
+        include_str!("test_data/quickfix_specs/field_without_name.xml"),
+        include_str!("test_data/quickfix_specs/message_without_msgtype.xml"),
+        include_str!("test_data/quickfix_specs/component_referencing_undefined_component.xml"),
+        include_str!("test_data/quickfix_specs/field_referencing_unknown_datatype.xml"),
+        include_str!("test_data/quickfix_specs/message_with_duplicate_name.xml"),
+        include_str!("test_data/quickfix_specs/field_tag_zero.xml"),
+        include_str!("test_data/quickfix_specs/empty_message_name.xml"),
+        include_str!("test_data/quickfix_specs/circular_component_dependency.xml"),
+    ];
 
-impl<'a> LayoutItem<'a> {
-    /// Returns `true` if `self` is required in order to have a valid definition
-    /// of its parent container, `false` otherwise.
-    pub fn required(&self) -> bool {
-        self.1.required
+    #[test]
+    fn invalid_quickfix_spec_errors() {
+        let at = |line, column| TextPosition { line, column };
+        let cases = [
+            (
+                include_str!("test_data/quickfix_specs/duplicate_field_tag.xml"),
+                ParseDictionaryError::DuplicateFieldTag {
+                    tag: 112,
+                    position: at(16, 9),
+                },
+            ),
+            (
+                include_str!("test_data/quickfix_specs/group_with_no_fields.xml"),
+                ParseDictionaryError::EmptyGroup {
+                    name: "NoLegs".to_string(),
+                    position: at(10, 13),
+                },
+            ),
+            (
+                include_str!("test_data/quickfix_specs/field_without_name.xml"),
+                ParseDictionaryError::MissingAttribute {
+                    element: "field".to_string(),
+                    attribute: "name".to_string(),
+                    position: at(16, 9),
+                },
+            ),
+            (
+                include_str!("test_data/quickfix_specs/message_without_msgtype.xml"),
+                ParseDictionaryError::MissingAttribute {
+                    element: "message".to_string(),
+                    attribute: "msgtype".to_string(),
+                    position: at(9, 9),
+                },
+            ),
+            (
+                include_str!(
+                    "test_data/quickfix_specs/component_referencing_undefined_component.xml"
+                ),
+                ParseDictionaryError::UnknownComponent {
+                    name: "SecAltIDGrp".to_string(),
+                    position: at(13, 13),
+                },
+            ),
+            (
+                include_str!("test_data/quickfix_specs/field_referencing_unknown_datatype.xml"),
+                ParseDictionaryError::UnknownDatatype {
+                    name: "NUM_IN_GROUP".to_string(),
+                    position: at(16, 9),
+                },
+            ),
+            (
+                include_str!("test_data/quickfix_specs/message_with_duplicate_name.xml"),
+                ParseDictionaryError::DuplicateMessageName {
+                    name: "Heartbeat".to_string(),
+                    position: at(9, 9),
+                },
+            ),
+            (
+                include_str!("test_data/quickfix_specs/field_tag_zero.xml"),
+                ParseDictionaryError::InvalidFieldTag {
+                    number: "0".to_string(),
+                    position: at(16, 9),
+                },
+            ),
+            (
+                include_str!("test_data/quickfix_specs/empty_message_name.xml"),
+                ParseDictionaryError::EmptyMessageName { position: at(9, 9) },
+            ),
+            (
+                include_str!("test_data/quickfix_specs/circular_component_dependency.xml"),
+                ParseDictionaryError::CircularComponent {
+                    name: "Instrument".to_string(),
+                    position: at(17, 13),
+                },
+            ),
+        ];
+        for (spec, expected) in cases {
+            let err = Dictionary::from_quickfix_spec(spec).unwrap_err();
+            assert_eq!(err.position(), expected.position());
+            assert_eq!(err, expected);
+        }
     }
 
-    /// Returns the [`LayoutItemKind`] of `self`.
-    pub fn kind(&self) -> LayoutItemKind {
-        layout_item_kind(&self.1.kind, self.0)
+    #[test]
+    fn quickfix_spec_errors_have_positions() {
+        let spec = include_str!("test_data/quickfix_specs/unknown_field_reference.xml");
+        assert_eq!(
+            Dictionary::from_quickfix_spec(spec).unwrap_err(),
+            ParseDictionaryError::UnknownField {
+                name: "TestReqID".to_string(),
+                position: TextPosition { line: 9, column: 7 },
+            }
+        );
+        match Dictionary::from_quickfix_spec("<fix>\n  <header>\n</fix>") {
+            Err(ParseDictionaryError::InvalidData(msg)) => assert!(msg.contains("3:"), "{}", msg),
+            other => panic!("{:?}", other.map(|dict| dict.version().to_string())),
+        }
     }
 
-    /// Returns the human-readable name of `self`.
-    pub fn tag_text(&self) -> String {
-        match &self.1.kind {
-            LayoutItemKindData::Component { name } => {
-                self.0.component_by_name(name).unwrap().name().to_string()
-            }
-            LayoutItemKindData::Group {
-                len_field_tag,
-                items: _items,
-            } => self
-                .0
-                .field_by_tag(*len_field_tag)
-                .unwrap()
-                .name()
-                .to_string(),
-            LayoutItemKindData::Field { tag } => {
-                self.0.field_by_tag(*tag).unwrap().name().to_string()
+    #[test]
+    fn quickfix_spec_root_is_checked() {
+        let spec = include_str!("test_data/quickfix_specs/root_is_not_fix.xml");
+        match Dictionary::from_quickfix_spec(spec) {
+            Err(ParseDictionaryError::UnexpectedRoot { found }) => {
+                assert_eq!(found, "root_is_not_fix")
             }
+            other => panic!("{:?}", other.map(|dict| dict.version().to_string())),
         }
+        let spec = include_str!("test_data/quickfix_specs/root_has_no_type_attr.xml");
+        assert_eq!(
+            Dictionary::from_quickfix_spec(spec).unwrap_err(),
+            ParseDictionaryError::MissingAttribute {
+                element: "fix".to_string(),
+                attribute: "type".to_string(),
+                position: TextPosition { line: 1, column: 1 },
+            }
+        );
     }
-}
 
-type LayoutItems = Vec<LayoutItemData>;
+    #[test]
+    fn quickfix_spec_with_version_override() {
+        let spec = include_str!("test_data/quickfix_specs/root_has_no_minor_version_attr.xml");
+        assert!(Dictionary::from_quickfix_spec(spec).is_err());
+        let dict = Dictionary::from_quickfix_spec_with_version(spec, "FIX.4.2").unwrap();
+        assert_eq!(dict.version(), "FIX.4.2");
+    }
 
-#[derive(Clone, Debug)]
-struct MessageData {
-    /// The unique integer identifier of this message type.
-    component_id: u32,
-    /// **Primary key**. The unique character identifier of this message
-    /// type; used literally in FIX messages.
-    msg_type: SmartString,
-    /// The name of this message type.
-    name: SmartString,
-    /// Identifier of the category to which this message belongs.
-    category_name: SmartString,
-    /// Identifier of the section to which this message belongs.
-    section_id: String,
-    layout_items: LayoutItems,
-    /// The abbreviated name of this message, when used in an XML context.
-    abbr_name: Option<SmartString>,
-    /// A boolean used to indicate if the message is to be generated as part
-    /// of FIXML.
-    required: bool,
-    description: String,
-    elaboration: Option<String>,
-}
+    #[test]
+    fn fix44_iter_all_layout_items() {
+        let dict = Dictionary::fix44();
+        let items = dict.iter_all_layout_items().collect::<Vec<_>>();
+        let top_level = dict
+            .messages()
+            .iter()
+            .map(|msg| msg.layout().count())
+            .chain(dict.components().iter().map(|c| c.items().count()))
+            .sum::<usize>();
+        assert_eq!(items.iter().filter(|i| i.depth == 0).count(), top_level);
+        // NoLegs > NoLegSecurityAltID > LegSecurityAltID
+        assert!(items.iter().any(|i| i.depth == 1 && i.parent == "NoLegs"));
+        let party_roles = items
+            .iter()
+            .filter(|i| i.item.tag_text() == "PartyRole")
+            .collect::<Vec<_>>();
+        assert!(!party_roles.is_empty());
+        assert!(party_roles.iter().all(|i| i.depth == 1));
+
+        let mut dict = Dictionary::fix44();
+        let message = dict.messages_by_msgtype.get_mut("0").unwrap();
+        message.layout_items.push(LayoutItemData {
+            required: false,
+            kind: LayoutItemKindData::Group {
+                len_field_tag: 9999,
+                items: vec![LayoutItemData {
+                    required: false,
+                    kind: LayoutItemKindData::Field { tag: 112 },
+                }],
+            },
+        });
+        let items = dict.iter_all_layout_items().collect::<Vec<_>>();
+        assert!(items
+            .iter()
+            .any(|i| i.depth == 1 && i.parent == "Heartbeat" && i.item.tag_text() == "TestReqID"));
+    }
 
-/// A [`Message`] is a unit of information sent on the wire between
-/// counterparties. Every [`Message`] is composed of fields and/or components.
-#[derive(Debug)]
-pub struct Message<'a>(&'a Dictionary, &'a MessageData);
+    #[test]
+    fn fix44_component_dependencies_and_dependents() {
+        let dict = Dictionary::fix44();
+        assert_eq!(
+            dict.component_dependencies("Instrument"),
+            &["EvntGrp", "SecAltIDGrp"][..]
+        );
+        assert!(dict.component_dependencies("CommissionData").is_empty());
+        assert!(dict.component_dependencies("Foobar").is_empty());
+        let dependents = dict.component_dependents("Instrument");
+        assert!(dependents.contains(&"NewOrderSingle".to_string()));
+        for dependent in dependents.iter() {
+            if let Some(component) = dict.component_by_name(dependent) {
+                let deps = dict.component_dependencies(component.name());
+                assert!(deps.contains(&"Instrument".to_string()));
+            }
+        }
+        assert!(dict.component_dependents("StandardHeader").is_empty());
+    }
 
-impl<'a> Message<'a> {
-    /// Returns the human-readable name of `self`.
-    pub fn name(&self) -> &str {
-        self.1.name.as_str()
+    #[test]
+    fn fix44_remove_field() {
+        let mut dict = Dictionary::fix44();
+        let removed = dict.remove_field(TagU32::new(11).unwrap()).unwrap();
+        assert_eq!(removed.definition.name, "ClOrdID");
+        assert!(removed.required_in.contains(&"NewOrderSingle".to_string()));
+        assert!(removed
+            .required_in
+            .contains(&"ListOrdGrp.NoOrders".to_string()));
+        assert!(dict.field_by_tag(11).is_none());
+        assert!(dict.field_by_name_case_insensitive("clordid").is_none());
+        assert!(!dict.to_cross_reference_csv().contains(",ClOrdID,"));
+        assert!(dict.remove_field(TagU32::new(11).unwrap()).is_none());
+
+        // Groups go away together with their `NumInGroup` field.
+        let removed = dict.remove_field(TagU32::new(453).unwrap()).unwrap();
+        assert!(removed.required_in.is_empty());
+        let parties = dict.component_by_name("Parties").unwrap();
+        assert_eq!(parties.items().count(), 0);
+        assert!(dict.field_by_tag(448).is_some());
+        assert!(dict
+            .group_for_count_tag("D", TagU32::new(453).unwrap())
+            .is_none());
     }
 
-    /// Returns the message type of `self`.
-    pub fn msg_type(&self) -> &str {
-        self.1.msg_type.as_str()
+    #[test]
+    fn enum_restricted_fields_in_range() {
+        let dict = Dictionary::fix44();
+        let tag = |tag| TagU32::new(tag).unwrap();
+        let all = dict
+            .iter_enum_restricted_fields_in_range(tag(1), tag(u32::MAX))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            all.len(),
+            dict.fields().iter().filter(|f| f.enums().is_some()).count()
+        );
+        assert!(all.windows(2).all(|w| w[0].tag() < w[1].tag()));
+        let side = dict
+            .iter_enum_restricted_fields_in_range(tag(54), tag(54))
+            .collect::<Vec<_>>();
+        assert_eq!(side.len(), 1);
+        assert_eq!(
+            dict.iter_enum_restricted_fields_in_range(tag(60), tag(50))
+                .count(),
+            0
+        );
     }
 
-    /// Returns the description associated with `self`.
-    pub fn description(&self) -> &str {
-        &self.1.description
+    #[test]
+    fn clone_field_with_new_datatype() {
+        let dict = Dictionary::fix44();
+        let side = dict.field_by_tag(54).unwrap();
+        let clone = side.clone_with_new_datatype(FixDatatype::String);
+        assert_eq!(clone.tag, 54);
+        assert_eq!(clone.name, "Side");
+        assert_eq!(clone.datatype, FixDatatype::String);
+        assert_eq!(clone.enums.len(), side.enums().unwrap().count());
+        assert_eq!(side.fix_datatype(), FixDatatype::Char);
     }
 
-    pub fn group_info(&self, num_in_group_tag: TagU32) -> Option<TagU32> {
-        self.layout().find_map(|layout_item| {
-            if let LayoutItemKind::Group(field, items) = layout_item.kind() {
-                if field.tag() == num_in_group_tag {
-                    if let LayoutItemKind::Field(f) = items[0].kind() {
-                        Some(f.tag())
-                    } else {
-                        None
-                    }
-                } else {
-                    None
+    #[test]
+    fn components_containing_fields() {
+        let dict = Dictionary::fix44();
+        let names = |dict: &Dictionary, tag: u32| {
+            dict.components_containing_field(TagU32::new(tag).unwrap())
+                .iter()
+                .map(|c| c.name().to_string())
+                .collect::<Vec<_>>()
+        };
+        // Inside a group, but not through `PtysSubGrp` from `Parties`.
+        assert_eq!(names(&dict, 523), &["PtysSubGrp"]);
+        assert!(names(&dict, 453).contains(&"Parties".to_string()));
+        assert!(!names(&dict, 448).contains(&"Instrument".to_string()));
+        assert!(names(&dict, 9999).is_empty());
+        for component in dict.components() {
+            for item in component.items() {
+                if let LayoutItemKind::Field(field) = item.kind() {
+                    assert!(names(&dict, field.tag().get()).contains(&component.name().to_string()));
                 }
-            } else if let LayoutItemKind::Component(_component) = layout_item.kind() {
-                None
-            } else {
-                None
             }
-        })
+        }
+        let dict = dict.clone_without_layout();
+        assert!(names(&dict, 453).is_empty());
     }
 
-    /// Returns the component ID of `self`.
-    pub fn component_id(&self) -> u32 {
-        self.1.component_id
+    #[test]
+    fn mandatory_and_optional_only_field_tags() {
+        let mut dicts = Dictionary::common_dictionaries();
+        dicts.push(Dictionary::fix44());
+        for dict in dicts.iter() {
+            let mandatory = dict.mandatory_field_tags();
+            let optional_only = dict.optional_only_field_tags();
+            assert!(mandatory.is_disjoint(&optional_only), "{}", dict.version());
+            for msg in dict.messages() {
+                for field in msg.flatten_fields() {
+                    let tag = field.tag();
+                    assert!(mandatory.contains(&tag) || optional_only.contains(&tag));
+                }
+            }
+        }
+        let dict = Dictionary::fix44();
+        let tag = |tag| TagU32::new(tag).unwrap();
+        // BeginString, from the header, and CheckSum, from the trailer.
+        assert!(dict.mandatory_field_tags().contains(&tag(8)));
+        assert!(dict.mandatory_field_tags().contains(&tag(10)));
+        // PartyID is required inside the optional `NoPartyIDs` group only.
+        assert!(dict.optional_only_field_tags().contains(&tag(448)));
     }
 
-    pub fn layout(&self) -> impl Iterator<Item = LayoutItem> {
-        self.1
-            .layout_items
-            .iter()
-            .map(move |data| LayoutItem(self.0, data))
+    #[test]
+    fn name_to_tag_map_matches_fields() {
+        let mut dict = Dictionary::fix44();
+        dict.apply_field_rename("Symbol", "Ticker").unwrap();
+        let map = dict.name_to_tag_map();
+        assert_eq!(map.len(), dict.fields().len());
+        for field in dict.fields() {
+            assert_eq!(map.get(field.name()), Some(&field.tag()));
+        }
+        assert!(!map.contains_key("Symbol"));
     }
 
-    pub fn fixml_required(&self) -> bool {
-        self.1.required
+    #[test]
+    fn group_shell_components() {
+        let dict = Dictionary::fix44();
+        let shell = |name: &str| dict.component_by_name(name).unwrap().single_group();
+        let group_field = |item: LayoutItem| match item.kind() {
+            LayoutItemKind::Group(field, _) => field.tag().get(),
+            _ => panic!("not a group"),
+        };
+        assert_eq!(shell("InstrmtLegGrp").map(group_field), Some(555));
+        assert_eq!(shell("LinesOfTextGrp").map(group_field), Some(33));
+        assert!(shell("Instrument").is_none());
+        assert!(shell("StandardHeader").is_none());
     }
-}
 
-/// A [`Section`] is a collection of many [`Component`]-s. It has no practical
-/// effect on encoding and decoding of FIX data and it's only used for
-/// documentation and human readability.
-#[derive(Clone, Debug, PartialEq)]
-pub struct Section {}
+    #[test]
+    fn fix44_remove_message_and_component() {
+        let mut dict = Dictionary::fix44();
+        let message_count = dict.messages().len();
+        let removed = dict.remove_message("8").unwrap();
+        assert_eq!(removed.name, "ExecutionReport");
+        assert_eq!(dict.messages().len(), message_count - 1);
+        assert!(dict.message_by_msgtype("8").is_none());
+        assert!(dict.find_messages("executionreport").is_empty());
+        assert!(dict.remove_message("8").is_none());
+
+        let instrument_id = dict.component_by_name("Instrument").unwrap().id();
+        let removed = dict.remove_component("Instrument").unwrap();
+        assert!(!removed.items.is_empty());
+        assert!(dict.component_by_name("Instrument").is_none());
+        assert_eq!(dict.component_name_for_id(instrument_id as usize), None);
+        let new_order_single = dict.message_by_msgtype("D").unwrap();
+        assert!(!new_order_single
+            .layout()
+            .any(|item| item.tag_text() == "Instrument"));
+        assert!(dict.component_dependents("Instrument").is_empty());
+        assert!(dict.remove_component("Instrument").is_none());
+
+        // `InstrmtLegGrp` only holds the `NoLegs` group, whose only item is
+        // `InstrumentLeg`.
+        let mut dict = Dictionary::fix44();
+        dict.remove_component("InstrumentLeg").unwrap();
+        let component = dict.component_by_name("InstrmtLegGrp").unwrap();
+        assert_eq!(component.items().count(), 0);
+        assert!(Dictionary::from_quickfix_spec(&dict.to_quickfix_string()).is_ok());
+    }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    use std::collections::HashSet;
+    #[test]
+    fn remove_component_unlinks_data_fields() {
+        let field = |tag: u32, name: &str, datatype| FieldDef {
+            tag,
+            name: name.to_string(),
+            datatype,
+            enums: vec![],
+        };
+        let fields = [
+            field(95, "RawDataLength", FixDatatype::Length),
+            field(96, "RawData", FixDatatype::Data),
+        ];
+        let item = |name: &str| LayoutItemDef::Field {
+            name: name.to_string(),
+            required: false,
+        };
+        let components = [ComponentDef {
+            name: "RawDataBlock".to_string(),
+            category: String::new(),
+            items: vec![item("RawDataLength"), item("RawData")],
+        }];
+        let messages = [MessageDef {
+            name: "Logon".to_string(),
+            msg_type: "A".to_string(),
+            category: "admin".to_string(),
+            items: vec![LayoutItemDef::Component {
+                name: "RawDataBlock".to_string(),
+                required: false,
+            }],
+            responses: vec![],
+        }];
+        let mut dict = Dictionary::from_parts(
+            "FIX.4.4",
+            &fields,
+            &[FixDatatype::Length, FixDatatype::Data],
+            &components,
+            &messages,
+        )
+        .unwrap();
+        let data_tag = |dict: &Dictionary| dict.field_by_tag(95).unwrap().data_tag();
+        assert_eq!(data_tag(&dict), TagU32::new(96));
+        dict.remove_component("RawDataBlock").unwrap();
+        assert_eq!(data_tag(&dict), None);
+    }
 
     #[test]
-    fn fix44_quickfix_is_ok() {
+    fn fix44_component_dependency_dot() {
         let dict = Dictionary::fix44();
-        let msg_heartbeat = dict.message_by_name("Heartbeat").unwrap();
-        assert_eq!(msg_heartbeat.msg_type(), "0");
-        assert_eq!(msg_heartbeat.name(), "Heartbeat".to_string());
-        assert!(msg_heartbeat.layout().any(|c| {
-            if let LayoutItemKind::Field(f) = c.kind() {
-                f.name() == "TestReqID"
-            } else {
-                false
-            }
-        }));
+        let dot = dict.component_dependency_dot();
+        assert_eq!(dot, dict.component_dependency_dot());
+        assert!(dot.ends_with("}\n"));
+        let lines = dot.lines().collect::<Vec<_>>();
+        let edge_count = dict
+            .components()
+            .iter()
+            .map(|component| dict.component_dependencies(component.name()).len())
+            .sum::<usize>();
+        assert_eq!(
+            lines.iter().filter(|l| l.contains(" -> ")).count(),
+            edge_count
+        );
+        assert_eq!(lines.len(), 2 + dict.components().len() + edge_count);
+        assert!(lines.contains(&"    \"Instrument\" -> \"SecAltIDGrp\";"));
+        assert_eq!(dot_id(r#"a"b\c"#), r#""a\"b\\c""#);
     }
 
     #[test]
-    fn all_datatypes_are_used_at_least_once() {
-        for dict in Dictionary::common_dictionaries().iter() {
-            let datatypes_count = dict.datatypes().len();
-            let mut datatypes = HashSet::new();
-            for field in dict.fields() {
-                datatypes.insert(field.data_type().name().to_string());
-            }
-            assert_eq!(datatypes_count, datatypes.len());
+    fn fix44_apply_field_rename() {
+        let mut dict = Dictionary::fix44();
+        assert_eq!(
+            dict.apply_field_rename("Foobar", "Baz"),
+            Err(DictError::FieldNotFound)
+        );
+        assert_eq!(
+            dict.apply_field_rename("Price", "OrderQty"),
+            Err(DictError::NameConflict)
+        );
+        dict.apply_field_rename("Price", "LimitPrice").unwrap();
+        assert_eq!(dict.field_by_tag(44).unwrap().name(), "LimitPrice");
+        assert!(dict.field_by_name("Price").is_none());
+        let msg = dict.message_by_msgtype("D").unwrap();
+        assert!(msg.layout().any(|item| item.tag_text() == "LimitPrice"));
+        assert!(!msg.layout().any(|item| item.tag_text() == "Price"));
+        dict.apply_field_rename("LimitPrice", "LimitPrice").unwrap();
+    }
+
+    #[test]
+    fn fix44_message_names_for_code_generation() {
+        let dict = Dictionary::fix44();
+        let cases = [
+            ("D", "new_order_single", "NewOrderSingle"),
+            ("6", "ioi", "Ioi"),
+            ("AE", "trade_capture_report", "TradeCaptureReport"),
+            ("BE", "user_request", "UserRequest"),
+        ];
+        for (msg_type, snake, pascal) in cases.iter() {
+            let msg = dict.message_by_msgtype(msg_type).unwrap();
+            assert_eq!(msg.name_snake_case(), *snake);
+            assert_eq!(msg.name_pascal_case(), *pascal);
+            assert_eq!(msg.module_name(), *snake);
+        }
+        for msg in dict.messages() {
+            let module_name = msg.module_name();
+            assert!(module_name
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_'));
         }
     }
 
     #[test]
-    fn at_least_one_datatype() {
-        for dict in Dictionary::common_dictionaries().iter() {
-            assert!(dict.datatypes().len() >= 1);
+    fn field_enum_rust_names() {
+        let cases = [
+            ("MARKET", "MARKET", "Market"),
+            ("Market Order", "MARKET_ORDER", "MarketOrder"),
+            ("LIMIT_ON_CLOSE", "LIMIT_ON_CLOSE", "LimitOnClose"),
+            (
+                "YIELD_VALUE_OF_1_32",
+                "YIELD_VALUE_OF_1_32",
+                "YieldValueOf132",
+            ),
+            ("1_32", "V_1_32", "V132"),
+            ("3rd party", "V_3RD_PARTY", "V3rdParty"),
+            (
+                "Good Till Cancel (GTC)",
+                "GOOD_TILL_CANCEL_GTC",
+                "GoodTillCancelGtc",
+            ),
+            ("  Buy -- minus  ", "BUY_MINUS", "BuyMinus"),
+            ("ExecutionReport", "EXECUTION_REPORT", "ExecutionReport"),
+            ("Très bien", "TR_S_BIEN", "TrSBien"),
+            ("", "V_", "V"),
+        ];
+        for (description, const_name, variant_name) in cases.iter() {
+            let data = FieldEnumData {
+                value: "X".to_string(),
+                description: description.to_string(),
+            };
+            let dict = Dictionary::new("FIX.4.4");
+            let field_enum = FieldEnum(&dict, &data);
+            assert_eq!(field_enum.rust_const_name(), *const_name, "{}", description);
+            assert_eq!(
+                field_enum.rust_variant_name(),
+                *variant_name,
+                "{}",
+                description
+            );
         }
     }
 
     #[test]
-    fn std_header_and_trailer_always_present() {
+    fn framing_specs() {
+        let tags = |tags: Vec<TagU32>| tags.iter().map(|t| t.get()).collect::<Vec<_>>();
         for dict in Dictionary::common_dictionaries().iter() {
-            let std_header = dict.component_by_name("StandardHeader");
-            let std_trailer = dict.component_by_name("StandardTrailer");
-            assert!(std_header.is_some() && std_trailer.is_some());
+            let framing = dict.framing_spec();
+            if dict.version().starts_with("FIX.5.0") {
+                assert!(framing.leading_tags.is_empty());
+                assert!(framing.trailing_tags.is_empty());
+            } else {
+                assert_eq!(
+                    tags(framing.leading_tags),
+                    &[8, 9, 35],
+                    "{}",
+                    dict.version()
+                );
+                assert_eq!(tags(framing.trailing_tags), &[10], "{}", dict.version());
+            }
         }
     }
 
     #[test]
-    fn fix44_field_28_has_three_variants() {
+    fn fix44_iter_groups() {
         let dict = Dictionary::fix44();
-        let field_28 = dict.field_by_tag(28).unwrap();
-        assert_eq!(field_28.name(), "IOITransType");
-        assert_eq!(field_28.enums().unwrap().count(), 3);
+        let groups = dict.iter_groups().collect::<Vec<_>>();
+        let mut count_tags = groups
+            .iter()
+            .map(|group| group.count_field.tag())
+            .collect::<Vec<_>>();
+        count_tags.sort();
+        count_tags.dedup();
+        assert_eq!(count_tags.len(), groups.len());
+        assert!(groups
+            .iter()
+            .all(|group| group.count_field.fix_datatype() == FixDatatype::NumInGroup));
+        let no_legs = groups
+            .iter()
+            .find(|group| group.count_field.tag().get() == 555)
+            .unwrap();
+        assert_eq!(no_legs.delimiter_field.tag().get(), 600);
+        let info = dict
+            .group_for_count_tag("8", no_legs.count_field.tag())
+            .unwrap();
+        assert_eq!(info.delimiter_tag, no_legs.delimiter_field.tag());
     }
 
     #[test]
-    fn fix44_field_36_has_no_variants() {
-        let dict = Dictionary::fix44();
-        let field_36 = dict.field_by_tag(36).unwrap();
-        assert_eq!(field_36.name(), "NewSeqNo");
-        assert!(field_36.enums().is_none());
+    fn iter_groups_skips_empty_groups() {
+        let field = |tag: u32, name: &str, datatype| FieldDef {
+            tag,
+            name: name.to_string(),
+            datatype,
+            enums: vec![],
+        };
+        let fields = [
+            field(448, "PartyID", FixDatatype::String),
+            field(453, "NoPartyIDs", FixDatatype::NumInGroup),
+        ];
+        let group = |items| LayoutItemDef::Group {
+            name: "NoPartyIDs".to_string(),
+            required: false,
+            items,
+        };
+        let party_id = LayoutItemDef::Field {
+            name: "PartyID".to_string(),
+            required: false,
+        };
+        let messages = [MessageDef {
+            name: "NewOrderSingle".to_string(),
+            msg_type: "D".to_string(),
+            category: "app".to_string(),
+            items: vec![group(vec![]), group(vec![party_id])],
+            responses: vec![],
+        }];
+        let dict = Dictionary::from_parts(
+            "FIX.4.4",
+            &fields,
+            &[FixDatatype::String, FixDatatype::NumInGroup],
+            &[],
+            &messages,
+        )
+        .unwrap();
+        let groups = dict.iter_groups().collect::<Vec<_>>();
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].count_field.tag().get(), 453);
+        assert_eq!(groups[0].delimiter_field.tag().get(), 448);
     }
 
     #[test]
-    fn fix44_field_167_has_eucorp_variant() {
+    fn message_inheritance() {
+        let spec = include_str!("test_data/quickfix_specs/message_inheritance.xml");
+        let dict = Dictionary::from_quickfix_spec(spec).unwrap();
+        let base = dict.message_inherits_from("AN").unwrap();
+        assert_eq!(base.name(), "RequestForPositionsBase");
+        assert_eq!(
+            dict.message_by_msgtype("AN").unwrap().base_message(),
+            Some("RequestForPositionsBase")
+        );
+        assert!(dict.message_inherits_from("ANB").is_none());
+        let names = |msg_type: &str| {
+            dict.message_by_msgtype(msg_type)
+                .unwrap()
+                .flatten_fields()
+                .iter()
+                .map(|field| field.name().to_string())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(names("ANB"), &["PosReqID", "PosReqType"]);
+        assert_eq!(
+            names("AN"),
+            &["PosReqID", "PosReqType", "NoPartyIDs", "PartyID", "Account"]
+        );
+        let xml = String::from_utf8(dict.to_quickfix_xml()).unwrap();
+        let dict = Dictionary::from_quickfix_spec(&xml).unwrap();
+        assert_eq!(dict.message_inherits_from("AN").unwrap().msg_type(), "ANB");
+    }
+
+    #[test]
+    fn fix44_group_tag_map() {
         let dict = Dictionary::fix44();
-        let field_167 = dict.field_by_tag(167).unwrap();
-        assert_eq!(field_167.name(), "SecurityType");
-        assert!(field_167.enums().unwrap().any(|e| e.value() == "EUCORP"));
+        let map = dict.build_group_tag_map();
+        let tag = |t: u32| TagU32::new(t).unwrap();
+        assert_eq!(map.len(), dict.iter_groups().count());
+        for (count_tag, tags) in map.iter() {
+            assert_eq!(dict.group_tags(*count_tag), Some(tags));
+        }
+        let no_legs = &map[&tag(555)];
+        // LegSymbol, from the `InstrumentLeg` component.
+        assert!(no_legs.contains(&tag(600)));
+        // NoLegSecurityAltID and LegSecurityAltID, from a nested group.
+        assert!(no_legs.contains(&tag(604)));
+        assert!(no_legs.contains(&tag(605)));
+        assert!(!no_legs.contains(&tag(555)));
+        assert!(dict.group_tags(tag(55)).is_none());
     }
 
-    const INVALID_QUICKFIX_SPECS: &[&str] = &[
-        include_str!("test_data/quickfix_specs/empty_file.xml"),
-        include_str!("test_data/quickfix_specs/missing_components.xml"),
-        include_str!("test_data/quickfix_specs/missing_fields.xml"),
-        include_str!("test_data/quickfix_specs/missing_header.xml"),
-        include_str!("test_data/quickfix_specs/missing_messages.xml"),
-        include_str!("test_data/quickfix_specs/missing_trailer.xml"),
-        include_str!("test_data/quickfix_specs/root_has_no_type_attr.xml"),
-        include_str!("test_data/quickfix_specs/root_has_no_version_attrs.xml"),
-        include_str!("test_data/quickfix_specs/root_is_not_fix.xml"),
-    ];
+    #[test]
+    fn fix44_message_wire_size_estimate() {
+        let dict = Dictionary::fix44();
+        // 8=FIX.4.4|9=...|35=0|49=...|56=...|34=...|52=...|10=...|
+        let heartbeat = dict.message_wire_size_estimate("0").unwrap();
+        let header_and_trailer = [8, 9, 35, 49, 56, 34, 52, 10]
+            .iter()
+            .map(|tag| {
+                let field = dict.field_by_tag(*tag).unwrap();
+                tag.to_string().len() + field.fix_datatype().byte_count_hint() + 2
+            })
+            .sum::<usize>();
+        assert_eq!(heartbeat, header_and_trailer);
+        assert!(dict.message_wire_size_estimate("D").unwrap() > heartbeat);
+        assert!(dict.message_wire_size_estimate("Foobar").is_none());
+        for msg in dict.messages() {
+            assert!(dict.message_wire_size_estimate(msg.msg_type()).unwrap() >= heartbeat);
+        }
+    }
 
     #[test]
     fn invalid_quickfix_specs() {
@@ -0,0 +1,299 @@
+use super::*;
+
+/// The error type that can arise when merging dictionaries with
+/// [`Dictionary::union`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConflictError {
+    /// All fields with incompatible definitions, sorted by tag.
+    pub conflicts: Vec<DatatypeConflict>,
+}
+
+/// A field which is defined with incompatible datatypes by two of the
+/// dictionaries given to [`Dictionary::union`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DatatypeConflict {
+    /// The tag of the field.
+    pub tag: TagU32,
+    /// The datatype of the field according to the earlier dictionaries.
+    pub existing: FixDatatype,
+    /// The datatype of the field according to the later dictionary.
+    pub new: FixDatatype,
+    /// The version of the later dictionary.
+    pub version: String,
+}
+
+/// Merging
+impl Dictionary {
+    /// Merges `dicts` into a single [`Dictionary`] that contains the union of
+    /// their fields, code sets, components, messages, datatypes, and
+    /// categories, e.g. for lenient decoding of messages from several FIX
+    /// versions.
+    ///
+    /// `dicts` should be sorted from oldest to newest: definitions from later
+    /// dictionaries take precedence over earlier ones, except for code sets,
+    /// whose values are accumulated. The version of the result is that of the
+    /// last dictionary. Components are merged by name, so components defined
+    /// by several dictionaries appear only once, under a single ID.
+    ///
+    /// Fields may change datatype across versions, but only within broad
+    /// families: numbers (e.g. from `int` to `Qty`), dates and times, and raw
+    /// data. Textual datatypes (e.g. `char` or `String`) are compatible with
+    /// any other datatype, as older FIX versions defined many identifiers as
+    /// `int` fields. Any other change is a conflict, and all conflicts are
+    /// reported at once.
+    ///
+    /// ```
+    /// use fefix_dictionary::{Dictionary, FieldDef, FixDatatype};
+    ///
+    /// let venue_fields = [FieldDef {
+    ///     tag: 5001,
+    ///     name: "VenueOrderTag".to_string(),
+    ///     datatype: FixDatatype::String,
+    ///     enums: vec![],
+    /// }];
+    /// let venue =
+    ///     Dictionary::from_parts("FIX.4.4", &venue_fields, &[FixDatatype::String], &[], &[])
+    ///         .unwrap();
+    /// let fix44 = Dictionary::fix44();
+    /// let dict = Dictionary::union(&[&fix44, &venue]).unwrap();
+    /// assert!(dict.field_by_name("VenueOrderTag").is_some());
+    /// assert!(dict.message_by_name("NewOrderSingle").is_some());
+    /// ```
+    pub fn union(dicts: &[&Dictionary]) -> Result<Dictionary, ConflictError> {
+        let version = dicts.last().map(|dict| dict.version()).unwrap_or_default();
+        let mut union = Dictionary::new(version);
+        let mut conflicts = Vec::new();
+        for dict in dicts {
+            for datatype in dict.data_types_by_name.values() {
+                union.add_datatype(datatype.clone());
+            }
+            for category in dict.categories_by_name.values() {
+                union.add_category(category.clone());
+            }
+            for field in dict.fields_by_tags.values() {
+                let mut field = field.clone();
+                if let Some(existing) = union.fields_by_tags.get(&field.tag) {
+                    let existing_datatype =
+                        union.data_types_by_name[&existing.data_type_name].datatype;
+                    let new_datatype = dict.data_types_by_name[&field.data_type_name].datatype;
+                    if !datatypes_are_compatible(existing_datatype, new_datatype) {
+                        conflicts.push(DatatypeConflict {
+                            tag: TagU32::new(field.tag).unwrap(),
+                            existing: existing_datatype,
+                            new: new_datatype,
+                            version: dict.version().to_string(),
+                        });
+                    }
+                    field.value_restrictions =
+                        merge_enums(&existing.value_restrictions, &field.value_restrictions);
+                    union.remove_field_names(field.tag);
+                }
+                union.add_field(field);
+            }
+            for component in dict.components_by_name.values() {
+                union.add_component(component.clone());
+            }
+            for message in dict.messages_by_msgtype.values() {
+                if let Some(existing) = union.messages_by_msgtype.get(&message.msg_type) {
+                    let name = existing.name.clone();
+                    union.message_msgtypes_by_name.remove(&name);
+                    union
+                        .message_msgtypes_by_lowercase_name
+                        .remove(name.to_ascii_lowercase().as_str());
+                }
+                union.add_message(message.clone());
+            }
+        }
+        if conflicts.is_empty() {
+            union.index_layouts();
+            Ok(union)
+        } else {
+            conflicts.sort_by_key(|conflict| conflict.tag);
+            Err(ConflictError { conflicts })
+        }
+    }
+}
+
+/// Broad families of datatypes. Across FIX versions, fields often moved from
+/// one datatype to another of the same family.
+#[derive(PartialEq, Eq)]
+enum DatatypeFamily {
+    Numeric,
+    Text,
+    Temporal,
+    Binary,
+}
+
+fn datatype_family(datatype: FixDatatype) -> DatatypeFamily {
+    match datatype {
+        FixDatatype::UtcTimestamp
+        | FixDatatype::UtcTimeOnly
+        | FixDatatype::UtcDateOnly
+        | FixDatatype::LocalMktDate
+        | FixDatatype::MonthYear => DatatypeFamily::Temporal,
+        FixDatatype::Data | FixDatatype::XmlData => DatatypeFamily::Binary,
+        datatype => match datatype.base_type() {
+            FixDatatype::Int | FixDatatype::Float => DatatypeFamily::Numeric,
+            _ => DatatypeFamily::Text,
+        },
+    }
+}
+
+/// Two datatypes are compatible if they belong to the same family, or if
+/// either is textual.
+fn datatypes_are_compatible(a: FixDatatype, b: FixDatatype) -> bool {
+    let (a, b) = (datatype_family(a), datatype_family(b));
+    a == b || a == DatatypeFamily::Text || b == DatatypeFamily::Text
+}
+
+/// Returns the values of `existing` followed by those of `new` which are not
+/// in `existing` already. A field allows any value if either definition does.
+fn merge_enums(
+    existing: &Option<Vec<FieldEnumData>>,
+    new: &Option<Vec<FieldEnumData>>,
+) -> Option<Vec<FieldEnumData>> {
+    let (existing, new) = (existing.as_ref()?, new.as_ref()?);
+    let mut values = existing.clone();
+    for value in new {
+        if !values.iter().any(|v| v.value == value.value) {
+            values.push(value.clone());
+        }
+    }
+    Some(values)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn field(tag: u32, name: &str, datatype: FixDatatype) -> FieldDef {
+        FieldDef {
+            tag,
+            name: name.to_string(),
+            datatype,
+            enums: vec![],
+        }
+    }
+
+    fn venue_dictionary(fields: &[FieldDef]) -> Dictionary {
+        let datatypes = fields.iter().map(|f| f.datatype).collect::<Vec<_>>();
+        Dictionary::from_parts("FIX.4.4-Venue", fields, &datatypes, &[], &[]).unwrap()
+    }
+
+    #[test]
+    fn union_of_fix4_dictionaries() {
+        // FIX 4.4 is always enabled, so it's not part of `common_dictionaries`.
+        let mut dicts = Dictionary::common_dictionaries()
+            .into_iter()
+            .filter(|dict| dict.version().starts_with("FIX.4"))
+            .collect::<Vec<_>>();
+        dicts.push(Dictionary::fix44());
+        let refs = dicts.iter().collect::<Vec<_>>();
+        let union = Dictionary::union(&refs).unwrap();
+        assert_eq!(union.version(), "FIX.4.4");
+        for dict in dicts.iter() {
+            for field in dict.fields() {
+                assert!(union.field_by_tag(field.tag().get()).is_some());
+            }
+            for message in dict.messages() {
+                assert!(union.message_by_msgtype(message.msg_type()).is_some());
+            }
+        }
+        // Newer definitions take precedence.
+        let fix44 = dicts.last().unwrap();
+        assert_eq!(
+            union.field_by_tag(22).unwrap().name(),
+            fix44.field_by_tag(22).unwrap().name()
+        );
+        for field in union.fields() {
+            let by_name = union.field_by_name(field.name()).unwrap();
+            assert_eq!(by_name.tag(), field.tag());
+        }
+        assert!(union.verify_no_duplicate_fields().is_empty());
+        for component in union.components() {
+            let id = component.id() as usize;
+            assert_eq!(union.component_name_for_id(id), Some(component.name()));
+        }
+    }
+
+    #[test]
+    fn union_collapses_components() {
+        let fix44 = Dictionary::fix44();
+        let venue = DictionaryBuilder::from_dictionary(&fix44).build().unwrap();
+        let union = Dictionary::union(&[&fix44, &venue]).unwrap();
+        assert_eq!(union.components().len(), fix44.components().len());
+        for component in union.components() {
+            let original = fix44.component_by_name(component.name()).unwrap();
+            assert_eq!(component, original);
+            let id = component.id() as usize;
+            assert_eq!(union.component_name_for_id(id), Some(component.name()));
+        }
+        // Later layouts take precedence.
+        let mut builder = DictionaryBuilder::from_dictionary(&fix44);
+        builder.add_component(ComponentDef {
+            name: "Parties".to_string(),
+            category: String::new(),
+            items: vec![LayoutItemDef::Field {
+                name: "PartyID".to_string(),
+                required: true,
+            }],
+        });
+        let venue = builder.build().unwrap();
+        let union = Dictionary::union(&[&fix44, &venue]).unwrap();
+        let parties = union.component_by_name("Parties").unwrap();
+        assert_eq!(parties, venue.component_by_name("Parties").unwrap());
+        assert_ne!(parties, fix44.component_by_name("Parties").unwrap());
+        assert_eq!(union.components().len(), fix44.components().len());
+    }
+
+    #[test]
+    fn union_accumulates_enums() {
+        let mut side = field(54, "Side", FixDatatype::Char);
+        side.enums = vec![
+            ("1".to_string(), "BUY".to_string()),
+            ("Z".to_string(), "VENUE_SPECIFIC".to_string()),
+        ];
+        let venue = venue_dictionary(&[side]);
+        let fix44 = Dictionary::fix44();
+        let union = Dictionary::union(&[&fix44, &venue]).unwrap();
+        assert_eq!(union.version(), "FIX.4.4-Venue");
+        let values = union
+            .field_by_tag(54)
+            .unwrap()
+            .enums()
+            .unwrap()
+            .map(|e| e.value().to_string())
+            .collect::<Vec<_>>();
+        let fix44_count = fix44.field_by_tag(54).unwrap().enums().unwrap().count();
+        assert_eq!(values.len(), fix44_count + 1);
+        assert_eq!(values.last().unwrap(), "Z");
+    }
+
+    #[test]
+    fn union_reports_conflicts() {
+        let venue = venue_dictionary(&[
+            field(112, "TestReqID", FixDatatype::Int),
+            field(44, "Price", FixDatatype::Amt),
+            field(58, "Text", FixDatatype::Char),
+            field(38, "OrderQty", FixDatatype::UtcTimestamp),
+            field(52, "SendingTime", FixDatatype::SeqNum),
+            field(95, "RawDataLength", FixDatatype::Data),
+        ]);
+        let fix44 = Dictionary::fix44();
+        let error = Dictionary::union(&[&fix44, &venue]).unwrap_err();
+        let conflict = |tag: u32, existing: FixDatatype, new: FixDatatype| DatatypeConflict {
+            tag: TagU32::new(tag).unwrap(),
+            existing,
+            new,
+            version: "FIX.4.4-Venue".to_string(),
+        };
+        assert_eq!(
+            error.conflicts,
+            vec![
+                conflict(38, FixDatatype::Qty, FixDatatype::UtcTimestamp),
+                conflict(52, FixDatatype::UtcTimestamp, FixDatatype::SeqNum),
+                conflict(95, FixDatatype::Length, FixDatatype::Data),
+            ]
+        );
+    }
+}
@@ -0,0 +1,1063 @@
+use super::*;
+
+/// The definition of a field, for use with [`Dictionary::from_parts`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FieldDef {
+    /// The numeric tag of the field.
+    pub tag: u32,
+    /// The name of the field, e.g. `ClOrdID`.
+    pub name: String,
+    /// The datatype of the field. It must be listed among the datatypes given
+    /// to [`Dictionary::from_parts`].
+    pub datatype: FixDatatype,
+    /// The code set of the field as value-description pairs, or an empty
+    /// [`Vec`] if the field allows any value.
+    pub enums: Vec<(String, String)>,
+}
+
+/// An entry in the layout of a [`ComponentDef`] or [`MessageDef`]. Fields and
+/// components are referred to by name.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LayoutItemDef {
+    /// A reference to a field.
+    Field { name: String, required: bool },
+    /// A reference to a component.
+    Component { name: String, required: bool },
+    /// A repeating group, announced by the `NumInGroup` field `name`.
+    Group {
+        name: String,
+        required: bool,
+        items: Vec<LayoutItemDef>,
+    },
+}
+
+/// The definition of a component, for use with [`Dictionary::from_parts`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ComponentDef {
+    /// The name of the component. `StandardHeader` and `StandardTrailer` are
+    /// the header and trailer of all messages.
+    pub name: String,
+    /// The name of the category of the component, or an empty string if it
+    /// has none. See [`Component::category`].
+    pub category: String,
+    /// The layout of the component.
+    pub items: Vec<LayoutItemDef>,
+}
+
+/// The definition of a message, for use with [`Dictionary::from_parts`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MessageDef {
+    /// The name of the message, e.g. `NewOrderSingle`.
+    pub name: String,
+    /// The message type, e.g. `D`.
+    pub msg_type: String,
+    /// The name of the category of the message, e.g. `app`.
+    pub category: String,
+    /// The layout of the message body.
+    pub items: Vec<LayoutItemDef>,
+    /// The message types of the messages that are expected in response to
+    /// this message, if any. See [`Dictionary::responses_for`].
+    pub responses: Vec<String>,
+}
+
+impl MessageDef {
+    /// Appends `item` to the layout of the message body.
+    pub fn add_layout_item(&mut self, item: LayoutItemDef) {
+        self.items.push(item);
+    }
+
+    /// Removes the first top-level layout item that refers to the field,
+    /// component, or repeating group `name`, and returns it. Returns `None` if
+    /// there's no such item.
+    pub fn remove_layout_item(&mut self, name: &str) -> Option<LayoutItemDef> {
+        let i = self.items.iter().position(|item| item.name() == name)?;
+        Some(self.items.remove(i))
+    }
+}
+
+impl LayoutItemDef {
+    /// Returns the name of the field, component, or `NumInGroup` field this
+    /// item refers to.
+    pub fn name(&self) -> &str {
+        match self {
+            LayoutItemDef::Field { name, .. } => name,
+            LayoutItemDef::Component { name, .. } => name,
+            LayoutItemDef::Group { name, .. } => name,
+        }
+    }
+}
+
+/// Definitions which can be added to or replaced one at a time, then built
+/// into a [`Dictionary`] with [`Dictionary::from_parts`].
+///
+/// ```
+/// use fefix_dictionary::{Dictionary, DictionaryBuilder, FieldDef, FixDatatype};
+///
+/// let mut builder = DictionaryBuilder::from_dictionary(&Dictionary::fix44());
+/// builder.add_field(FieldDef {
+///     tag: 5001,
+///     name: "VenueOrderTag".to_string(),
+///     datatype: FixDatatype::String,
+///     enums: vec![],
+/// });
+/// let dict = builder.build().unwrap();
+/// assert_eq!(dict.field_by_tag(5001).unwrap().name(), "VenueOrderTag");
+/// ```
+#[derive(Clone, Debug)]
+pub struct DictionaryBuilder {
+    version: String,
+    fields: Vec<FieldDef>,
+    datatypes: Vec<FixDatatype>,
+    components: Vec<ComponentDef>,
+    messages: Vec<MessageDef>,
+}
+
+impl DictionaryBuilder {
+    /// Creates a [`DictionaryBuilder`] for `version` without any definitions.
+    pub fn new(version: &str) -> Self {
+        Self {
+            version: version.to_string(),
+            fields: Vec::new(),
+            datatypes: Vec::new(),
+            components: Vec::new(),
+            messages: Vec::new(),
+        }
+    }
+
+    /// Creates a [`DictionaryBuilder`] with the version and all definitions
+    /// of `dict`, as returned by [`Dictionary::to_parts`].
+    pub fn from_dictionary(dict: &Dictionary) -> Self {
+        let (fields, datatypes, components, messages) = dict.to_parts();
+        Self {
+            version: dict.version().to_string(),
+            fields,
+            datatypes,
+            components,
+            messages,
+        }
+    }
+
+    /// Adds `field`, replacing any field with the same tag.
+    pub fn add_field(&mut self, field: FieldDef) {
+        match self.fields.iter_mut().find(|f| f.tag == field.tag) {
+            Some(f) => *f = field,
+            None => self.fields.push(field),
+        }
+    }
+
+    /// Adds `datatype`, unless it's already there.
+    pub fn add_datatype(&mut self, datatype: FixDatatype) {
+        if !self.datatypes.contains(&datatype) {
+            self.datatypes.push(datatype);
+        }
+    }
+
+    /// Adds `component`, replacing any component with the same name.
+    pub fn add_component(&mut self, component: ComponentDef) {
+        match self
+            .components
+            .iter_mut()
+            .find(|c| c.name == component.name)
+        {
+            Some(c) => *c = component,
+            None => self.components.push(component),
+        }
+    }
+
+    /// Adds `message`, replacing any message with the same message type.
+    pub fn add_message(&mut self, message: MessageDef) {
+        match self
+            .messages
+            .iter_mut()
+            .find(|m| m.msg_type == message.msg_type)
+        {
+            Some(m) => *m = message,
+            None => self.messages.push(message),
+        }
+    }
+
+    /// Builds a [`Dictionary`] out of the definitions added so far. See
+    /// [`Dictionary::from_parts`].
+    pub fn build(&self) -> Result<Dictionary, BuildError> {
+        Dictionary::from_parts(
+            &self.version,
+            &self.fields,
+            &self.datatypes,
+            &self.components,
+            &self.messages,
+        )
+    }
+}
+
+/// The error type that can arise when building a [`Dictionary`] with
+/// [`Dictionary::from_parts`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BuildError {
+    /// Two fields have the same tag.
+    DuplicateTag(u32),
+    /// A field has tag 0, which isn't a valid FIX tag.
+    InvalidTag(u32),
+    /// Two fields, components, or messages have the same name.
+    DuplicateName(String),
+    /// Two messages have the same message type.
+    DuplicateMsgType(String),
+    /// A field has a datatype which wasn't listed.
+    UnknownDatatype(FixDatatype),
+    /// A layout item refers to a field which wasn't defined.
+    UnknownField(String),
+    /// A layout item refers to a component which wasn't defined.
+    UnknownComponent(String),
+    /// A message lists a response with a message type which wasn't defined, or
+    /// [`Dictionary::modify_message`] was given an unknown message type.
+    UnknownMsgType(String),
+    /// Some components include themselves, directly or through other
+    /// components. See [`Dictionary::component_recursion_check`].
+    CircularComponents(Vec<String>),
+}
+
+impl Dictionary {
+    /// Builds a [`Dictionary`] named `version` out of complete lists of its
+    /// definitions, without going through a specification file. All references
+    /// between definitions are validated. The result is the same as parsing an
+    /// equivalent QuickFIX specification with [`Dictionary::from_quickfix_spec`];
+    /// in particular, empty `StandardHeader` and `StandardTrailer` components
+    /// are added if they're missing from `components`.
+    ///
+    /// ```
+    /// use fefix_dictionary::{Dictionary, FieldDef, FixDatatype, LayoutItemDef, MessageDef};
+    ///
+    /// let fields = [FieldDef {
+    ///     tag: 112,
+    ///     name: "TestReqID".to_string(),
+    ///     datatype: FixDatatype::String,
+    ///     enums: vec![],
+    /// }];
+    /// let messages = [MessageDef {
+    ///     name: "Heartbeat".to_string(),
+    ///     msg_type: "0".to_string(),
+    ///     category: "admin".to_string(),
+    ///     items: vec![LayoutItemDef::Field {
+    ///         name: "TestReqID".to_string(),
+    ///         required: false,
+    ///     }],
+    ///     responses: vec![],
+    /// }];
+    /// let dict =
+    ///     Dictionary::from_parts("FIX.4.4", &fields, &[FixDatatype::String], &[], &messages)
+    ///         .unwrap();
+    /// assert_eq!(dict.message_by_name("Heartbeat").unwrap().msg_type(), "0");
+    /// ```
+    pub fn from_parts(
+        version: &str,
+        fields: &[FieldDef],
+        datatypes: &[FixDatatype],
+        components: &[ComponentDef],
+        messages: &[MessageDef],
+    ) -> Result<Self, BuildError> {
+        let mut dict = Dictionary::new(version);
+        for datatype in datatypes {
+            dict.add_datatype(DatatypeData {
+                datatype: *datatype,
+                description: String::new(),
+                examples: Vec::new(),
+            });
+        }
+        for field in fields {
+            if field.tag == 0 {
+                return Err(BuildError::InvalidTag(field.tag));
+            }
+            if dict.fields_by_tags.contains_key(&field.tag) {
+                return Err(BuildError::DuplicateTag(field.tag));
+            }
+            if dict.field_tags_by_name.contains_key(field.name.as_str()) {
+                return Err(BuildError::DuplicateName(field.name.clone()));
+            }
+            if !datatypes.contains(&field.datatype) {
+                return Err(BuildError::UnknownDatatype(field.datatype));
+            }
+            let value_restrictions = field
+                .enums
+                .iter()
+                .map(|(value, description)| FieldEnumData {
+                    value: value.clone(),
+                    description: description.clone(),
+                })
+                .collect::<Vec<_>>();
+            dict.add_field(FieldData {
+                name: field.name.as_str().into(),
+                tag: field.tag,
+                data_type_name: field.datatype.name().into(),
+                associated_data_tag: None,
+                value_restrictions: Some(value_restrictions).filter(|v| !v.is_empty()),
+                required: true,
+                abbr_name: None,
+                base_category_abbr_name: None,
+                base_category_id: None,
+                description: None,
+                extra_attributes: FnvHashMap::default(),
+            });
+        }
+        let component_names = components
+            .iter()
+            .map(|component| component.name.as_str())
+            .chain(["StandardHeader", "StandardTrailer"])
+            .collect::<FnvHashSet<&str>>();
+        for component in components {
+            if dict
+                .components_by_name
+                .contains_key(component.name.as_str())
+            {
+                return Err(BuildError::DuplicateName(component.name.clone()));
+            }
+            let layout_items = layout_items(&dict, &component_names, &component.items)?;
+            if !component.category.is_empty()
+                && dict.category_by_name(&component.category).is_none()
+            {
+                dict.add_category(CategoryData {
+                    name: component.category.clone(),
+                    fixml_filename: String::new(),
+                });
+            }
+            dict.add_component(ComponentData {
+                id: 0,
+                component_type: FixmlComponentAttributes::Block {
+                    is_implicit: false,
+                    is_repeating: false,
+                    is_optimized: false,
+                },
+                layout_items,
+                category_name: component.category.as_str().into(),
+                name: component.name.as_str().into(),
+                abbr_name: None,
+            });
+        }
+        for name in ["StandardHeader", "StandardTrailer"] {
+            if !dict.components_by_name.contains_key(name) {
+                dict.add_component(ComponentData {
+                    id: 0,
+                    component_type: FixmlComponentAttributes::Block {
+                        is_implicit: false,
+                        is_repeating: false,
+                        is_optimized: false,
+                    },
+                    layout_items: LayoutItems::new(),
+                    category_name: "".into(),
+                    name: name.into(),
+                    abbr_name: None,
+                });
+            }
+        }
+        if let Some(names) = dict.component_recursion_check() {
+            return Err(BuildError::CircularComponents(names));
+        }
+        for message in messages {
+            if dict
+                .messages_by_msgtype
+                .contains_key(message.msg_type.as_str())
+            {
+                return Err(BuildError::DuplicateMsgType(message.msg_type.clone()));
+            }
+            if dict
+                .message_msgtypes_by_name
+                .contains_key(message.name.as_str())
+            {
+                return Err(BuildError::DuplicateName(message.name.clone()));
+            }
+            let layout_items = layout_items(&dict, &component_names, &message.items)?;
+            if dict.category_by_name(&message.category).is_none() {
+                dict.add_category(CategoryData {
+                    name: message.category.clone(),
+                    fixml_filename: String::new(),
+                });
+            }
+            let section_id = sections::category_of_message(&message.name)
+                .and_then(sections::section_of_category)
+                .unwrap_or_default();
+            dict.add_message(MessageData {
+                name: message.name.as_str().into(),
+                msg_type: message.msg_type.as_str().into(),
+                component_id: 0,
+                category_name: message.category.as_str().into(),
+                section_id: section_id.to_string(),
+                layout_items,
+                abbr_name: None,
+                required: true,
+                elaboration: None,
+                description: String::new(),
+                base_message: None,
+                responses: message
+                    .responses
+                    .iter()
+                    .map(|msg_type| msg_type.as_str().into())
+                    .collect(),
+            });
+        }
+        for message in messages {
+            for msg_type in message.responses.iter() {
+                if !dict.messages_by_msgtype.contains_key(msg_type.as_str()) {
+                    return Err(BuildError::UnknownMsgType(msg_type.clone()));
+                }
+            }
+        }
+        dict.index_layouts();
+        Ok(dict)
+    }
+
+    /// Decomposes `self` into the arguments of [`Dictionary::from_parts`]:
+    /// fields, datatypes, components (including `StandardHeader` and
+    /// `StandardTrailer`), and messages, in this order. Definitions are sorted
+    /// by tag, name, name, and message type respectively.
+    ///
+    /// Abbreviated names, extra field attributes, and base messages can't be
+    /// expressed as parts, so they're lost when rebuilding the [`Dictionary`].
+    ///
+    /// ```
+    /// use fefix_dictionary::Dictionary;
+    ///
+    /// let dict = Dictionary::fix44();
+    /// let (fields, datatypes, components, messages) = dict.to_parts();
+    /// let rebuilt =
+    ///     Dictionary::from_parts(dict.version(), &fields, &datatypes, &components, &messages)
+    ///         .unwrap();
+    /// assert_eq!(rebuilt.to_quickfix_xml(), dict.to_quickfix_xml());
+    /// ```
+    pub fn to_parts(
+        &self,
+    ) -> (
+        Vec<FieldDef>,
+        Vec<FixDatatype>,
+        Vec<ComponentDef>,
+        Vec<MessageDef>,
+    ) {
+        let mut fields = self
+            .fields_by_tags
+            .values()
+            .map(|field| field_def(self, field))
+            .collect::<Vec<_>>();
+        fields.sort_by_key(|field| field.tag);
+        let mut datatypes = self
+            .data_types_by_name
+            .values()
+            .map(|datatype| datatype.datatype)
+            .collect::<Vec<_>>();
+        datatypes.sort_by_key(|datatype| datatype.name());
+        let mut components = self
+            .components_by_name
+            .values()
+            .map(|component| component_def(self, component))
+            .collect::<Vec<_>>();
+        components.sort_by(|a, b| a.name.cmp(&b.name));
+        let mut messages = self
+            .messages_by_msgtype
+            .values()
+            .map(|message| message_def(self, message))
+            .collect::<Vec<_>>();
+        messages.sort_by(|a, b| a.msg_type.cmp(&b.msg_type));
+        (fields, datatypes, components, messages)
+    }
+
+    /// Applies `f` to the definition of the message `msg_type` and replaces
+    /// the message with the result, validating all references as
+    /// [`Dictionary::from_parts`] does. If the message type changes, the
+    /// responses of other messages are updated accordingly. On error, `self`
+    /// is left untouched.
+    ///
+    /// ```
+    /// use fefix_dictionary::{Dictionary, LayoutItemDef};
+    ///
+    /// let mut dict = Dictionary::fix44();
+    /// dict.modify_message("D", |message| {
+    ///     message.remove_layout_item("Parties");
+    ///     message.add_layout_item(LayoutItemDef::Field {
+    ///         name: "Text".to_string(),
+    ///         required: true,
+    ///     });
+    /// })
+    /// .unwrap();
+    /// let message = dict.message_by_msgtype("D").unwrap();
+    /// assert!(message.layout().all(|item| item.tag_text() != "Parties"));
+    /// assert_eq!(message.layout().last().unwrap().tag_text(), "Text");
+    /// ```
+    pub fn modify_message<F>(&mut self, msg_type: &str, f: F) -> Result<(), BuildError>
+    where
+        F: FnOnce(&mut MessageDef),
+    {
+        let old = self
+            .messages_by_msgtype
+            .get(msg_type)
+            .ok_or_else(|| BuildError::UnknownMsgType(msg_type.to_string()))?;
+        let mut message = message_def(self, old);
+        f(&mut message);
+        if message.msg_type != msg_type
+            && self
+                .messages_by_msgtype
+                .contains_key(message.msg_type.as_str())
+        {
+            return Err(BuildError::DuplicateMsgType(message.msg_type));
+        }
+        if message.name != old.name.as_str()
+            && self
+                .message_msgtypes_by_name
+                .contains_key(message.name.as_str())
+        {
+            return Err(BuildError::DuplicateName(message.name));
+        }
+        let component_names = self
+            .components_by_name
+            .keys()
+            .map(|name| name.as_str())
+            .collect::<FnvHashSet<&str>>();
+        let layout_items = layout_items(self, &component_names, &message.items)?;
+        for response in message.responses.iter() {
+            if response != &message.msg_type
+                && (response == msg_type
+                    || !self.messages_by_msgtype.contains_key(response.as_str()))
+            {
+                return Err(BuildError::UnknownMsgType(response.clone()));
+            }
+        }
+        let mut data = self.remove_message_data(msg_type);
+        if data.name != message.name.as_str() {
+            data.section_id = sections::category_of_message(&message.name)
+                .and_then(sections::section_of_category)
+                .unwrap_or_default()
+                .to_string();
+        }
+        data.name = message.name.as_str().into();
+        data.msg_type = message.msg_type.as_str().into();
+        data.category_name = message.category.as_str().into();
+        data.layout_items = layout_items;
+        data.responses = message
+            .responses
+            .iter()
+            .map(|msg_type| msg_type.as_str().into())
+            .collect();
+        if self.category_by_name(&message.category).is_none() {
+            self.add_category(CategoryData {
+                name: message.category.clone(),
+                fixml_filename: String::new(),
+            });
+        }
+        for other in self.messages_by_msgtype.values_mut() {
+            for response in other.responses.iter_mut() {
+                if response.as_str() == msg_type {
+                    *response = message.msg_type.as_str().into();
+                }
+            }
+        }
+        self.add_message(data);
+        self.index_layouts();
+        Ok(())
+    }
+
+    /// Removes the first top-level layout item of the message `msg_type` that
+    /// refers to the field `tag`, i.e. the field itself or the repeating
+    /// group it announces, and returns it. Returns `Ok(None)` if there's no
+    /// such item. See [`Dictionary::modify_message`].
+    ///
+    /// ```
+    /// use fefix_dictionary::{Dictionary, TagU32};
+    ///
+    /// let mut dict = Dictionary::fix44();
+    /// let text = TagU32::new(58).unwrap();
+    /// let removed = dict.remove_layout_item_by_tag("D", text).unwrap();
+    /// assert_eq!(removed.unwrap().name(), "Text");
+    /// assert!(dict.remove_layout_item_by_tag("D", text).unwrap().is_none());
+    /// ```
+    pub fn remove_layout_item_by_tag(
+        &mut self,
+        msg_type: &str,
+        tag: TagU32,
+    ) -> Result<Option<LayoutItemDef>, BuildError> {
+        let field_name = self.field_by_tag(tag.get()).map(|f| f.name().to_string());
+        let mut removed = None;
+        self.modify_message(msg_type, |message| {
+            let i = message.items.iter().position(|item| match item {
+                LayoutItemDef::Field { name, .. } | LayoutItemDef::Group { name, .. } => {
+                    Some(name) == field_name.as_ref()
+                }
+                LayoutItemDef::Component { .. } => false,
+            });
+            removed = i.map(|i| message.items.remove(i));
+        })?;
+        Ok(removed)
+    }
+}
+
+/// Converts `component`, which must belong to `dict`, into a
+/// [`ComponentDef`].
+pub(crate) fn component_def(dict: &Dictionary, component: &ComponentData) -> ComponentDef {
+    ComponentDef {
+        name: component.name.to_string(),
+        category: component.category_name.to_string(),
+        items: layout_item_defs(dict, &component.layout_items),
+    }
+}
+
+/// Converts `message`, which must belong to `dict`, into a [`MessageDef`].
+pub(crate) fn message_def(dict: &Dictionary, message: &MessageData) -> MessageDef {
+    MessageDef {
+        name: message.name.to_string(),
+        msg_type: message.msg_type.to_string(),
+        category: message.category_name.to_string(),
+        items: layout_item_defs(dict, &message.layout_items),
+        responses: message.responses.iter().map(|t| t.to_string()).collect(),
+    }
+}
+
+fn layout_item_defs(dict: &Dictionary, items: &[LayoutItemData]) -> Vec<LayoutItemDef> {
+    let field_name = |tag: &u32| dict.fields_by_tags[tag].name.to_string();
+    items
+        .iter()
+        .map(|item| {
+            let required = item.required;
+            match &item.kind {
+                LayoutItemKindData::Field { tag } => LayoutItemDef::Field {
+                    name: field_name(tag),
+                    required,
+                },
+                LayoutItemKindData::Component { name } => LayoutItemDef::Component {
+                    name: name.to_string(),
+                    required,
+                },
+                LayoutItemKindData::Group {
+                    len_field_tag,
+                    items,
+                } => LayoutItemDef::Group {
+                    name: field_name(len_field_tag),
+                    required,
+                    items: layout_item_defs(dict, items),
+                },
+            }
+        })
+        .collect()
+}
+
+fn layout_items(
+    dict: &Dictionary,
+    component_names: &FnvHashSet<&str>,
+    items: &[LayoutItemDef],
+) -> Result<LayoutItems, BuildError> {
+    let field_tag = |name: &str| {
+        dict.field_tags_by_name
+            .get(name)
+            .copied()
+            .ok_or_else(|| BuildError::UnknownField(name.to_string()))
+    };
+    items
+        .iter()
+        .map(|item| {
+            let (required, kind) = match item {
+                LayoutItemDef::Field { name, required } => {
+                    let tag = field_tag(name)?;
+                    (*required, LayoutItemKindData::Field { tag })
+                }
+                LayoutItemDef::Component { name, required } => {
+                    if !component_names.contains(name.as_str()) {
+                        return Err(BuildError::UnknownComponent(name.clone()));
+                    }
+                    let name = name.as_str().into();
+                    (*required, LayoutItemKindData::Component { name })
+                }
+                LayoutItemDef::Group {
+                    name,
+                    required,
+                    items,
+                } => {
+                    let len_field_tag = field_tag(name)?;
+                    let items = layout_items(dict, component_names, items)?;
+                    let kind = LayoutItemKindData::Group {
+                        len_field_tag,
+                        items,
+                    };
+                    (*required, kind)
+                }
+            };
+            Ok(LayoutItemData { required, kind })
+        })
+        .collect()
+}
+
+/// Converts `field`, which must belong to `dict`, into a [`FieldDef`].
+pub(crate) fn field_def(dict: &Dictionary, field: &FieldData) -> FieldDef {
+    FieldDef {
+        tag: field.tag,
+        name: field.name.to_string(),
+        datatype: dict.data_types_by_name[&field.data_type_name].datatype,
+        enums: field
+            .value_restrictions
+            .iter()
+            .flatten()
+            .map(|e| (e.value.clone(), e.description.clone()))
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn field(tag: u32, name: &str, datatype: FixDatatype) -> FieldDef {
+        FieldDef {
+            tag,
+            name: name.to_string(),
+            datatype,
+            enums: vec![],
+        }
+    }
+
+    fn item(name: &str, required: bool) -> LayoutItemDef {
+        LayoutItemDef::Field {
+            name: name.to_string(),
+            required,
+        }
+    }
+
+    fn parts() -> (Vec<FieldDef>, Vec<ComponentDef>, Vec<MessageDef>) {
+        let mut side = field(54, "Side", FixDatatype::Char);
+        side.enums = vec![
+            ("1".to_string(), "BUY".to_string()),
+            ("2".to_string(), "SELL".to_string()),
+        ];
+        let fields = vec![
+            field(8, "BeginString", FixDatatype::String),
+            field(10, "CheckSum", FixDatatype::String),
+            field(11, "ClOrdID", FixDatatype::String),
+            field(55, "Symbol", FixDatatype::String),
+            side,
+            field(448, "PartyID", FixDatatype::String),
+            field(453, "NoPartyIDs", FixDatatype::NumInGroup),
+        ];
+        let components = vec![
+            ComponentDef {
+                name: "StandardHeader".to_string(),
+                category: String::new(),
+                items: vec![item("BeginString", true)],
+            },
+            ComponentDef {
+                name: "StandardTrailer".to_string(),
+                category: String::new(),
+                items: vec![item("CheckSum", true)],
+            },
+            ComponentDef {
+                name: "Parties".to_string(),
+                category: String::new(),
+                items: vec![LayoutItemDef::Group {
+                    name: "NoPartyIDs".to_string(),
+                    required: false,
+                    items: vec![item("PartyID", false)],
+                }],
+            },
+        ];
+        let messages = vec![MessageDef {
+            name: "NewOrderSingle".to_string(),
+            msg_type: "D".to_string(),
+            category: "app".to_string(),
+            items: vec![
+                item("ClOrdID", true),
+                LayoutItemDef::Component {
+                    name: "Parties".to_string(),
+                    required: false,
+                },
+                item("Symbol", true),
+                item("Side", true),
+            ],
+            responses: vec![],
+        }];
+        (fields, components, messages)
+    }
+
+    const DATATYPES: &[FixDatatype] = &[
+        FixDatatype::String,
+        FixDatatype::Char,
+        FixDatatype::NumInGroup,
+    ];
+
+    const SPEC: &str = r#"
+<fix type='FIX' major='4' minor='4' servicepack='0'>
+    <header>
+        <field name='BeginString' required='Y' />
+    </header>
+    <messages>
+        <message name='NewOrderSingle' msgtype='D' msgcat='app'>
+            <field name='ClOrdID' required='Y' />
+            <component name='Parties' required='N' />
+            <field name='Symbol' required='Y' />
+            <field name='Side' required='Y' />
+        </message>
+    </messages>
+    <trailer>
+        <field name='CheckSum' required='Y' />
+    </trailer>
+    <components>
+        <component name='Parties'>
+            <group name='NoPartyIDs' required='N'>
+                <field name='PartyID' required='N' />
+            </group>
+        </component>
+    </components>
+    <fields>
+        <field number='8' name='BeginString' type='STRING' />
+        <field number='10' name='CheckSum' type='STRING' />
+        <field number='11' name='ClOrdID' type='STRING' />
+        <field number='54' name='Side' type='CHAR'>
+            <value enum='1' description='BUY' />
+            <value enum='2' description='SELL' />
+        </field>
+        <field number='55' name='Symbol' type='STRING' />
+        <field number='448' name='PartyID' type='STRING' />
+        <field number='453' name='NoPartyIDs' type='NUMINGROUP' />
+    </fields>
+</fix>
+"#;
+
+    #[test]
+    fn from_parts_is_the_same_as_parsing() {
+        let (fields, components, messages) = parts();
+        let dict =
+            Dictionary::from_parts("FIX.4.4", &fields, DATATYPES, &components, &messages).unwrap();
+        let parsed = Dictionary::from_quickfix_spec(SPEC).unwrap();
+        assert_eq!(dict.to_quickfix_xml(), parsed.to_quickfix_xml());
+        assert_eq!(dict.stats(), parsed.stats());
+    }
+
+    #[test]
+    fn from_parts_with_standard_header_trailer() {
+        let (fields, mut components, messages) = parts();
+        components.retain(|c| c.name != "StandardTrailer");
+        let mut dict =
+            Dictionary::from_parts("FIX.4.4", &fields, DATATYPES, &components, &messages).unwrap();
+        let tags = |tags: Vec<TagU32>| tags.iter().map(|t| t.get()).collect::<Vec<_>>();
+        assert_eq!(tags(dict.framing_spec().leading_tags), &[8]);
+        assert!(dict.framing_spec().trailing_tags.is_empty());
+        assert_eq!(tags(dict.ensure_standard_header_trailer()), &[9, 35, 10]);
+        let framing = dict.framing_spec();
+        assert_eq!(tags(framing.leading_tags), &[8, 9, 35]);
+        assert_eq!(tags(framing.trailing_tags), &[10]);
+        let body_length = dict.field_by_name("BodyLength").unwrap();
+        assert_eq!(body_length.fix_datatype(), FixDatatype::Length);
+        assert!(dict.ensure_standard_header_trailer().is_empty());
+        assert_eq!(
+            dict.full_message_layout("D").unwrap().len(),
+            messages[0].items.len() + 4
+        );
+    }
+
+    #[test]
+    fn parts_round_trip() {
+        let mut dicts = Dictionary::common_dictionaries();
+        dicts.push(Dictionary::fix44());
+        for dict in dicts {
+            let (fields, datatypes, components, messages) = dict.to_parts();
+            let rebuilt =
+                Dictionary::from_parts(dict.version(), &fields, &datatypes, &components, &messages)
+                    .unwrap();
+            assert_eq!(rebuilt.to_quickfix_xml(), dict.to_quickfix_xml());
+            assert_eq!(rebuilt.stats(), dict.stats());
+            assert_eq!(rebuilt.to_parts().0.len(), fields.len());
+        }
+    }
+
+    #[test]
+    fn from_parts_validates_references() {
+        let (fields, mut components, messages) = parts();
+        let build = |fields: &[FieldDef], components: &[ComponentDef], messages: &[MessageDef]| {
+            Dictionary::from_parts("FIX.4.4", fields, DATATYPES, components, messages)
+        };
+        assert_eq!(
+            build(&fields, &[], &messages).unwrap_err(),
+            BuildError::UnknownComponent("Parties".to_string())
+        );
+        assert_eq!(
+            build(&fields[1..], &components, &messages).unwrap_err(),
+            BuildError::UnknownField("BeginString".to_string())
+        );
+        let mut duplicate = fields.clone();
+        duplicate.push(field(11, "ClOrdID2", FixDatatype::String));
+        assert_eq!(
+            build(&duplicate, &components, &messages).unwrap_err(),
+            BuildError::DuplicateTag(11)
+        );
+        let mut zero = fields.clone();
+        zero.push(field(0, "Zero", FixDatatype::String));
+        assert_eq!(
+            build(&zero, &components, &messages).unwrap_err(),
+            BuildError::InvalidTag(0)
+        );
+        let mut float = fields.clone();
+        float.push(field(44, "Price", FixDatatype::Price));
+        assert_eq!(
+            build(&float, &components, &messages).unwrap_err(),
+            BuildError::UnknownDatatype(FixDatatype::Price)
+        );
+        let mut circular = components.clone();
+        circular[2].items.push(LayoutItemDef::Component {
+            name: "Parties".to_string(),
+            required: false,
+        });
+        assert_eq!(
+            build(&fields, &circular, &messages).unwrap_err(),
+            BuildError::CircularComponents(vec!["Parties".to_string()])
+        );
+        components.retain(|c| c.name == "Parties");
+        let dict = build(&fields, &components, &messages).unwrap();
+        assert_eq!(
+            dict.component_by_name("StandardHeader")
+                .unwrap()
+                .items()
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn from_parts_with_responses() {
+        let (fields, components, mut messages) = parts();
+        messages[0].responses = vec!["8".to_string()];
+        let build = |messages: &[MessageDef]| {
+            Dictionary::from_parts("FIX.4.4", &fields, DATATYPES, &components, messages)
+        };
+        assert_eq!(
+            build(&messages).unwrap_err(),
+            BuildError::UnknownMsgType("8".to_string())
+        );
+        messages.push(MessageDef {
+            name: "ExecutionReport".to_string(),
+            msg_type: "8".to_string(),
+            category: "app".to_string(),
+            items: vec![item("ClOrdID", true)],
+            responses: vec![],
+        });
+        let mut dict = build(&messages).unwrap();
+        let responses = dict.responses_for("D");
+        assert_eq!(responses.len(), 1);
+        assert_eq!(responses[0].name(), "ExecutionReport");
+        assert!(dict.responses_for("8").is_empty());
+        assert!(dict.responses_for("Z").is_empty());
+        let message = dict.message_by_msgtype("D").unwrap();
+        assert_eq!(message.response_msg_types().collect::<Vec<_>>(), vec!["8"]);
+
+        dict.remove_message("8").unwrap();
+        assert!(dict.responses_for("D").is_empty());
+        let message = dict.message_by_msgtype("D").unwrap();
+        assert_eq!(message.response_msg_types().count(), 0);
+        let (fields, datatypes, components, messages) = dict.to_parts();
+        assert!(
+            Dictionary::from_parts("FIX.4.4", &fields, &datatypes, &components, &messages).is_ok()
+        );
+    }
+
+    #[test]
+    fn builder_replaces_definitions() {
+        let (fields, components, messages) = parts();
+        let mut builder = DictionaryBuilder::new("FIX.4.4");
+        for datatype in DATATYPES {
+            builder.add_datatype(*datatype);
+        }
+        for field in fields {
+            builder.add_field(field);
+        }
+        for component in components {
+            builder.add_component(component);
+        }
+        builder.add_message(messages[0].clone());
+        let dict = builder.build().unwrap();
+        let mut builder = DictionaryBuilder::from_dictionary(&dict);
+        builder.add_field(field(11, "OrderTag", FixDatatype::String));
+        assert_eq!(
+            builder.build().unwrap_err(),
+            BuildError::UnknownField("ClOrdID".to_string())
+        );
+        let mut message = messages[0].clone();
+        message.items.remove(0);
+        builder.add_message(message);
+        let rebuilt = builder.build().unwrap();
+        assert_eq!(rebuilt.field_by_tag(11).unwrap().name(), "OrderTag");
+        assert_eq!(rebuilt.message_by_msgtype("D").unwrap().layout().count(), 3);
+        assert_eq!(rebuilt.fields().len(), dict.fields().len());
+        assert_eq!(rebuilt.messages().len(), 1);
+    }
+
+    #[test]
+    fn modify_message_validates_and_reindexes() {
+        let (fields, components, mut messages) = parts();
+        messages.push(MessageDef {
+            name: "ExecutionReport".to_string(),
+            msg_type: "8".to_string(),
+            category: "app".to_string(),
+            items: vec![item("ClOrdID", true)],
+            responses: vec!["D".to_string()],
+        });
+        let mut dict =
+            Dictionary::from_parts("FIX.4.4", &fields, DATATYPES, &components, &messages).unwrap();
+        assert_eq!(
+            dict.modify_message("Z", |_| {}).unwrap_err(),
+            BuildError::UnknownMsgType("Z".to_string())
+        );
+        assert_eq!(
+            dict.modify_message("D", |m| m.add_layout_item(item("Price", false)))
+                .unwrap_err(),
+            BuildError::UnknownField("Price".to_string())
+        );
+        assert_eq!(
+            dict.modify_message("D", |m| m.msg_type = "8".to_string())
+                .unwrap_err(),
+            BuildError::DuplicateMsgType("8".to_string())
+        );
+        assert_eq!(dict.message_by_msgtype("D").unwrap().layout().count(), 4);
+        dict.modify_message("D", |m| {
+            assert!(m.remove_layout_item("Parties").is_some());
+            assert!(m.remove_layout_item("Parties").is_none());
+            let symbol = m.remove_layout_item("Symbol").unwrap();
+            m.add_layout_item(symbol);
+        })
+        .unwrap();
+        let message = dict.message_by_msgtype("D").unwrap();
+        let names = message
+            .layout()
+            .map(|item| item.tag_text())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["ClOrdID", "Side", "Symbol"]);
+        dict.modify_message("D", |m| m.msg_type = "DD".to_string())
+            .unwrap();
+        assert!(dict.message_by_msgtype("D").is_none());
+        assert_eq!(
+            dict.message_by_name("NewOrderSingle").unwrap().msg_type(),
+            "DD"
+        );
+        assert_eq!(dict.responses_for("8")[0].msg_type(), "DD");
+    }
+
+    #[test]
+    fn remove_layout_item_by_tag() {
+        let (fields, components, mut messages) = parts();
+        messages[0].items.push(LayoutItemDef::Group {
+            name: "NoPartyIDs".to_string(),
+            required: false,
+            items: vec![item("PartyID", false)],
+        });
+        let mut dict =
+            Dictionary::from_parts("FIX.4.4", &fields, DATATYPES, &components, &messages).unwrap();
+        let tag = |t| TagU32::new(t).unwrap();
+        assert_eq!(
+            dict.remove_layout_item_by_tag("Z", tag(55)).unwrap_err(),
+            BuildError::UnknownMsgType("Z".to_string())
+        );
+        assert_eq!(dict.remove_layout_item_by_tag("D", tag(999)), Ok(None));
+        assert_eq!(
+            dict.remove_layout_item_by_tag("D", tag(55)),
+            Ok(Some(item("Symbol", true)))
+        );
+        assert_eq!(dict.remove_layout_item_by_tag("D", tag(55)), Ok(None));
+        // The group is removed, but the `Parties` component that contains
+        // another one is left alone.
+        let removed = dict.remove_layout_item_by_tag("D", tag(453)).unwrap();
+        assert_eq!(removed.unwrap().name(), "NoPartyIDs");
+        let message = dict.message_by_msgtype("D").unwrap();
+        let names = message
+            .layout()
+            .map(|item| item.tag_text())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["ClOrdID", "Parties", "Side"]);
+    }
+}
@@ -1,4 +1,5 @@
 use super::*;
+use std::{borrow::Cow, io};
 
 pub struct QuickFixReader<'a> {
     node_with_header: roxmltree::Node<'a, 'a>,
@@ -9,27 +10,73 @@ pub struct QuickFixReader<'a> {
     dict: Dictionary,
 }
 
+/// Options for reading QuickFIX specification files. See
+/// [`Dictionary::from_quickfix_spec_with_options`].
+#[derive(Clone, Debug, Default)]
+pub struct QuickFixOptions {
+    /// If `true`, entries of the `<fields>` section that can't be parsed (e.g.
+    /// non-standard `<component>` references) are skipped instead of failing
+    /// the whole specification file. Each skipped entry is reported as a
+    /// warning.
+    pub skip_unparseable_fields: bool,
+}
+
+/// A step of reading a QuickFIX specification file. See
+/// [`Dictionary::from_quickfix_spec_with_progress`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ParsePhase {
+    /// Checking that component references are valid and acyclic.
+    CheckingComponents,
+    /// Importing the entries of the `<fields>` section.
+    Fields,
+    /// Importing the entries of the `<components>` section.
+    Components,
+    /// Importing the entries of the `<messages>` section.
+    Messages,
+    /// Building lookup tables over the imported definitions.
+    Indexing,
+}
+
 impl<'a> QuickFixReader<'a> {
-    pub fn new(xml_document: &'a roxmltree::Document<'a>) -> ParseResult<Dictionary> {
-        let mut reader = Self::empty(xml_document)?;
-        for child in reader.node_with_fields.children() {
-            if child.is_element() {
-                import_field(&mut reader.dict, child)?;
+    /// Reads `xml_document` into a [`Dictionary`]. Its version is taken from
+    /// the root element's attributes unless `version` is given. Errors that
+    /// `options` allow to recover from are returned as warnings.
+    ///
+    /// `on_progress` is called with the number of completed and total steps
+    /// at the start of each [`ParsePhase`] and after every step.
+    pub fn new(
+        xml_document: &'a roxmltree::Document<'a>,
+        version: Option<&str>,
+        options: &QuickFixOptions,
+        on_progress: &mut dyn FnMut(ParsePhase, usize, usize),
+    ) -> ParseResult<(Dictionary, Vec<ParseDictionaryError>)> {
+        let mut reader = Self::empty(xml_document, version)?;
+        on_progress(ParsePhase::CheckingComponents, 0, 1);
+        reader.check_component_references()?;
+        on_progress(ParsePhase::CheckingComponents, 1, 1);
+        let mut warnings = Vec::new();
+        let fields = elements(reader.node_with_fields);
+        on_progress(ParsePhase::Fields, 0, fields.len());
+        for (i, child) in fields.iter().enumerate() {
+            match import_field(&mut reader.dict, *child) {
+                Ok(()) => {}
+                Err(err) if options.skip_unparseable_fields => warnings.push(err),
+                Err(err) => return Err(err),
             }
+            on_progress(ParsePhase::Fields, i + 1, fields.len());
         }
-        for child in reader.node_with_components.children() {
-            if child.is_element() {
-                let name = child
-                    .attribute("name")
-                    .ok_or(ParseDictionaryError::InvalidFormat)?
-                    .to_string();
-                import_component(&mut reader.dict, child, &name)?;
-            }
+        let components = elements(reader.node_with_components);
+        on_progress(ParsePhase::Components, 0, components.len());
+        for (i, child) in components.iter().enumerate() {
+            let name = required_attribute(*child, "name")?.to_string();
+            import_component(&mut reader.dict, *child, &name)?;
+            on_progress(ParsePhase::Components, i + 1, components.len());
         }
-        for child in reader.node_with_messages.children() {
-            if child.is_element() {
-                import_message(&mut reader.dict, child)?;
-            }
+        let messages = elements(reader.node_with_messages);
+        on_progress(ParsePhase::Messages, 0, messages.len());
+        for (i, child) in messages.iter().enumerate() {
+            import_message(&mut reader.dict, *child)?;
+            on_progress(ParsePhase::Messages, i + 1, messages.len());
         }
         // `StandardHeader` and `StandardTrailer` are defined in ad-hoc
         // sections of the XML files. They're always there, even if
@@ -40,11 +87,22 @@ impl<'a> QuickFixReader<'a> {
             reader.node_with_trailer,
             "StandardTrailer",
         )?;
-        Ok(reader.dict)
+        on_progress(ParsePhase::Indexing, 0, 1);
+        reader.dict.index_layouts();
+        on_progress(ParsePhase::Indexing, 1, 1);
+        Ok((reader.dict, warnings))
     }
 
-    fn empty(xml_document: &'a roxmltree::Document<'a>) -> ParseResult<Self> {
+    fn empty(
+        xml_document: &'a roxmltree::Document<'a>,
+        version: Option<&str>,
+    ) -> ParseResult<Self> {
         let root = xml_document.root_element();
+        if !matches!(root.tag_name().name(), "fix" | "fixt") {
+            return Err(ParseDictionaryError::UnexpectedRoot {
+                found: root.tag_name().name().to_string(),
+            });
+        }
         let find_tagged_child = |tag: &str| {
             root.children()
                 .find(|n| n.has_tag_name(tag))
@@ -52,34 +110,10 @@ impl<'a> QuickFixReader<'a> {
                     ParseDictionaryError::InvalidData(format!("<{}> tag not found", tag))
                 })
         };
-        let version_type = root
-            .attribute("type")
-            .ok_or(ParseDictionaryError::InvalidData(
-                "No version attribute.".to_string(),
-            ))?;
-        let version_major = root
-            .attribute("major")
-            .ok_or(ParseDictionaryError::InvalidData(
-                "No major version attribute.".to_string(),
-            ))?;
-        let version_minor = root
-            .attribute("minor")
-            .ok_or(ParseDictionaryError::InvalidData(
-                "No minor version attribute.".to_string(),
-            ))?;
-        let version_sp = root.attribute("servicepack").unwrap_or("0");
-        let version = format!(
-            "{}.{}.{}{}",
-            version_type,
-            version_major,
-            version_minor,
-            // Omit Service Pack ID if set to zero.
-            if version_sp != "0" {
-                format!("-SP{}", version_sp)
-            } else {
-                String::new()
-            }
-        );
+        let version = match version {
+            Some(version) => version.to_string(),
+            None => version_from_attributes(root)?,
+        };
         Ok(QuickFixReader {
             dict: Dictionary::new(version),
             node_with_header: find_tagged_child("header")?,
@@ -91,32 +125,163 @@ impl<'a> QuickFixReader<'a> {
     }
 }
 
+/// Returns the child elements of `node`.
+fn elements<'a, 'input>(node: roxmltree::Node<'a, 'input>) -> Vec<roxmltree::Node<'a, 'input>> {
+    node.children().filter(|child| child.is_element()).collect()
+}
+
+impl<'a> QuickFixReader<'a> {
+    /// Checks that all component references point to a component definition
+    /// and that no component includes itself, directly or not. Placeholders
+    /// for components that are referenced before being defined get replaced
+    /// by their definitions later on, so this can't be checked while
+    /// importing layouts.
+    fn check_component_references(&self) -> ParseResult<()> {
+        let mut definitions = FnvHashMap::default();
+        for node in self.node_with_components.children() {
+            if node.is_element() {
+                definitions.insert(required_attribute(node, "name")?, node);
+            }
+        }
+        let sections = [
+            self.node_with_header,
+            self.node_with_trailer,
+            self.node_with_messages,
+            self.node_with_components,
+        ];
+        for section in sections {
+            for node in section.descendants() {
+                if is_component_reference(node) && node.parent() != Some(self.node_with_components)
+                {
+                    let name = required_attribute(node, "name")?;
+                    if !definitions.contains_key(name) {
+                        return Err(ParseDictionaryError::UnknownComponent {
+                            name: name.to_string(),
+                            position: position_of(node),
+                        });
+                    }
+                }
+            }
+        }
+        let mut acyclic = FnvHashSet::default();
+        for (name, node) in definitions.iter() {
+            check_acyclic(&definitions, *node, &mut vec![*name], &mut acyclic)?;
+        }
+        Ok(())
+    }
+}
+
+fn is_component_reference(node: roxmltree::Node) -> bool {
+    node.is_element() && node.has_tag_name("component")
+}
+
+/// Checks that the component definition `node` doesn't include any of the
+/// components in `path`, i.e. the chain of components that include it.
+/// Components in `acyclic` are known not to include themselves.
+fn check_acyclic<'a>(
+    definitions: &FnvHashMap<&'a str, roxmltree::Node<'a, 'a>>,
+    node: roxmltree::Node<'a, 'a>,
+    path: &mut Vec<&'a str>,
+    acyclic: &mut FnvHashSet<&'a str>,
+) -> ParseResult<()> {
+    for child in node
+        .descendants()
+        .skip(1)
+        .filter(|n| is_component_reference(*n))
+    {
+        let name = required_attribute(child, "name")?;
+        if path.contains(&name) {
+            return Err(ParseDictionaryError::CircularComponent {
+                name: name.to_string(),
+                position: position_of(child),
+            });
+        }
+        if !acyclic.contains(name) {
+            path.push(name);
+            check_acyclic(definitions, definitions[name], path, acyclic)?;
+            path.pop();
+        }
+    }
+    acyclic.insert(path[path.len() - 1]);
+    Ok(())
+}
+
+/// Computes the version of a specification file from the `type`, `major`,
+/// `minor`, and `servicepack` attributes of its root element `root`.
+fn version_from_attributes(root: roxmltree::Node) -> ParseResult<String> {
+    let version_type = required_attribute(root, "type")?;
+    let version_major = root
+        .attribute("major")
+        .ok_or(ParseDictionaryError::InvalidData(
+            "No major version attribute.".to_string(),
+        ))?;
+    let version_minor = root
+        .attribute("minor")
+        .ok_or(ParseDictionaryError::InvalidData(
+            "No minor version attribute.".to_string(),
+        ))?;
+    let version_sp = root.attribute("servicepack").unwrap_or("0");
+    Ok(format!(
+        "{}.{}.{}{}",
+        version_type,
+        version_major,
+        version_minor,
+        // Omit Service Pack ID if set to zero.
+        if version_sp != "0" {
+            format!("-SP{}", version_sp)
+        } else {
+            String::new()
+        }
+    ))
+}
+
+/// Attributes of `<field>` definitions that are part of the QuickFIX format.
+/// Anything else is kept as an extra attribute.
+const FIELD_ATTRIBUTES: &[&str] = &["number", "name", "type", "abbrName", "description"];
+
 fn import_field(builder: &mut Dictionary, node: roxmltree::Node) -> ParseResult<()> {
     if node.tag_name().name() != "field" {
-        return Err(ParseDictionaryError::InvalidFormat);
+        return Err(ParseDictionaryError::UnexpectedElement {
+            found: node.tag_name().name().to_string(),
+            position: position_of(node),
+        });
     }
-    let data_type_name = import_datatype(builder, node);
-    let value_restrictions = value_restrictions_from_node(node, data_type_name.clone());
-    let name = node
-        .attribute("name")
-        .ok_or(ParseDictionaryError::InvalidFormat)?
-        .into();
-    let tag = node
-        .attribute("number")
-        .ok_or(ParseDictionaryError::InvalidFormat)?
-        .parse()
-        .map_err(|_| ParseDictionaryError::InvalidFormat)?;
+    let value_restrictions = value_restrictions_from_node(node)?;
+    let name = required_attribute(node, "name")?.into();
+    let number = required_attribute(node, "number")?;
+    let tag = number.parse().ok().filter(|tag| *tag != 0).ok_or_else(|| {
+        ParseDictionaryError::InvalidFieldTag {
+            number: number.to_string(),
+            position: position_of(node),
+        }
+    })?;
+    if builder.fields_by_tags.contains_key(&tag) {
+        return Err(ParseDictionaryError::DuplicateFieldTag {
+            tag,
+            position: position_of(node),
+        });
+    }
+    // Only add the datatype once the rest of the field is known to be valid.
+    let data_type_name = import_datatype(builder, node)?;
     let field = FieldData {
         name,
         tag,
         data_type_name,
         associated_data_tag: None,
         value_restrictions,
-        required: true,
-        abbr_name: None,
+        // FIX Repository extension; kept among the extra attributes too, so
+        // that it survives a round trip.
+        required: node.attribute("notReqXML") != Some("Y"),
+        abbr_name: node.attribute("abbrName").map(|s| s.to_string()),
         base_category_abbr_name: None,
         base_category_id: None,
-        description: None,
+        description: node.attribute("description").map(|s| s.to_string()),
+        extra_attributes: node
+            .attributes()
+            .iter()
+            .filter(|attr| !FIELD_ATTRIBUTES.contains(&attr.name()))
+            .map(|attr| (attr.name().to_string(), attr.value().to_string()))
+            .collect(),
     };
     builder.add_field(field);
     Ok(())
@@ -124,7 +289,7 @@ fn import_field(builder: &mut Dictionary, node: roxmltree::Node) -> ParseResult<
 
 fn import_message(dict: &mut Dictionary, node: roxmltree::Node) -> ParseResult<()> {
     debug_assert_eq!(node.tag_name().name(), "message");
-    let _category = import_category(dict, node)?;
+    import_category(dict, node)?;
     let mut layout_items = LayoutItems::new();
     for child in node.children() {
         if child.is_element() {
@@ -133,23 +298,34 @@ fn import_message(dict: &mut Dictionary, node: roxmltree::Node) -> ParseResult<(
             layout_items.push(import_layout_item(dict, child)?);
         }
     }
+    let name = required_attribute(node, "name")?;
+    if name.is_empty() {
+        return Err(ParseDictionaryError::EmptyMessageName {
+            position: position_of(node),
+        });
+    }
+    if dict.message_by_name(name).is_some() {
+        return Err(ParseDictionaryError::DuplicateMessageName {
+            name: name.to_string(),
+            position: position_of(node),
+        });
+    }
+    let section_id = sections::category_of_message(name)
+        .and_then(sections::section_of_category)
+        .unwrap_or_default();
     let message = MessageData {
-        name: node
-            .attribute("name")
-            .ok_or(ParseDictionaryError::InvalidFormat)?
-            .into(),
-        msg_type: node
-            .attribute("msgtype")
-            .ok_or(ParseDictionaryError::InvalidFormat)?
-            .into(),
+        name: name.into(),
+        msg_type: required_attribute(node, "msgtype")?.into(),
         component_id: 0,
-        category_name: "FIXME".into(),
-        section_id: String::new(),
+        category_name: required_attribute(node, "msgcat")?.into(),
+        section_id: section_id.to_string(),
         layout_items,
         abbr_name: None,
         required: true,
         elaboration: None,
         description: String::new(),
+        base_message: node.attribute("base").map(|name| name.into()),
+        responses: Vec::new(),
     };
     dict.add_message(message);
     Ok(())
@@ -162,6 +338,14 @@ fn import_component(dict: &mut Dictionary, node: roxmltree::Node, name: &str) ->
             layout_items.push(import_layout_item(dict, child)?);
         }
     }
+    if let Some(category) = node.attribute("category") {
+        if dict.category_by_name(category).is_none() {
+            dict.add_category(CategoryData {
+                name: category.to_string(),
+                fixml_filename: String::new(),
+            });
+        }
+    }
     let component = ComponentData {
         id: 0,
         component_type: FixmlComponentAttributes::Block {
@@ -171,22 +355,27 @@ fn import_component(dict: &mut Dictionary, node: roxmltree::Node, name: &str) ->
             is_optimized: false,
         },
         layout_items,
-        category_name: "".into(), // FIXME
+        category_name: node.attribute("category").unwrap_or_default().into(),
         name: name.into(),
-        abbr_name: None,
+        abbr_name: node.attribute("abbrName").map(|s| s.into()),
     };
     dict.add_component(component);
     Ok(())
 }
 
-fn import_datatype(dict: &mut Dictionary, node: roxmltree::Node) -> SmartString {
+fn import_datatype(dict: &mut Dictionary, node: roxmltree::Node) -> ParseResult<SmartString> {
     // References should only happen at <field> tags.
     debug_assert_eq!(node.tag_name().name(), "field");
     let datatype = {
         // The idenfier that QuickFIX uses for this type.
-        let quickfix_name = node.attribute("type").unwrap();
+        let quickfix_name = required_attribute(node, "type")?;
         // Translate that into a real datatype.
-        FixDatatype::from_quickfix_name(quickfix_name).unwrap()
+        FixDatatype::from_quickfix_name(quickfix_name).ok_or_else(|| {
+            ParseDictionaryError::UnknownDatatype {
+                name: quickfix_name.to_string(),
+                position: position_of(node),
+            }
+        })?
     };
 
     // Get the official (not QuickFIX's) name of `datatype`.
@@ -199,18 +388,15 @@ fn import_datatype(dict: &mut Dictionary, node: roxmltree::Node) -> SmartString
         };
         dict.add_datatype(dt);
     }
-    name.into()
+    Ok(name.into())
 }
 
-fn value_restrictions_from_node(
-    node: roxmltree::Node,
-    _datatype_name: SmartString,
-) -> Option<Vec<FieldEnumData>> {
+fn value_restrictions_from_node(node: roxmltree::Node) -> ParseResult<Option<Vec<FieldEnumData>>> {
     let mut values = Vec::new();
     for child in node.children() {
         if child.is_element() {
-            let variant = child.attribute("enum").unwrap().to_string();
-            let description = child.attribute("description").unwrap().to_string();
+            let variant = required_attribute(child, "enum")?.to_string();
+            let description = required_attribute(child, "description")?.to_string();
             let enum_value = FieldEnumData {
                 value: variant,
                 description,
@@ -219,9 +405,9 @@ fn value_restrictions_from_node(
         }
     }
     if values.is_empty() {
-        None
+        Ok(None)
     } else {
-        Some(values)
+        Ok(Some(values))
     }
 }
 
@@ -229,32 +415,54 @@ fn import_layout_item(dict: &mut Dictionary, node: roxmltree::Node) -> ParseResu
     // This processing step requires on fields being already present in
     // the dictionary.
     debug_assert_ne!(dict.fields().len(), 0);
-    let name = node.attribute("name").unwrap();
-    let required = node.attribute("required").unwrap() == "Y";
+    let name = required_attribute(node, "name")?;
+    let required = required_attribute(node, "required")? == "Y";
     let tag = node.tag_name().name();
+    let field_tag_by_name = |dict: &Dictionary| {
+        dict.field_by_name(name)
+            .map(|field| field.tag().get())
+            .ok_or_else(|| ParseDictionaryError::UnknownField {
+                name: name.to_string(),
+                position: position_of(node),
+            })
+    };
     let kind = match tag {
         "field" => {
-            let field_tag = dict.field_by_name(name).unwrap().tag().get();
+            let field_tag = field_tag_by_name(dict)?;
             LayoutItemKindData::Field { tag: field_tag }
         }
         "component" => {
-            // Components may *not* be already present.
-            import_component(dict, node, name)?;
+            // Components may *not* be already present. If that's the case, we
+            // insert an empty placeholder which will be overwritten once we
+            // get to its definition. We must never overwrite an existing
+            // definition with a mere reference, though.
+            if dict.component_by_name(name).is_none() {
+                import_component(dict, node, name)?;
+            }
             LayoutItemKindData::Component { name: name.into() }
         }
         "group" => {
-            let len_field_tag = dict.field_by_name(name).unwrap().tag().get();
+            let len_field_tag = field_tag_by_name(dict)?;
             let mut items = Vec::new();
             for child in node.children().filter(|n| n.is_element()) {
                 items.push(import_layout_item(dict, child)?);
             }
+            if items.is_empty() {
+                return Err(ParseDictionaryError::EmptyGroup {
+                    name: name.to_string(),
+                    position: position_of(node),
+                });
+            }
             LayoutItemKindData::Group {
                 len_field_tag,
                 items,
             }
         }
         _ => {
-            return Err(ParseDictionaryError::InvalidFormat);
+            return Err(ParseDictionaryError::UnexpectedElement {
+                found: tag.to_string(),
+                position: position_of(node),
+            });
         }
     };
     let item = LayoutItemData { required, kind };
@@ -263,7 +471,7 @@ fn import_layout_item(dict: &mut Dictionary, node: roxmltree::Node) -> ParseResu
 
 fn import_category(dict: &mut Dictionary, node: roxmltree::Node) -> ParseResult<()> {
     debug_assert_eq!(node.tag_name().name(), "message");
-    let name = node.attribute("msgcat").ok_or(ParseError::InvalidFormat)?;
+    let name = required_attribute(node, "msgcat")?;
 
     if dict.category_by_name(name).is_none() {
         dict.add_category(CategoryData {
@@ -275,12 +483,315 @@ fn import_category(dict: &mut Dictionary, node: roxmltree::Node) -> ParseResult<
     Ok(())
 }
 
+/// Serializes `dict` into a QuickFIX-style specification file. Layout items
+/// which refer to fields that aren't in `dict` are left out.
+pub fn write_quickfix_xml(dict: &Dictionary, w: &mut impl io::Write) -> io::Result<()> {
+    let (version_type, version_major, version_minor, version_sp) = version_attributes(dict);
+    writeln!(
+        w,
+        "<fix type='{}' major='{}' minor='{}' servicepack='{}'>",
+        version_type, version_major, version_minor, version_sp
+    )?;
+    write_component_body(dict, "header", "StandardHeader", w)?;
+    writeln!(w, " <messages>")?;
+    let mut messages = dict.messages_by_msgtype.values().collect::<Vec<_>>();
+    messages.sort_by(|a, b| a.msg_type.cmp(&b.msg_type));
+    for message in messages {
+        write!(
+            w,
+            "  <message name='{}' msgtype='{}' msgcat='{}'",
+            escape(&message.name),
+            escape(&message.msg_type),
+            escape(&message.category_name),
+        )?;
+        if let Some(base_message) = &message.base_message {
+            write!(w, " base='{}'", escape(base_message))?;
+        }
+        writeln!(w, ">")?;
+        for item in message.layout_items.iter() {
+            write_layout_item(dict, item, 3, w)?;
+        }
+        writeln!(w, "  </message>")?;
+    }
+    writeln!(w, " </messages>")?;
+    write_component_body(dict, "trailer", "StandardTrailer", w)?;
+    writeln!(w, " <components>")?;
+    let mut components = dict
+        .components_by_name
+        .values()
+        .filter(|c| c.name != "StandardHeader" && c.name != "StandardTrailer")
+        .collect::<Vec<_>>();
+    components.sort_by(|a, b| a.name.cmp(&b.name));
+    for component in components {
+        write!(w, "  <component name='{}'", escape(&component.name))?;
+        if let Some(abbr_name) = &component.abbr_name {
+            write!(w, " abbrName='{}'", escape(abbr_name))?;
+        }
+        if !component.category_name.is_empty() {
+            write!(w, " category='{}'", escape(&component.category_name))?;
+        }
+        writeln!(w, ">")?;
+        for item in component.layout_items.iter() {
+            write_layout_item(dict, item, 3, w)?;
+        }
+        writeln!(w, "  </component>")?;
+    }
+    writeln!(w, " </components>")?;
+    writeln!(w, " <fields>")?;
+    let mut fields = dict.fields_by_tags.values().collect::<Vec<_>>();
+    fields.sort_by_key(|f| f.tag);
+    for field in fields {
+        let datatype_name = match dict.data_types_by_name.get(&field.data_type_name) {
+            Some(datatype) => datatype.datatype.to_quickfix_name(),
+            None => field.data_type_name.as_str(),
+        };
+        write!(
+            w,
+            "  <field number='{}' name='{}' type='{}'",
+            field.tag,
+            escape(&field.name),
+            escape(datatype_name),
+        )?;
+        if let Some(abbr_name) = &field.abbr_name {
+            write!(w, " abbrName='{}'", escape(abbr_name))?;
+        }
+        if let Some(description) = &field.description {
+            write!(w, " description='{}'", escape(description))?;
+        }
+        let mut extra_attributes = field.extra_attributes.iter().collect::<Vec<_>>();
+        extra_attributes.sort();
+        for (name, value) in extra_attributes {
+            write!(w, " {}='{}'", name, escape(value))?;
+        }
+        if let Some(values) = &field.value_restrictions {
+            writeln!(w, ">")?;
+            for value in values {
+                writeln!(
+                    w,
+                    "   <value enum='{}' description='{}' />",
+                    escape(&value.value),
+                    escape(&value.description),
+                )?;
+            }
+            writeln!(w, "  </field>")?;
+        } else {
+            writeln!(w, " />")?;
+        }
+    }
+    writeln!(w, " </fields>")?;
+    writeln!(w, "</fix>")?;
+    Ok(())
+}
+
+/// Splits the version string of `dict` back into the attributes of the root
+/// element, e.g. `FIX.5.0-SP2` becomes `("FIX", "5", "0", "2")`.
+fn version_attributes(dict: &Dictionary) -> (&str, &str, &str, &str) {
+    let (version, sp) = match dict.version().split_once("-SP") {
+        Some((version, sp)) => (version, sp),
+        None => (dict.version(), "0"),
+    };
+    let mut parts = version.splitn(3, '.');
+    let version_type = parts.next().unwrap_or_default();
+    let major = parts.next().unwrap_or_default();
+    let minor = parts.next().unwrap_or_default();
+    (version_type, major, minor, sp)
+}
+
+fn write_component_body(
+    dict: &Dictionary,
+    tag: &str,
+    name: &str,
+    w: &mut impl io::Write,
+) -> io::Result<()> {
+    writeln!(w, " <{}>", tag)?;
+    if let Some(component) = dict.components_by_name.get(name) {
+        for item in component.layout_items.iter() {
+            write_layout_item(dict, item, 2, w)?;
+        }
+    }
+    writeln!(w, " </{}>", tag)
+}
+
+fn write_layout_item(
+    dict: &Dictionary,
+    item: &LayoutItemData,
+    indent: usize,
+    w: &mut impl io::Write,
+) -> io::Result<()> {
+    let required = if item.required { 'Y' } else { 'N' };
+    match &item.kind {
+        LayoutItemKindData::Field { tag } => {
+            let Some(field) = dict.fields_by_tags.get(tag) else {
+                return Ok(());
+            };
+            write!(w, "{:indent$}", "", indent = indent)?;
+            writeln!(
+                w,
+                "<field name='{}' required='{}' />",
+                escape(&field.name),
+                required
+            )?;
+        }
+        LayoutItemKindData::Component { name } => {
+            write!(w, "{:indent$}", "", indent = indent)?;
+            writeln!(
+                w,
+                "<component name='{}' required='{}' />",
+                escape(name),
+                required
+            )?;
+        }
+        LayoutItemKindData::Group {
+            len_field_tag,
+            items,
+        } => {
+            let Some(field) = dict.fields_by_tags.get(len_field_tag) else {
+                return Ok(());
+            };
+            write!(w, "{:indent$}", "", indent = indent)?;
+            writeln!(
+                w,
+                "<group name='{}' required='{}'>",
+                escape(&field.name),
+                required
+            )?;
+            for item in items {
+                write_layout_item(dict, item, indent + 1, w)?;
+            }
+            writeln!(w, "{:indent$}</group>", "", indent = indent)?;
+        }
+    }
+    Ok(())
+}
+
+pub(crate) fn escape(s: &str) -> Cow<'_, str> {
+    match quick_xml::escape::escape(s.as_bytes()) {
+        // `escape` only ever replaces ASCII characters with ASCII entities, so
+        // the output is valid UTF-8 whenever the input is.
+        Cow::Borrowed(_) => Cow::Borrowed(s),
+        Cow::Owned(bytes) => Cow::Owned(String::from_utf8(bytes).unwrap()),
+    }
+}
+
+/// Returns the line and column at which `node` starts inside its XML document.
+fn position_of(node: roxmltree::Node) -> TextPosition {
+    let pos = node.document().text_pos_at(node.range().start);
+    TextPosition {
+        line: pos.row,
+        column: pos.col,
+    }
+}
+
+fn required_attribute<'a>(node: roxmltree::Node<'a, '_>, name: &str) -> ParseResult<&'a str> {
+    node.attribute(name)
+        .ok_or_else(|| ParseDictionaryError::MissingAttribute {
+            element: node.tag_name().name().to_string(),
+            attribute: name.to_string(),
+            position: position_of(node),
+        })
+}
+
 type ParseError = ParseDictionaryError;
 type ParseResult<T> = Result<T, ParseError>;
 
+/// A line and column inside a specification file, both starting from 1.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TextPosition {
+    pub line: u32,
+    pub column: u32,
+}
+
 /// The error type that can arise when decoding a QuickFIX Dictionary.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum ParseDictionaryError {
     InvalidFormat,
     InvalidData(String),
+    /// The root element of the document is neither `<fix>` nor `<fixt>`, so
+    /// it's not a specification file at all (e.g. it's a FIXML message).
+    UnexpectedRoot {
+        /// The name of the actual root element.
+        found: String,
+    },
+    /// An element appears where the QuickFIX format doesn't allow it, e.g. a
+    /// `<component>` inside `<fields>`.
+    UnexpectedElement {
+        /// The name of the element.
+        found: String,
+        position: TextPosition,
+    },
+    /// An element lacks an attribute which the QuickFIX format requires.
+    MissingAttribute {
+        /// The name of the element, e.g. `field`.
+        element: String,
+        /// The name of the missing attribute, e.g. `number`.
+        attribute: String,
+        position: TextPosition,
+    },
+    /// The `number` attribute of a `<field>` definition isn't a valid tag.
+    InvalidFieldTag {
+        number: String,
+        position: TextPosition,
+    },
+    /// Two `<field>` definitions have the same tag.
+    DuplicateFieldTag {
+        tag: u32,
+        position: TextPosition,
+    },
+    /// The `type` attribute of a `<field>` definition isn't a known datatype.
+    UnknownDatatype {
+        name: String,
+        position: TextPosition,
+    },
+    /// A layout item refers to a field which isn't defined.
+    UnknownField {
+        name: String,
+        position: TextPosition,
+    },
+    /// A layout item refers to a component which isn't defined.
+    UnknownComponent {
+        name: String,
+        position: TextPosition,
+    },
+    /// A component includes itself, directly or through other components.
+    CircularComponent {
+        name: String,
+        position: TextPosition,
+    },
+    /// A `<group>` has no items.
+    EmptyGroup {
+        name: String,
+        position: TextPosition,
+    },
+    /// A `<message>` has an empty `name` attribute.
+    EmptyMessageName {
+        position: TextPosition,
+    },
+    /// Two `<message>` definitions have the same name.
+    DuplicateMessageName {
+        name: String,
+        position: TextPosition,
+    },
+}
+
+impl ParseDictionaryError {
+    /// Returns the position of the offending element inside the specification
+    /// file, if known.
+    pub fn position(&self) -> Option<TextPosition> {
+        match self {
+            ParseDictionaryError::InvalidFormat
+            | ParseDictionaryError::InvalidData(_)
+            | ParseDictionaryError::UnexpectedRoot { .. } => None,
+            ParseDictionaryError::UnexpectedElement { position, .. }
+            | ParseDictionaryError::MissingAttribute { position, .. }
+            | ParseDictionaryError::InvalidFieldTag { position, .. }
+            | ParseDictionaryError::DuplicateFieldTag { position, .. }
+            | ParseDictionaryError::UnknownDatatype { position, .. }
+            | ParseDictionaryError::UnknownField { position, .. }
+            | ParseDictionaryError::UnknownComponent { position, .. }
+            | ParseDictionaryError::CircularComponent { position, .. }
+            | ParseDictionaryError::EmptyGroup { position, .. }
+            | ParseDictionaryError::EmptyMessageName { position }
+            | ParseDictionaryError::DuplicateMessageName { position, .. } => Some(*position),
+        }
+    }
 }
@@ -0,0 +1,209 @@
+//! Built-in mapping of standard FIX messages to their categories and
+//! sections, as defined by the FIX Repository (2010 Edition, FIX.5.0SP2).
+//!
+//! QuickFIX specification files only carry a coarse `msgcat` attribute
+//! (`admin` or `app`), so this is used to fill in the missing information.
+
+/// Standard FIX message categories and the section each of them belongs to.
+const CATEGORY_SECTIONS: &[(&str, &str)] = &[
+    ("Allocation", "PostTrade"),
+    ("Application", "Infrastructure"),
+    ("BusinessReject", "Infrastructure"),
+    ("CollateralManagement", "PostTrade"),
+    ("Confirmation", "PostTrade"),
+    ("CrossOrders", "Trade"),
+    ("EventCommunication", "PreTrade"),
+    ("Indication", "PreTrade"),
+    ("MarketData", "PreTrade"),
+    ("MarketStructureReferenceData", "PreTrade"),
+    ("MultilegOrders", "Trade"),
+    ("Network", "Infrastructure"),
+    ("OrderMassHandling", "Trade"),
+    ("PositionMaintenance", "PostTrade"),
+    ("ProgramTrading", "Trade"),
+    ("QuotationNegotiation", "PreTrade"),
+    ("RegistrationInstruction", "PostTrade"),
+    ("SecuritiesReferenceData", "PreTrade"),
+    ("Session", "Session"),
+    ("SettlementInstruction", "PostTrade"),
+    ("SingleGeneralOrderHandling", "Trade"),
+    ("TradeCapture", "PostTrade"),
+    ("UserManagement", "Infrastructure"),
+];
+
+/// Standard FIX message names and the category each of them belongs to. Names
+/// used by older FIX versions are listed as well.
+const MESSAGE_CATEGORIES: &[(&str, &str)] = &[
+    ("AdjustedPositionReport", "PositionMaintenance"),
+    ("Advertisement", "Indication"),
+    ("AllocationAck", "Allocation"),
+    ("AllocationInstructionAck", "Allocation"),
+    ("AllocationInstructionAlert", "Allocation"),
+    ("AllocationInstruction", "Allocation"),
+    ("AllocationReportAck", "Allocation"),
+    ("AllocationReport", "Allocation"),
+    ("Allocation", "Allocation"),
+    ("ApplicationMessageReport", "Application"),
+    ("ApplicationMessageRequestAck", "Application"),
+    ("ApplicationMessageRequest", "Application"),
+    ("AssignmentReport", "PositionMaintenance"),
+    ("BidRequest", "ProgramTrading"),
+    ("BidResponse", "ProgramTrading"),
+    ("BusinessMessageReject", "BusinessReject"),
+    ("CollateralAssignment", "CollateralManagement"),
+    ("CollateralInquiryAck", "CollateralManagement"),
+    ("CollateralInquiry", "CollateralManagement"),
+    ("CollateralReport", "CollateralManagement"),
+    ("CollateralRequest", "CollateralManagement"),
+    ("CollateralResponse", "CollateralManagement"),
+    ("ConfirmationAck", "Confirmation"),
+    ("ConfirmationRequest", "Confirmation"),
+    ("Confirmation", "Confirmation"),
+    ("ContraryIntentionReport", "PositionMaintenance"),
+    ("CrossOrderCancelReplaceRequest", "CrossOrders"),
+    ("CrossOrderCancelRequest", "CrossOrders"),
+    ("DerivativeSecurityListRequest", "SecuritiesReferenceData"),
+    (
+        "DerivativeSecurityListUpdateReport",
+        "SecuritiesReferenceData",
+    ),
+    ("DerivativeSecurityList", "SecuritiesReferenceData"),
+    ("DontKnowTrade", "SingleGeneralOrderHandling"),
+    ("Email", "EventCommunication"),
+    ("ExecutionAcknowledgement", "SingleGeneralOrderHandling"),
+    ("ExecutionReport", "SingleGeneralOrderHandling"),
+    ("Heartbeat", "Session"),
+    ("IOI", "Indication"),
+    ("ListCancelRequest", "ProgramTrading"),
+    ("ListExecute", "ProgramTrading"),
+    ("ListStatusRequest", "ProgramTrading"),
+    ("ListStatus", "ProgramTrading"),
+    ("ListStrikePrice", "ProgramTrading"),
+    ("Logon", "Session"),
+    ("Logout", "Session"),
+    ("MarketDataIncrementalRefresh", "MarketData"),
+    ("MarketDataRequestReject", "MarketData"),
+    ("MarketDataRequest", "MarketData"),
+    ("MarketDataSnapshotFullRefresh", "MarketData"),
+    ("MarketDefinitionRequest", "MarketStructureReferenceData"),
+    (
+        "MarketDefinitionUpdateReport",
+        "MarketStructureReferenceData",
+    ),
+    ("MarketDefinition", "MarketStructureReferenceData"),
+    ("MassQuoteAcknowledgement", "QuotationNegotiation"),
+    ("MassQuote", "QuotationNegotiation"),
+    ("MultilegOrderCancelReplaceRequest", "MultilegOrders"),
+    ("MultilegOrderCancelReplace", "MultilegOrders"),
+    ("NetworkCounterpartySystemStatusRequest", "Network"),
+    ("NetworkCounterpartySystemStatusResponse", "Network"),
+    ("NewOrderCross", "CrossOrders"),
+    ("NewOrderList", "ProgramTrading"),
+    ("NewOrderMultileg", "MultilegOrders"),
+    ("NewOrderSingle", "SingleGeneralOrderHandling"),
+    ("News", "EventCommunication"),
+    ("OrderCancelReject", "SingleGeneralOrderHandling"),
+    ("OrderCancelReplaceRequest", "SingleGeneralOrderHandling"),
+    ("OrderCancelRequest", "SingleGeneralOrderHandling"),
+    ("OrderMassActionReport", "OrderMassHandling"),
+    ("OrderMassActionRequest", "OrderMassHandling"),
+    ("OrderMassCancelReport", "OrderMassHandling"),
+    ("OrderMassCancelRequest", "OrderMassHandling"),
+    ("OrderMassStatusRequest", "OrderMassHandling"),
+    ("OrderStatusRequest", "SingleGeneralOrderHandling"),
+    ("PositionMaintenanceReport", "PositionMaintenance"),
+    ("PositionMaintenanceRequest", "PositionMaintenance"),
+    ("PositionReport", "PositionMaintenance"),
+    ("QuoteAcknowledgement", "QuotationNegotiation"),
+    ("QuoteCancel", "QuotationNegotiation"),
+    ("QuoteRequestReject", "QuotationNegotiation"),
+    ("QuoteRequest", "QuotationNegotiation"),
+    ("QuoteResponse", "QuotationNegotiation"),
+    ("QuoteStatusReport", "QuotationNegotiation"),
+    ("QuoteStatusRequest", "QuotationNegotiation"),
+    ("Quote", "QuotationNegotiation"),
+    ("RFQRequest", "QuotationNegotiation"),
+    (
+        "RegistrationInstructionsResponse",
+        "RegistrationInstruction",
+    ),
+    ("RegistrationInstructions", "RegistrationInstruction"),
+    ("Reject", "Session"),
+    ("RequestForPositionsAck", "PositionMaintenance"),
+    ("RequestForPositions", "PositionMaintenance"),
+    ("ResendRequest", "Session"),
+    ("SecurityDefinitionRequest", "SecuritiesReferenceData"),
+    ("SecurityDefinitionUpdateReport", "SecuritiesReferenceData"),
+    ("SecurityDefinition", "SecuritiesReferenceData"),
+    ("SecurityListRequest", "SecuritiesReferenceData"),
+    ("SecurityListUpdateReport", "SecuritiesReferenceData"),
+    ("SecurityList", "SecuritiesReferenceData"),
+    ("SecurityStatusRequest", "SecuritiesReferenceData"),
+    ("SecurityStatus", "SecuritiesReferenceData"),
+    ("SecurityTypeRequest", "SecuritiesReferenceData"),
+    ("SecurityTypes", "SecuritiesReferenceData"),
+    ("SequenceReset", "Session"),
+    ("SettlementInstructionRequest", "SettlementInstruction"),
+    ("SettlementInstructions", "SettlementInstruction"),
+    ("SettlementObligationReport", "SettlementInstruction"),
+    ("StreamAssignmentReportACK", "MarketData"),
+    ("StreamAssignmentReport", "MarketData"),
+    ("StreamAssignmentRequest", "MarketData"),
+    ("TestRequest", "Session"),
+    ("TradeCaptureReportAck", "TradeCapture"),
+    ("TradeCaptureReportRequestAck", "TradeCapture"),
+    ("TradeCaptureReportRequest", "TradeCapture"),
+    ("TradeCaptureReport", "TradeCapture"),
+    ("TradingSessionListRequest", "MarketStructureReferenceData"),
+    (
+        "TradingSessionListUpdateReport",
+        "MarketStructureReferenceData",
+    ),
+    ("TradingSessionList", "MarketStructureReferenceData"),
+    (
+        "TradingSessionStatusRequest",
+        "MarketStructureReferenceData",
+    ),
+    ("TradingSessionStatus", "MarketStructureReferenceData"),
+    ("UserNotification", "UserManagement"),
+    ("UserRequest", "UserManagement"),
+    ("UserResponse", "UserManagement"),
+    ("XMLnonFIX", "Session"),
+];
+
+/// Returns the name of the standard category of the message called `name`, if
+/// any.
+pub fn category_of_message(name: &str) -> Option<&'static str> {
+    MESSAGE_CATEGORIES
+        .iter()
+        .find(|(message, _)| *message == name)
+        .map(|(_, category)| *category)
+}
+
+/// Returns the name of the section to which the standard category `name`
+/// belongs, if any.
+pub fn section_of_category(name: &str) -> Option<&'static str> {
+    CATEGORY_SECTIONS
+        .iter()
+        .find(|(category, _)| *category == name)
+        .map(|(_, section)| *section)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn every_message_category_has_a_section() {
+        for (_, category) in MESSAGE_CATEGORIES {
+            assert!(section_of_category(category).is_some(), "{}", category);
+        }
+    }
+
+    #[test]
+    fn order_handling_is_trade() {
+        let category = category_of_message("NewOrderSingle").unwrap();
+        assert_eq!(category, "SingleGeneralOrderHandling");
+        assert_eq!(section_of_category(category), Some("Trade"));
+    }
+}
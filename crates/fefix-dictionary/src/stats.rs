@@ -0,0 +1,167 @@
+use super::*;
+
+/// An aggregate summary of the contents of a [`Dictionary`]. See
+/// [`Dictionary::stats`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DictionaryStats {
+    /// See [`Dictionary::version`].
+    pub version: String,
+    /// The number of [`Field`]'s.
+    pub fields: usize,
+    /// The number of [`Message`]'s.
+    pub messages: usize,
+    /// The number of [`Component`]'s, including `StandardHeader` and
+    /// `StandardTrailer`.
+    pub components: usize,
+    /// The number of [`Datatype`]'s.
+    pub datatypes: usize,
+    /// The number of [`Category`]'s.
+    pub categories: usize,
+    /// The number of [`Field`]'s with a code set.
+    pub code_set_fields: usize,
+    /// The number of repeating group definitions, across all messages and
+    /// components. Nested groups are counted as well.
+    pub groups: usize,
+    /// The maximum number of components and repeating groups that enclose
+    /// any single layout item of a message body. A message made only of fields
+    /// has a nesting depth of zero.
+    pub max_nesting_depth: usize,
+}
+
+/// Diagnostics
+impl Dictionary {
+    /// Computes a [`DictionaryStats`] summary of `self`.
+    ///
+    /// ```
+    /// use fefix_dictionary::Dictionary;
+    ///
+    /// let dict = Dictionary::fix44();
+    /// let stats = dict.stats();
+    /// assert_eq!(stats.version, "FIX.4.4");
+    /// assert_eq!(stats.messages, dict.messages().len());
+    /// assert!(stats.max_nesting_depth > 1);
+    /// ```
+    pub fn stats(&self) -> DictionaryStats {
+        let containers = self
+            .messages_by_msgtype
+            .values()
+            .map(|message| &message.layout_items)
+            .chain(
+                self.components_by_name
+                    .values()
+                    .map(|component| &component.layout_items),
+            );
+        DictionaryStats {
+            version: self.version().to_string(),
+            fields: self.fields_by_tags.len(),
+            messages: self.messages_by_msgtype.len(),
+            components: self.components_by_name.len(),
+            datatypes: self.data_types_by_name.len(),
+            categories: self.categories_by_name.len(),
+            code_set_fields: self
+                .fields_by_tags
+                .values()
+                .filter(|field| field.value_restrictions.is_some())
+                .count(),
+            groups: containers.map(|items| count_groups(items)).sum(),
+            max_nesting_depth: self
+                .messages_by_msgtype
+                .values()
+                .map(|message| nesting_depth(self, &message.layout_items))
+                .max()
+                .unwrap_or(0),
+        }
+    }
+}
+
+/// Summaries
+impl Dictionary {
+    /// Counts the [`Message`]'s of each [`Category`], by category name.
+    /// Messages without a category are counted as `Uncategorized`.
+    ///
+    /// ```
+    /// use fefix_dictionary::Dictionary;
+    ///
+    /// let dict = Dictionary::fix44();
+    /// let counts = dict.message_count_by_category();
+    /// assert!(counts["admin"] >= 7);
+    /// ```
+    pub fn message_count_by_category(&self) -> FnvHashMap<String, usize> {
+        let mut counts = FnvHashMap::default();
+        for message in self.messages() {
+            let category = match message.category() {
+                Some(category) => category.name().to_string(),
+                None => "Uncategorized".to_string(),
+            };
+            *counts.entry(category).or_insert(0) += 1;
+        }
+        counts
+    }
+}
+
+fn count_groups(items: &[LayoutItemData]) -> usize {
+    items
+        .iter()
+        .map(|item| match &item.kind {
+            LayoutItemKindData::Group { items, .. } => 1 + count_groups(items),
+            _ => 0,
+        })
+        .sum()
+}
+
+fn nesting_depth(dict: &Dictionary, items: &[LayoutItemData]) -> usize {
+    items
+        .iter()
+        .map(|item| match &item.kind {
+            LayoutItemKindData::Field { .. } => 0,
+            LayoutItemKindData::Group { items, .. } => 1 + nesting_depth(dict, items),
+            LayoutItemKindData::Component { name } => {
+                dict.components_by_name.get(name).map_or(0, |component| {
+                    1 + nesting_depth(dict, &component.layout_items)
+                })
+            }
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn fix44_stats() {
+        let dict = Dictionary::fix44();
+        let stats = dict.stats();
+        assert_eq!(stats.fields, dict.fields().len());
+        assert_eq!(stats.components, dict.components().len());
+        assert_eq!(
+            stats.code_set_fields,
+            dict.fields().iter().filter(|f| f.enums().is_some()).count()
+        );
+        assert!(stats.groups > 0);
+        assert_eq!(stats.categories, 2);
+    }
+
+    #[test]
+    fn fix44_message_count_by_category() {
+        let dict = Dictionary::fix44();
+        let counts = dict.message_count_by_category();
+        assert_eq!(counts.values().sum::<usize>(), dict.messages().len());
+        assert_eq!(counts.len(), 2);
+        assert_eq!(counts["admin"], 8);
+        let dict = Dictionary::from_parts("FIX.4.4", &[], &[], &[], &[]).unwrap();
+        assert!(dict.message_count_by_category().is_empty());
+    }
+
+    #[test]
+    fn nesting_depth_of_execution_report() {
+        let dict = Dictionary::fix44();
+        let message = dict.messages_by_msgtype.get("8").unwrap();
+        // InstrmtLegExecGrp > NoLegs > InstrumentLeg > LegSecAltIDGrp >
+        // NoLegSecurityAltID > LegSecurityAltID
+        assert!(nesting_depth(&dict, &message.layout_items) >= 5);
+        let heartbeat = dict.messages_by_msgtype.get("0").unwrap();
+        assert_eq!(nesting_depth(&dict, &heartbeat.layout_items), 0);
+    }
+}
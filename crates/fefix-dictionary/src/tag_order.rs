@@ -0,0 +1,276 @@
+use super::*;
+
+/// The error type that can arise when validating the order of the tags of a
+/// message. See [`Dictionary::validate_tag_order`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum OrderError {
+    /// The [`Dictionary`] doesn't contain the message type.
+    UnknownMsgType,
+    /// A tag appears after some other tag which should come after it, or a
+    /// repeating group instance doesn't start with the group's first field.
+    OutOfOrder {
+        /// The position of the offending tag in the given sequence.
+        index: usize,
+        /// The offending tag.
+        tag: TagU32,
+    },
+}
+
+/// Options for validating messages. See
+/// [`Dictionary::validate_tag_order_with_options`].
+#[derive(Clone, Debug, Default)]
+pub struct ValidationOptions {
+    /// If `true`, messages of types that the [`Dictionary`] doesn't contain
+    /// (e.g. proprietary messages) are accepted as they are, and reported
+    /// with [`ValidationNote::UnknownMessageType`] instead of failing with
+    /// [`OrderError::UnknownMsgType`].
+    pub allow_unknown_msg_types: bool,
+}
+
+/// Something worth noting about a message which passed validation. See
+/// [`Dictionary::validate_tag_order_with_options`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ValidationNote {
+    /// The message type is unknown, so the message wasn't validated at all.
+    /// See [`ValidationOptions::allow_unknown_msg_types`].
+    UnknownMessageType,
+}
+
+/// Message validation
+impl Dictionary {
+    /// Checks that the order of `tags_in_order`, as decoded from a message of
+    /// type `msg_type`, follows the layout of the message body. Every instance
+    /// of a repeating group must start with the first field of the group and
+    /// list its other fields in definition order.
+    ///
+    /// Only the order of tags is checked: optional and required fields alike
+    /// can be missing, and tags which are not part of the message body (e.g.
+    /// `StandardHeader` and `StandardTrailer` fields) are ignored.
+    ///
+    /// ```
+    /// use fefix_dictionary::{Dictionary, OrderError, TagU32};
+    ///
+    /// let dict = Dictionary::fix44();
+    /// let tags = |tags: &[u32]| -> Vec<TagU32> {
+    ///     tags.iter().map(|tag| TagU32::new(*tag).unwrap()).collect()
+    /// };
+    /// // ClOrdID, Symbol, Side, TransactTime, OrdType.
+    /// assert_eq!(dict.validate_tag_order("D", &tags(&[11, 55, 54, 60, 40])), Ok(()));
+    /// assert_eq!(
+    ///     dict.validate_tag_order("D", &tags(&[11, 54, 55])),
+    ///     Err(OrderError::OutOfOrder {
+    ///         index: 2,
+    ///         tag: TagU32::new(55).unwrap(),
+    ///     })
+    /// );
+    /// ```
+    pub fn validate_tag_order(
+        &self,
+        msg_type: &str,
+        tags_in_order: &[TagU32],
+    ) -> Result<(), OrderError> {
+        self.validate_tag_order_with_options(msg_type, tags_in_order, &ValidationOptions::default())
+            .map(|_| ())
+    }
+
+    /// Like [`Dictionary::validate_tag_order`], but some messages that would
+    /// otherwise fail are accepted according to `options`, e.g. to forward
+    /// proprietary messages from a gateway. The reasons why they were
+    /// accepted are returned as [`ValidationNote`]'s.
+    ///
+    /// ```
+    /// use fefix_dictionary::{Dictionary, TagU32, ValidationNote, ValidationOptions};
+    ///
+    /// let dict = Dictionary::fix44();
+    /// let options = ValidationOptions {
+    ///     allow_unknown_msg_types: true,
+    /// };
+    /// let tags = [TagU32::new(5001).unwrap()];
+    /// let notes = dict.validate_tag_order_with_options("U1", &tags, &options);
+    /// assert_eq!(notes, Ok(vec![ValidationNote::UnknownMessageType]));
+    /// ```
+    pub fn validate_tag_order_with_options(
+        &self,
+        msg_type: &str,
+        tags_in_order: &[TagU32],
+        options: &ValidationOptions,
+    ) -> Result<Vec<ValidationNote>, OrderError> {
+        let message = match self.messages_by_msgtype.get(msg_type) {
+            Some(message) => message,
+            None if options.allow_unknown_msg_types => {
+                return Ok(vec![ValidationNote::UnknownMessageType]);
+            }
+            None => return Err(OrderError::UnknownMsgType),
+        };
+        let mut all_tags = Vec::new();
+        expand_layout_tags(
+            message
+                .layout_items
+                .iter()
+                .map(|item| LayoutItem(self, item)),
+            &mut all_tags,
+        );
+        let all_tags = all_tags.into_iter().collect::<FnvHashSet<_>>();
+        let mut scopes = vec![Scope::new(self, &message.layout_items, false)];
+        for (index, tag) in tags_in_order.iter().enumerate() {
+            let out_of_order = OrderError::OutOfOrder { index, tag: *tag };
+            let depth = scopes
+                .iter()
+                .rposition(|scope| scope.contains(tag.get()))
+                .map(|i| i + 1);
+            let depth = match depth {
+                Some(depth) => depth,
+                // A field of a group that was already left, or of a group
+                // instance which never started.
+                None if all_tags.contains(tag) => return Err(out_of_order),
+                None => continue,
+            };
+            scopes.truncate(depth);
+            let scope = scopes.last_mut().unwrap();
+            let i = scope.advance(tag.get()).ok_or(out_of_order)?;
+            if let Some(items) = scope.members[i].1 {
+                scopes.push(Scope::new(self, items, true));
+            }
+        }
+        Ok(Vec::new())
+    }
+}
+
+/// The fields that can appear directly in a message body or repeating group
+/// instance, and how far the validation got through them.
+struct Scope<'a> {
+    /// Tags in definition order, with components expanded. Nested groups only
+    /// contribute their count tag, together with their items.
+    members: Vec<(u32, Option<&'a [LayoutItemData]>)>,
+    /// The index of the next member that can appear.
+    next: usize,
+    is_group: bool,
+}
+
+impl<'a> Scope<'a> {
+    fn new(dict: &'a Dictionary, items: &'a [LayoutItemData], is_group: bool) -> Self {
+        let mut members = Vec::new();
+        scope_members(dict, items, &mut members);
+        Self {
+            members,
+            next: 0,
+            is_group,
+        }
+    }
+
+    fn contains(&self, tag: u32) -> bool {
+        self.members.iter().any(|(t, _)| *t == tag)
+    }
+
+    /// Moves past `tag` and returns its index in `self.members`, or returns
+    /// `None` if `tag` can't appear at this point.
+    fn advance(&mut self, tag: u32) -> Option<usize> {
+        let i = if self.is_group && self.members.first().map(|(t, _)| *t) == Some(tag) {
+            // The delimiter starts a new group instance.
+            0
+        } else if self.is_group && self.next == 0 {
+            return None;
+        } else {
+            self.next
+                + self.members[self.next..]
+                    .iter()
+                    .position(|(t, _)| *t == tag)?
+        };
+        self.next = i + 1;
+        Some(i)
+    }
+}
+
+fn scope_members<'a>(
+    dict: &'a Dictionary,
+    items: &'a [LayoutItemData],
+    members: &mut Vec<(u32, Option<&'a [LayoutItemData]>)>,
+) {
+    for item in items {
+        match &item.kind {
+            LayoutItemKindData::Field { tag } => members.push((*tag, None)),
+            LayoutItemKindData::Group {
+                len_field_tag,
+                items,
+            } => members.push((*len_field_tag, Some(items.as_slice()))),
+            LayoutItemKindData::Component { name } => {
+                if let Some(component) = dict.components_by_name.get(name.as_str()) {
+                    scope_members(dict, &component.layout_items, members);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn validate(msg_type: &str, tags: &[u32]) -> Result<(), OrderError> {
+        let tags = tags
+            .iter()
+            .map(|tag| TagU32::new(*tag).unwrap())
+            .collect::<Vec<_>>();
+        Dictionary::fix44().validate_tag_order(msg_type, &tags)
+    }
+
+    fn out_of_order(index: usize, tag: u32) -> Result<(), OrderError> {
+        Err(OrderError::OutOfOrder {
+            index,
+            tag: TagU32::new(tag).unwrap(),
+        })
+    }
+
+    #[test]
+    fn tag_order_with_groups() {
+        // NewOrderSingle with two Parties, PartyIDSource being optional.
+        let tags = [
+            8, 9, 35, 49, 56, 34, 52, 11, 453, 448, 447, 452, 448, 452, 55, 54, 60, 38, 40, 10,
+        ];
+        assert_eq!(validate("D", &tags), Ok(()));
+        assert_eq!(
+            validate("D", &[11, 55, 54, 60, 40, 38]),
+            out_of_order(5, 38)
+        );
+        // Group instances must start with PartyID.
+        assert_eq!(validate("D", &[11, 453, 447, 448]), out_of_order(2, 447));
+        assert_eq!(
+            validate("D", &[11, 453, 448, 452, 447]),
+            out_of_order(4, 447)
+        );
+        // PartyRole after the group is over.
+        assert_eq!(
+            validate("D", &[11, 453, 448, 55, 452]),
+            out_of_order(4, 452)
+        );
+        // Unknown tags are ignored.
+        assert_eq!(validate("D", &[11, 9999, 55]), Ok(()));
+        assert_eq!(validate("ZZ", &[11]), Err(OrderError::UnknownMsgType));
+    }
+
+    #[test]
+    fn unknown_msg_types_can_be_allowed() {
+        let dict = Dictionary::fix44();
+        let options = ValidationOptions {
+            allow_unknown_msg_types: true,
+        };
+        let tags = [TagU32::new(11).unwrap(), TagU32::new(55).unwrap()];
+        assert_eq!(
+            dict.validate_tag_order_with_options("ZZ", &tags, &options),
+            Ok(vec![ValidationNote::UnknownMessageType])
+        );
+        assert_eq!(
+            dict.validate_tag_order_with_options("D", &tags, &options),
+            Ok(vec![])
+        );
+        // Known messages are still validated.
+        let tags = [TagU32::new(55).unwrap(), TagU32::new(11).unwrap()];
+        assert!(dict
+            .validate_tag_order_with_options("D", &tags, &options)
+            .is_err());
+        assert_eq!(
+            dict.validate_tag_order_with_options("ZZ", &tags, &ValidationOptions::default()),
+            Err(OrderError::UnknownMsgType)
+        );
+    }
+}
@@ -0,0 +1,339 @@
+use super::*;
+use rand::distributions::Alphanumeric;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+/// Random message generation
+impl Dictionary {
+    /// Generates a random, syntactically valid body for the message with type
+    /// `msg_type`. Returns `None` if there's no such message.
+    ///
+    /// Required fields are always present, while optional fields are included
+    /// with 50% probability. Field values are random but always valid for the
+    /// field's datatype (or code set, if any). Repeating groups have one to
+    /// three instances, each starting with the group delimiter. `data` fields
+    /// are generated together with the `Length` field that precedes them,
+    /// which holds their actual length.
+    ///
+    /// The standard header and trailer are **not** included, as their values
+    /// (e.g. `BodyLength` and `CheckSum`) are the encoder's responsibility.
+    ///
+    /// ```
+    /// use fefix_dictionary::Dictionary;
+    /// use rand::SeedableRng;
+    ///
+    /// let dict = Dictionary::fix44();
+    /// let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+    /// let fields = dict.generate_test_message("D", &mut rng).unwrap();
+    /// // `ClOrdID` is required in `NewOrderSingle`.
+    /// assert!(fields.iter().any(|(tag, _)| tag.get() == 11));
+    /// ```
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "test-utils")))]
+    pub fn generate_test_message(
+        &self,
+        msg_type: &str,
+        rng: &mut impl Rng,
+    ) -> Option<Vec<(TagU32, Vec<u8>)>> {
+        let message = self.messages_by_msgtype.get(msg_type)?;
+        let mut fields = Vec::new();
+        generate_layout_items(self, &message.layout_items, false, rng, &mut fields);
+        Some(fields)
+    }
+}
+
+fn generate_layout_items(
+    dict: &Dictionary,
+    items: &[LayoutItemData],
+    force_first: bool,
+    rng: &mut impl Rng,
+    fields: &mut Vec<(TagU32, Vec<u8>)>,
+) {
+    let mut items = items.iter().enumerate().peekable();
+    while let Some((i, item)) = items.next() {
+        let forced = force_first && i == 0;
+        let data_item = items
+            .peek()
+            .filter(|(_, next)| is_length_of(dict, item, next))
+            .map(|(_, next)| *next);
+        if data_item.is_some() {
+            items.next();
+        }
+        let required = item.required || data_item.is_some_and(|data| data.required);
+        if !required && !forced && !rng.gen_bool(0.5) {
+            continue;
+        }
+        match &item.kind {
+            LayoutItemKindData::Field { tag } => match data_item.map(|data| &data.kind) {
+                Some(LayoutItemKindData::Field { tag: data_tag }) => {
+                    generate_data_field(dict, *tag, *data_tag, rng, fields)
+                }
+                _ => generate_field(dict, *tag, rng, fields),
+            },
+            LayoutItemKindData::Component { name } => {
+                if let Some(component) = dict.components_by_name.get(name) {
+                    generate_layout_items(dict, &component.layout_items, forced, rng, fields);
+                }
+            }
+            LayoutItemKindData::Group {
+                len_field_tag,
+                items,
+            } => {
+                let num_in_group = rng.gen_range(1..=3);
+                let tag = TagU32::new(*len_field_tag).unwrap();
+                fields.push((tag, num_in_group.to_string().into_bytes()));
+                for _ in 0..num_in_group {
+                    generate_layout_items(dict, items, true, rng, fields);
+                }
+            }
+        }
+    }
+}
+
+/// Tells whether `item` is a `Length` field and `next` is the data field
+/// whose length it holds.
+fn is_length_of(dict: &Dictionary, item: &LayoutItemData, next: &LayoutItemData) -> bool {
+    match (&item.kind, &next.kind) {
+        (LayoutItemKindData::Field { tag }, LayoutItemKindData::Field { tag: next_tag }) => {
+            dict.fields_by_tags
+                .get(tag)
+                .and_then(|field| field.associated_data_tag)
+                == Some(*next_tag as usize)
+        }
+        _ => false,
+    }
+}
+
+fn generate_field(
+    dict: &Dictionary,
+    tag: u32,
+    rng: &mut impl Rng,
+    fields: &mut Vec<(TagU32, Vec<u8>)>,
+) {
+    if let Some(field) = dict.field_by_tag(tag) {
+        fields.push((field.tag(), random_field_value(field, rng)));
+    }
+}
+
+/// Generates the data field `data_tag` preceded by the `Length` field
+/// `length_tag`, which holds the length of the data.
+fn generate_data_field(
+    dict: &Dictionary,
+    length_tag: u32,
+    data_tag: u32,
+    rng: &mut impl Rng,
+    fields: &mut Vec<(TagU32, Vec<u8>)>,
+) {
+    let (length_field, data_field) =
+        match (dict.field_by_tag(length_tag), dict.field_by_tag(data_tag)) {
+            (Some(length_field), Some(data_field)) => (length_field, data_field),
+            _ => return,
+        };
+    let data = random_field_value(data_field, rng);
+    fields.push((length_field.tag(), data.len().to_string().into_bytes()));
+    fields.push((data_field.tag(), data));
+}
+
+fn random_field_value(field: Field, rng: &mut impl Rng) -> Vec<u8> {
+    match &field.1.value_restrictions {
+        Some(enums) => enums.choose(rng).unwrap().value.clone().into_bytes(),
+        None => random_value(field.fix_datatype(), rng),
+    }
+}
+
+fn random_value(datatype: FixDatatype, rng: &mut impl Rng) -> Vec<u8> {
+    let value = match datatype {
+        FixDatatype::Int => rng.gen_range(-99999..=99999i64).to_string(),
+        FixDatatype::Length
+        | FixDatatype::NumInGroup
+        | FixDatatype::SeqNum
+        | FixDatatype::TagNum => rng.gen_range(1..=9999u32).to_string(),
+        FixDatatype::DayOfMonth => rng.gen_range(1..=31u32).to_string(),
+        FixDatatype::Float
+        | FixDatatype::Amt
+        | FixDatatype::Price
+        | FixDatatype::PriceOffset
+        | FixDatatype::Qty
+        | FixDatatype::Percentage => {
+            format!(
+                "{}.{:02}",
+                rng.gen_range(0..=9999u32),
+                rng.gen_range(0..100u32)
+            )
+        }
+        FixDatatype::Char => random_string(1, rng),
+        FixDatatype::Boolean => ["Y", "N"].choose(rng).unwrap().to_string(),
+        FixDatatype::Currency => random_uppercase(3, rng),
+        FixDatatype::Country => random_uppercase(2, rng),
+        FixDatatype::Language => random_uppercase(2, rng).to_ascii_lowercase(),
+        FixDatatype::MonthYear => format!("{}{}", random_year(rng), random_month(rng)),
+        FixDatatype::LocalMktDate | FixDatatype::UtcDateOnly => random_date(rng),
+        FixDatatype::UtcTimeOnly => random_time(rng),
+        FixDatatype::UtcTimestamp => format!("{}-{}", random_date(rng), random_time(rng)),
+        FixDatatype::XmlData => format!("<{}/>", random_uppercase(4, rng)),
+        // ISO 10383 Market Identifier Codes.
+        FixDatatype::Exchange => random_uppercase(4, rng),
+        FixDatatype::MultipleCharValue => random_list(rng, |rng| random_string(1, rng)),
+        FixDatatype::MultipleStringValue => random_list(rng, |rng| {
+            let len = rng.gen_range(1..=4);
+            random_string(len, rng)
+        }),
+        FixDatatype::String | FixDatatype::Data => {
+            let len = rng.gen_range(1..=12);
+            random_string(len, rng)
+        }
+    };
+    value.into_bytes()
+}
+
+fn random_string(len: usize, rng: &mut impl Rng) -> String {
+    rng.sample_iter(&Alphanumeric)
+        .take(len)
+        .map(char::from)
+        .collect()
+}
+
+/// Joins one to three values generated by `f` with spaces.
+fn random_list<R: Rng>(rng: &mut R, f: impl Fn(&mut R) -> String) -> String {
+    let len = rng.gen_range(1..=3);
+    (0..len).map(|_| f(rng)).collect::<Vec<_>>().join(" ")
+}
+
+fn random_uppercase(len: usize, rng: &mut impl Rng) -> String {
+    (0..len)
+        .map(|_| rng.gen_range(b'A'..=b'Z') as char)
+        .collect()
+}
+
+fn random_year(rng: &mut impl Rng) -> String {
+    rng.gen_range(1990..=2050u32).to_string()
+}
+
+fn random_month(rng: &mut impl Rng) -> String {
+    format!("{:02}", rng.gen_range(1..=12u32))
+}
+
+fn random_date(rng: &mut impl Rng) -> String {
+    format!(
+        "{}{}{:02}",
+        random_year(rng),
+        random_month(rng),
+        rng.gen_range(1..=28u32)
+    )
+}
+
+fn random_time(rng: &mut impl Rng) -> String {
+    format!(
+        "{:02}:{:02}:{:02}.{:03}",
+        rng.gen_range(0..24u32),
+        rng.gen_range(0..60u32),
+        rng.gen_range(0..60u32),
+        rng.gen_range(0..1000u32)
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn unknown_msg_type_is_none() {
+        let dict = Dictionary::fix44();
+        let mut rng = StdRng::seed_from_u64(0);
+        assert!(dict.generate_test_message("FOOBAR", &mut rng).is_none());
+    }
+
+    #[test]
+    fn required_fields_are_always_present() {
+        let dict = Dictionary::fix44();
+        let mut rng = StdRng::seed_from_u64(0);
+        for msg in dict.messages() {
+            for _ in 0..10 {
+                let fields = dict
+                    .generate_test_message(msg.msg_type(), &mut rng)
+                    .unwrap();
+                for item in msg.layout().filter(|item| item.required()) {
+                    if let LayoutItemKind::Field(field) = item.kind() {
+                        assert!(fields.iter().any(|(tag, _)| *tag == field.tag()));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn enum_values_are_valid() {
+        let dict = Dictionary::fix44();
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..50 {
+            let fields = dict.generate_test_message("D", &mut rng).unwrap();
+            for (tag, value) in fields.iter() {
+                let field = dict.field_by_tag(tag.get()).unwrap();
+                let enums = field.enums();
+                if let Some(mut enums) = enums {
+                    let value = std::str::from_utf8(value).unwrap();
+                    assert!(enums.any(|e| e.value() == value));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn values_are_valid_for_their_datatype() {
+        let dict = Dictionary::fix44();
+        let mut rng = StdRng::seed_from_u64(0);
+        for msg in dict.messages() {
+            for _ in 0..10 {
+                let fields = dict
+                    .generate_test_message(msg.msg_type(), &mut rng)
+                    .unwrap();
+                for (tag, value) in fields.iter() {
+                    let field = dict.field_by_tag(tag.get()).unwrap();
+                    // Some code sets don't respect their own datatype, e.g.
+                    // `MiscFeeType <139>` is a `char` with a `12` value.
+                    if field.enums().is_none() {
+                        assert!(field.fix_datatype().is_valid_value(value));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn data_fields_come_with_their_length() {
+        let dict = Dictionary::fix44();
+        let mut rng = StdRng::seed_from_u64(0);
+        for msg in dict.messages() {
+            for _ in 0..10 {
+                let fields = dict
+                    .generate_test_message(msg.msg_type(), &mut rng)
+                    .unwrap();
+                for (i, (tag, value)) in fields.iter().enumerate() {
+                    if !dict.data_tags().contains(tag) {
+                        continue;
+                    }
+                    let (length_tag, length) = &fields[i - 1];
+                    let length_field = dict.field_by_tag(length_tag.get()).unwrap();
+                    assert_eq!(length_field.data_tag(), Some(*tag));
+                    assert_eq!(length, &value.len().to_string().into_bytes());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn group_counts_are_consistent() {
+        let dict = Dictionary::fix44();
+        let mut rng = StdRng::seed_from_u64(0);
+        let no_legs = TagU32::new(555).unwrap();
+        let leg_symbol = TagU32::new(600).unwrap();
+        for _ in 0..50 {
+            let fields = dict.generate_test_message("AB", &mut rng).unwrap();
+            let (_, count) = fields.iter().find(|(tag, _)| *tag == no_legs).unwrap();
+            let count: usize = std::str::from_utf8(count).unwrap().parse().unwrap();
+            let legs = fields.iter().filter(|(tag, _)| *tag == leg_symbol).count();
+            assert_eq!(count, legs);
+        }
+    }
+}
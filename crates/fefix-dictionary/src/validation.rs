@@ -0,0 +1,811 @@
+use super::*;
+
+/// A field tag which appears more than once at the same nesting level of a
+/// message, component, or repeating group definition. See
+/// [`Dictionary::verify_no_duplicate_fields`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DuplicateFieldError {
+    /// The name of the message or component that contains the duplicates.
+    /// Repeating groups are named after their parent container, followed by a
+    /// dot and the name of their `NumInGroup` field (e.g.
+    /// `ExecutionReport.NoLegs`).
+    pub container: String,
+    /// The duplicated field tag.
+    pub tag: TagU32,
+    /// How many times `tag` appears inside `container`.
+    pub occurrences: usize,
+}
+
+/// A field which announces a repeating group in some places, but is used as a
+/// plain field in others. Decoders that treat group-ness as a global property
+/// of fields mis-handle one of the two. See
+/// [`Dictionary::verify_consistent_group_usage`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GroupUsageConflict {
+    /// The conflicting field tag.
+    pub tag: TagU32,
+    /// The containers in which `tag` announces a repeating group, named as in
+    /// [`DuplicateFieldError::container`]. Sorted and deduplicated.
+    pub as_group: Vec<String>,
+    /// The containers in which `tag` is a plain field, named as in
+    /// [`DuplicateFieldError::container`]. Sorted and deduplicated.
+    pub as_field: Vec<String>,
+}
+
+/// A data field which isn't immediately preceded by a `Length` field, so its
+/// length can't be known when decoding. See
+/// [`Dictionary::verify_data_fields_have_length`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MissingLengthFieldError {
+    /// The name of the message or component that contains the data field,
+    /// named as in [`DuplicateFieldError::container`].
+    pub container: String,
+    /// The tag of the data field.
+    pub tag: TagU32,
+}
+
+/// A required layout item whose field is left out of FIXML messages (see
+/// [`Field::required_in_xml_messages`]), so a FIXML schema can't require it.
+/// See [`Dictionary::check_fixml_consistency`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FixmlIssue {
+    /// The name of the message or component that contains the item, named as
+    /// in [`DuplicateFieldError::container`].
+    pub container: String,
+    /// The tag of the field, or of the `NumInGroup` field for repeating
+    /// groups.
+    pub tag: TagU32,
+}
+
+/// A repeating group without a first field, so decoders can't tell where
+/// each instance begins. See
+/// [`Dictionary::check_all_groups_have_delimiter_first`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GroupDelimiterError {
+    /// The name of the message or component that contains the group, named
+    /// as in [`DuplicateFieldError::container`].
+    pub container: String,
+    /// The tag of the `NumInGroup` field which announces the group.
+    pub count_tag: TagU32,
+}
+
+/// An example value of a [`Datatype`] which isn't a well-formed value of the
+/// datatype itself (see [`FixDatatype::is_valid_value`]). See
+/// [`Dictionary::validate_datatype_examples`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DatatypeExampleError {
+    /// The name of the datatype.
+    pub datatype: String,
+    /// The offending example.
+    pub example: String,
+}
+
+/// Spec consistency checks
+impl Dictionary {
+    /// Checks that no field tag appears more than once at the same nesting
+    /// level of any message, component, or repeating group. Fields that are
+    /// brought in by a component are only checked against the other items of
+    /// that same component.
+    ///
+    /// Violations are sorted by container name and then by tag.
+    ///
+    /// ```
+    /// use fefix_dictionary::Dictionary;
+    ///
+    /// let dict = Dictionary::fix44();
+    /// assert!(dict.verify_no_duplicate_fields().is_empty());
+    /// ```
+    pub fn verify_no_duplicate_fields(&self) -> Vec<DuplicateFieldError> {
+        let mut errors = Vec::new();
+        for message in self.messages_by_msgtype.values() {
+            find_duplicate_fields(self, &message.name, &message.layout_items, &mut errors);
+        }
+        for component in self.components_by_name.values() {
+            find_duplicate_fields(self, &component.name, &component.layout_items, &mut errors);
+        }
+        errors.sort_by(|a, b| (&a.container, a.tag).cmp(&(&b.container, b.tag)));
+        errors
+    }
+
+    /// Checks that every field which announces a repeating group does so
+    /// everywhere it's used. Group-ness is a property of each
+    /// [`LayoutItem`] (see [`LayoutItemKind::Group`]), so this [`Dictionary`]
+    /// itself handles such fields correctly; but decoders that only look at
+    /// fields, e.g. through [`Field::is_num_in_group`], don't.
+    ///
+    /// Conflicts are sorted by tag.
+    ///
+    /// ```
+    /// use fefix_dictionary::Dictionary;
+    ///
+    /// let dict = Dictionary::fix44();
+    /// assert!(dict.verify_consistent_group_usage().is_empty());
+    /// ```
+    pub fn verify_consistent_group_usage(&self) -> Vec<GroupUsageConflict> {
+        let mut usages = FnvHashMap::default();
+        for message in self.messages_by_msgtype.values() {
+            collect_group_usages(self, &message.name, &message.layout_items, &mut usages);
+        }
+        for component in self.components_by_name.values() {
+            collect_group_usages(self, &component.name, &component.layout_items, &mut usages);
+        }
+        let mut conflicts = usages
+            .into_iter()
+            .filter_map(|(tag, (mut as_group, mut as_field))| {
+                if as_group.is_empty() || as_field.is_empty() {
+                    return None;
+                }
+                as_group.sort();
+                as_group.dedup();
+                as_field.sort();
+                as_field.dedup();
+                Some(GroupUsageConflict {
+                    tag: TagU32::new(tag).unwrap(),
+                    as_group,
+                    as_field,
+                })
+            })
+            .collect::<Vec<_>>();
+        conflicts.sort_by_key(|conflict| conflict.tag);
+        conflicts
+    }
+
+    /// Returns all data fields (i.e. with [`FixDatatype::Data`]) which are not
+    /// preceded by a `Length` field anywhere in the specification (see
+    /// [`Field::data_tag`]), sorted by tag. Their values can't be decoded
+    /// wherever they appear. See also
+    /// [`Dictionary::verify_data_fields_have_length`], which checks every
+    /// single occurrence instead.
+    ///
+    /// ```
+    /// use fefix_dictionary::Dictionary;
+    ///
+    /// let dict = Dictionary::fix44();
+    /// assert!(dict.data_fields_without_length().is_empty());
+    /// ```
+    pub fn data_fields_without_length(&self) -> Vec<Field<'_>> {
+        let linked = self
+            .fields_by_tags
+            .values()
+            .filter_map(|field| field.associated_data_tag)
+            .collect::<FnvHashSet<_>>();
+        let mut fields = self
+            .fields_by_tags
+            .values()
+            .filter(|field| self.field_is_data(field.tag))
+            .filter(|field| !linked.contains(&(field.tag as usize)))
+            .map(|data| Field(self, data))
+            .collect::<Vec<_>>();
+        fields.sort_by_key(|field| field.tag());
+        fields
+    }
+
+    /// Checks that every data field is immediately preceded by its `Length`
+    /// field in all messages, components, and repeating groups, as required by
+    /// decoders. Violations are sorted by container name and then by tag.
+    ///
+    /// ```
+    /// use fefix_dictionary::Dictionary;
+    ///
+    /// let dict = Dictionary::fix44();
+    /// assert!(dict.verify_data_fields_have_length().is_empty());
+    /// ```
+    pub fn verify_data_fields_have_length(&self) -> Vec<MissingLengthFieldError> {
+        let mut errors = Vec::new();
+        for message in self.messages_by_msgtype.values() {
+            find_missing_length_fields(self, &message.name, &message.layout_items, &mut errors);
+        }
+        for component in self.components_by_name.values() {
+            find_missing_length_fields(self, &component.name, &component.layout_items, &mut errors);
+        }
+        errors.sort_by(|a, b| (&a.container, a.tag).cmp(&(&b.container, b.tag)));
+        errors
+    }
+
+    /// Checks that the FIXML metadata of fields agrees with the layouts that
+    /// use them, i.e. that no field which is left out of FIXML messages is
+    /// required by a component or by a message that is part of FIXML (see
+    /// [`Message::fixml_required`]). Messages that aren't part of FIXML are
+    /// skipped. Issues are sorted by container name and then by tag.
+    ///
+    /// ```
+    /// use fefix_dictionary::Dictionary;
+    ///
+    /// let dict = Dictionary::fix44();
+    /// assert!(dict.check_fixml_consistency().is_empty());
+    /// ```
+    pub fn check_fixml_consistency(&self) -> Vec<FixmlIssue> {
+        let mut issues = Vec::new();
+        for message in self.messages_by_msgtype.values() {
+            if message.required {
+                find_fixml_issues(self, &message.name, &message.layout_items, &mut issues);
+            }
+        }
+        for component in self.components_by_name.values() {
+            find_fixml_issues(self, &component.name, &component.layout_items, &mut issues);
+        }
+        issues.sort_by(|a, b| (&a.container, a.tag).cmp(&(&b.container, b.tag)));
+        issues
+    }
+
+    /// Checks that every repeating group in all messages and components starts
+    /// with a field, its delimiter (see [`GroupDef::delimiter_field`]).
+    /// Leading components are expanded, and a nested group at the start of a
+    /// group is delimited by its `NumInGroup` field. Empty groups, and groups
+    /// that start with an empty or unknown component, are reported. Errors
+    /// are sorted by container name and then by tag.
+    ///
+    /// ```
+    /// use fefix_dictionary::Dictionary;
+    ///
+    /// let dict = Dictionary::fix44();
+    /// assert!(dict.check_all_groups_have_delimiter_first().is_empty());
+    /// ```
+    pub fn check_all_groups_have_delimiter_first(&self) -> Vec<GroupDelimiterError> {
+        let mut errors = Vec::new();
+        for message in self.messages_by_msgtype.values() {
+            find_groups_without_delimiter(self, &message.name, &message.layout_items, &mut errors);
+        }
+        for component in self.components_by_name.values() {
+            find_groups_without_delimiter(
+                self,
+                &component.name,
+                &component.layout_items,
+                &mut errors,
+            );
+        }
+        errors.sort_by(|a, b| (&a.container, a.count_tag).cmp(&(&b.container, b.count_tag)));
+        errors
+    }
+
+    /// Returns all [`Datatype`]'s that no [`Field`] references, sorted by name.
+    /// An unused datatype often hints at a typo in the datatype of some field
+    /// definition.
+    ///
+    /// ```
+    /// use fefix_dictionary::Dictionary;
+    ///
+    /// let dict = Dictionary::fix44();
+    /// assert!(dict.unused_datatypes().is_empty());
+    /// ```
+    pub fn unused_datatypes(&self) -> Vec<Datatype<'_>> {
+        let used = self
+            .fields_by_tags
+            .values()
+            .map(|field| field.data_type_name.as_str())
+            .collect::<FnvHashSet<_>>();
+        let mut unused = self
+            .data_types_by_name
+            .iter()
+            .filter(|(name, _)| !used.contains(name.as_str()))
+            .map(|(_, data)| Datatype(self, data))
+            .collect::<Vec<_>>();
+        unused.sort_by(|a, b| a.name().cmp(b.name()));
+        unused
+    }
+
+    /// Returns the sorted names of all components which include themselves,
+    /// directly or through other components (also from within repeating
+    /// groups), or `None` if there are no such cycles. Expanding the layout of
+    /// a circular component (e.g. with [`Message::flatten_fields`]) never
+    /// terminates.
+    ///
+    /// Specification files with cycles are rejected when parsed, and so are
+    /// parts given to [`Dictionary::from_parts`], but dictionaries can still
+    /// be changed in other ways after being built.
+    ///
+    /// ```
+    /// use fefix_dictionary::Dictionary;
+    ///
+    /// let dict = Dictionary::fix44();
+    /// assert_eq!(dict.component_recursion_check(), None);
+    /// ```
+    pub fn component_recursion_check(&self) -> Option<Vec<String>> {
+        let mut names = self
+            .components_by_name
+            .keys()
+            .filter(|name| includes_itself(self, name))
+            .map(|name| name.to_string())
+            .collect::<Vec<_>>();
+        names.sort_unstable();
+        Some(names).filter(|names| !names.is_empty())
+    }
+
+    /// Checks that every example value of every [`Datatype`] (see
+    /// [`Datatype::examples`]) is well-formed according to the datatype, e.g.
+    /// that `UTCTimestamp` examples follow the FIX timestamp format. This
+    /// catches typos in specifications. Results are sorted by datatype name,
+    /// then in the order of the examples.
+    ///
+    /// ```
+    /// use fefix_dictionary::Dictionary;
+    ///
+    /// let dict = Dictionary::fix44();
+    /// assert!(dict.validate_datatype_examples().is_empty());
+    /// ```
+    pub fn validate_datatype_examples(&self) -> Vec<DatatypeExampleError> {
+        let mut errors = self
+            .data_types_by_name
+            .values()
+            .flat_map(|data| {
+                data.examples
+                    .iter()
+                    .filter(|example| !data.datatype.is_valid_value(example.as_bytes()))
+                    .map(|example| DatatypeExampleError {
+                        datatype: data.datatype.name().to_string(),
+                        example: example.clone(),
+                    })
+            })
+            .collect::<Vec<_>>();
+        // Stable, so examples of the same datatype keep their order.
+        errors.sort_by(|a, b| a.datatype.cmp(&b.datatype));
+        errors
+    }
+}
+
+/// For each field tag, the containers in which it announces a repeating group
+/// and those in which it's a plain field.
+type GroupUsages = FnvHashMap<u32, (Vec<String>, Vec<String>)>;
+
+fn collect_group_usages(
+    dict: &Dictionary,
+    container: &str,
+    items: &[LayoutItemData],
+    usages: &mut GroupUsages,
+) {
+    for item in items {
+        match &item.kind {
+            LayoutItemKindData::Field { tag } => {
+                let usage = usages.entry(*tag).or_default();
+                usage.1.push(container.to_string());
+            }
+            LayoutItemKindData::Group {
+                len_field_tag,
+                items,
+            } => {
+                let usage = usages.entry(*len_field_tag).or_default();
+                usage.0.push(container.to_string());
+                let group_name = dict
+                    .fields_by_tags
+                    .get(len_field_tag)
+                    .map(|field| field.name.as_str())
+                    .unwrap_or_default();
+                let group_container = format!("{}.{}", container, group_name);
+                collect_group_usages(dict, &group_container, items, usages);
+            }
+            LayoutItemKindData::Component { .. } => {}
+        }
+    }
+}
+
+fn find_duplicate_fields(
+    dict: &Dictionary,
+    container: &str,
+    items: &[LayoutItemData],
+    errors: &mut Vec<DuplicateFieldError>,
+) {
+    let mut occurrences: FnvHashMap<u32, usize> = FnvHashMap::default();
+    for item in items {
+        match &item.kind {
+            LayoutItemKindData::Field { tag } => {
+                *occurrences.entry(*tag).or_default() += 1;
+            }
+            LayoutItemKindData::Group {
+                len_field_tag,
+                items,
+            } => {
+                *occurrences.entry(*len_field_tag).or_default() += 1;
+                let group_name = dict
+                    .fields_by_tags
+                    .get(len_field_tag)
+                    .map(|field| field.name.as_str())
+                    .unwrap_or_default();
+                let group_container = format!("{}.{}", container, group_name);
+                find_duplicate_fields(dict, &group_container, items, errors);
+            }
+            LayoutItemKindData::Component { .. } => {}
+        }
+    }
+    errors.extend(
+        occurrences
+            .into_iter()
+            .filter(|(_, n)| *n > 1)
+            .map(|(tag, occurrences)| DuplicateFieldError {
+                container: container.to_string(),
+                tag: TagU32::new(tag).unwrap(),
+                occurrences,
+            }),
+    );
+}
+
+fn find_missing_length_fields(
+    dict: &Dictionary,
+    container: &str,
+    items: &[LayoutItemData],
+    errors: &mut Vec<MissingLengthFieldError>,
+) {
+    for (i, item) in items.iter().enumerate() {
+        match &item.kind {
+            LayoutItemKindData::Field { tag }
+                if dict.field_is_data(*tag) && preceding_length_field(dict, items, i).is_none() =>
+            {
+                errors.push(MissingLengthFieldError {
+                    container: container.to_string(),
+                    tag: TagU32::new(*tag).unwrap(),
+                });
+            }
+            LayoutItemKindData::Group {
+                len_field_tag,
+                items,
+            } => {
+                let group_name = dict
+                    .fields_by_tags
+                    .get(len_field_tag)
+                    .map(|field| field.name.as_str())
+                    .unwrap_or_default();
+                let group_container = format!("{}.{}", container, group_name);
+                find_missing_length_fields(dict, &group_container, items, errors);
+            }
+            _ => {}
+        }
+    }
+}
+
+fn find_fixml_issues(
+    dict: &Dictionary,
+    container: &str,
+    items: &[LayoutItemData],
+    issues: &mut Vec<FixmlIssue>,
+) {
+    let excluded_from_fixml = |tag: &u32| {
+        dict.fields_by_tags
+            .get(tag)
+            .is_some_and(|field| !field.required)
+    };
+    for item in items {
+        match &item.kind {
+            LayoutItemKindData::Field { tag }
+            | LayoutItemKindData::Group {
+                len_field_tag: tag, ..
+            } if item.required && excluded_from_fixml(tag) => {
+                issues.push(FixmlIssue {
+                    container: container.to_string(),
+                    tag: TagU32::new(*tag).unwrap(),
+                });
+            }
+            _ => {}
+        }
+        if let LayoutItemKindData::Group {
+            len_field_tag,
+            items,
+        } = &item.kind
+        {
+            let group_name = dict
+                .fields_by_tags
+                .get(len_field_tag)
+                .map(|field| field.name.as_str())
+                .unwrap_or_default();
+            let group_container = format!("{}.{}", container, group_name);
+            find_fixml_issues(dict, &group_container, items, issues);
+        }
+    }
+}
+
+fn find_groups_without_delimiter(
+    dict: &Dictionary,
+    container: &str,
+    items: &[LayoutItemData],
+    errors: &mut Vec<GroupDelimiterError>,
+) {
+    for item in items {
+        if let LayoutItemKindData::Group {
+            len_field_tag,
+            items,
+        } = &item.kind
+        {
+            if delimiter_tag(dict, items).is_none() {
+                errors.push(GroupDelimiterError {
+                    container: container.to_string(),
+                    count_tag: TagU32::new(*len_field_tag).unwrap(),
+                });
+            }
+            let group_name = dict
+                .fields_by_tags
+                .get(len_field_tag)
+                .map(|field| field.name.as_str())
+                .unwrap_or_default();
+            let group_container = format!("{}.{}", container, group_name);
+            find_groups_without_delimiter(dict, &group_container, items, errors);
+        }
+    }
+}
+
+/// Returns the tag of the first field of `items`, expanding leading
+/// components.
+fn delimiter_tag(dict: &Dictionary, items: &[LayoutItemData]) -> Option<u32> {
+    match &items.first()?.kind {
+        LayoutItemKindData::Field { tag } => Some(*tag),
+        LayoutItemKindData::Group { len_field_tag, .. } => Some(*len_field_tag),
+        LayoutItemKindData::Component { name } => {
+            delimiter_tag(dict, &dict.components_by_name.get(name)?.layout_items)
+        }
+    }
+}
+
+/// Checks whether the component named `name` can be reached again by
+/// following its component references.
+fn includes_itself(dict: &Dictionary, name: &str) -> bool {
+    let mut visited = FnvHashSet::default();
+    let mut pending = vec![name];
+    while let Some(current) = pending.pop() {
+        let mut references = Vec::new();
+        if let Some(component) = dict.components_by_name.get(current) {
+            referenced_components(&component.layout_items, &mut references);
+        }
+        for reference in references {
+            if reference == name {
+                return true;
+            }
+            if visited.insert(reference) {
+                pending.push(reference);
+            }
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn common_dictionaries_have_no_duplicate_fields() {
+        for dict in Dictionary::common_dictionaries().iter() {
+            assert_eq!(
+                dict.verify_no_duplicate_fields(),
+                vec![],
+                "{}",
+                dict.version()
+            );
+        }
+    }
+
+    #[test]
+    fn common_dictionaries_have_consistent_group_usage() {
+        for dict in Dictionary::common_dictionaries().iter() {
+            assert_eq!(
+                dict.verify_consistent_group_usage(),
+                vec![],
+                "{}",
+                dict.version()
+            );
+        }
+    }
+
+    #[test]
+    fn component_cycles_are_detected() {
+        let mut dicts = Dictionary::common_dictionaries();
+        dicts.push(Dictionary::fix44());
+        for dict in dicts.iter() {
+            assert_eq!(dict.component_recursion_check(), None, "{}", dict.version());
+        }
+        let mut dict = Dictionary::fix44();
+        dict.components_by_name
+            .get_mut("InstrumentLeg")
+            .unwrap()
+            .layout_items
+            .push(LayoutItemData {
+                required: false,
+                kind: LayoutItemKindData::Component {
+                    name: "InstrmtLegGrp".into(),
+                },
+            });
+        assert_eq!(
+            dict.component_recursion_check(),
+            Some(vec![
+                "InstrmtLegGrp".to_string(),
+                "InstrumentLeg".to_string()
+            ])
+        );
+    }
+
+    #[test]
+    fn invalid_datatype_examples_are_detected() {
+        let mut dict = Dictionary::fix44();
+        assert_eq!(dict.validate_datatype_examples(), vec![]);
+        let examples = [
+            ("UTCTimestamp", "20030910-13:45:00.000"),
+            ("UTCTimestamp", "2003-09-10T13:45:00"),
+            ("Price", "12.5"),
+            ("Price", "12,5"),
+            ("Boolean", "Y"),
+        ];
+        for (datatype, example) in examples {
+            dict.data_types_by_name
+                .get_mut(datatype)
+                .unwrap()
+                .examples
+                .push(example.to_string());
+        }
+        let error = |datatype: &str, example: &str| DatatypeExampleError {
+            datatype: datatype.to_string(),
+            example: example.to_string(),
+        };
+        assert_eq!(
+            dict.validate_datatype_examples(),
+            vec![
+                error("Price", "12,5"),
+                error("UTCTimestamp", "2003-09-10T13:45:00"),
+            ]
+        );
+        assert_eq!(
+            dict.datatype_by_name("Price").unwrap().examples(),
+            &["12.5".to_string(), "12,5".to_string()]
+        );
+    }
+
+    #[test]
+    fn unused_datatypes_are_detected() {
+        let fields = [FieldDef {
+            tag: 112,
+            name: "TestReqID".to_string(),
+            datatype: FixDatatype::String,
+            enums: vec![],
+        }];
+        let datatypes = [FixDatatype::Int, FixDatatype::String, FixDatatype::Amt];
+        let dict = Dictionary::from_parts("FIX.4.4", &fields, &datatypes, &[], &[]).unwrap();
+        let unused = dict
+            .unused_datatypes()
+            .iter()
+            .map(|dt| dt.name().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(unused, vec!["Amt".to_string(), "int".to_string()]);
+    }
+
+    #[test]
+    fn common_dictionaries_have_length_fields() {
+        let mut dicts = Dictionary::common_dictionaries();
+        dicts.push(Dictionary::fix44());
+        for dict in dicts.iter() {
+            assert_eq!(
+                dict.verify_data_fields_have_length(),
+                vec![],
+                "{}",
+                dict.version()
+            );
+            for field in dict.fields() {
+                if let Some(data_tag) = field.data_tag() {
+                    assert_eq!(field.fix_datatype(), FixDatatype::Length);
+                    assert!(dict.field_is_data(data_tag.get()));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn missing_length_fields_are_detected() {
+        let spec = include_str!("test_data/quickfix_specs/missing_length_field.xml");
+        let dict = Dictionary::from_quickfix_spec(spec).unwrap();
+        assert_eq!(
+            dict.verify_data_fields_have_length(),
+            vec![MissingLengthFieldError {
+                container: "Logon".to_string(),
+                tag: TagU32::new(96).unwrap(),
+            }]
+        );
+        // `RawDataLength` comes right before `RawData` in `Heartbeat`.
+        let raw_data_length = dict.field_by_tag(95).unwrap();
+        assert_eq!(raw_data_length.data_tag(), TagU32::new(96));
+    }
+
+    #[test]
+    fn data_fields_without_length_are_detected() {
+        let names = |dict: &Dictionary| {
+            dict.data_fields_without_length()
+                .iter()
+                .map(|field| field.name().to_string())
+                .collect::<Vec<_>>()
+        };
+        let mut dict = Dictionary::fix44();
+        assert!(names(&dict).is_empty());
+        dict.remove_field(TagU32::new(95).unwrap()).unwrap();
+        assert_eq!(names(&dict), &["RawData"]);
+    }
+
+    #[test]
+    #[cfg(feature = "fix50")]
+    fn fix50_header_data_fields_have_no_length() {
+        // The header lives in FIXT.1.1, so the `Length` fields of these data
+        // fields never precede them.
+        let dict = Dictionary::fix50();
+        let names = dict
+            .data_fields_without_length()
+            .iter()
+            .map(|field| field.name().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(names, &["Signature", "SecureData", "XmlData"]);
+    }
+
+    #[test]
+    fn fixml_issues_are_detected() {
+        let spec = include_str!("test_data/quickfix_specs/fixml_required.xml");
+        let dict = Dictionary::from_quickfix_spec(spec).unwrap();
+        assert!(!dict.field_by_tag(9).unwrap().required_in_xml_messages());
+        assert!(dict.field_by_tag(112).unwrap().required_in_xml_messages());
+        let issue = |container: &str, tag: u32| FixmlIssue {
+            container: container.to_string(),
+            tag: TagU32::new(tag).unwrap(),
+        };
+        assert_eq!(
+            dict.check_fixml_consistency(),
+            vec![
+                issue("Heartbeat", 9),
+                issue("Parties.NoPartyIDs", 9),
+                issue("StandardHeader", 9),
+            ]
+        );
+    }
+
+    #[test]
+    fn common_dictionaries_have_group_delimiters() {
+        let mut dicts = Dictionary::common_dictionaries();
+        dicts.push(Dictionary::fix44());
+        for dict in dicts.iter() {
+            assert_eq!(
+                dict.check_all_groups_have_delimiter_first(),
+                vec![],
+                "{}",
+                dict.version()
+            );
+        }
+    }
+
+    #[test]
+    fn groups_without_delimiter_are_detected() {
+        let spec = include_str!("test_data/quickfix_specs/group_delimiters.xml");
+        let dict = Dictionary::from_quickfix_spec(spec).unwrap();
+        let error = |container: &str, tag: u32| GroupDelimiterError {
+            container: container.to_string(),
+            count_tag: TagU32::new(tag).unwrap(),
+        };
+        assert_eq!(
+            dict.check_all_groups_have_delimiter_first(),
+            vec![error("Legs", 555)]
+        );
+    }
+
+    #[test]
+    fn group_usage_conflicts_are_detected() {
+        let spec = include_str!("test_data/quickfix_specs/conflicting_group_usage.xml");
+        let dict = Dictionary::from_quickfix_spec(spec).unwrap();
+        assert_eq!(
+            dict.verify_consistent_group_usage(),
+            vec![GroupUsageConflict {
+                tag: TagU32::new(453).unwrap(),
+                as_group: vec!["Parties".to_string()],
+                as_field: vec!["Heartbeat".to_string(), "Parties.NoPartyIDs".to_string()],
+            }]
+        );
+    }
+
+    #[test]
+    fn duplicate_fields_are_detected() {
+        let spec = include_str!("test_data/quickfix_specs/duplicate_fields.xml");
+        let dict = Dictionary::from_quickfix_spec(spec).unwrap();
+        assert_eq!(
+            dict.verify_no_duplicate_fields(),
+            vec![
+                DuplicateFieldError {
+                    container: "Heartbeat".to_string(),
+                    tag: TagU32::new(112).unwrap(),
+                    occurrences: 2,
+                },
+                DuplicateFieldError {
+                    container: "Parties.NoPartyIDs".to_string(),
+                    tag: TagU32::new(448).unwrap(),
+                    occurrences: 3,
+                },
+            ]
+        );
+    }
+}